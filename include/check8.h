@@ -0,0 +1,57 @@
+/*
+ * check8.h - the C interface of the check8 crate, built with the ffi feature:
+ *
+ *     cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * See the ffi module documentation for the behaviour of each function. The functions do not
+ * abort: null handles are ignored and null data pointers are taken as empty buffers.
+ */
+
+#ifndef CHECK8_H
+#define CHECK8_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* A running checksum, opaque to C. */
+typedef struct Check8Handle Check8Handle;
+
+/* The Rocksoft model parameters of a CRC of up to 8 bits, as Check8CrcParams. */
+typedef struct Check8CrcParams {
+    uint8_t width;
+    uint8_t poly;
+    uint8_t init;
+    bool refin;
+    bool refout;
+    uint8_t xorout;
+} Check8CrcParams;
+
+Check8Handle *check8_sum_create(uint8_t initial);
+Check8Handle *check8_xor_create(uint8_t initial);
+Check8Handle *check8_crc_create(uint8_t poly);
+/* NULL if the width is not 1 to 8 bits. */
+Check8Handle *check8_crc_create_with_params(Check8CrcParams params);
+/* NULL if the name is not a known preset. Requires the presets feature (on by default). */
+Check8Handle *check8_crc_create_preset(const char *name);
+
+uint8_t check8_reset(Check8Handle *handle);
+uint8_t check8_update(Check8Handle *handle, const uint8_t *data, size_t len);
+uint8_t check8_finalize(const Check8Handle *handle);
+void check8_destroy(Check8Handle *handle);
+
+uint8_t check8_sum(const uint8_t *data, size_t len);
+uint8_t check8_xor(const uint8_t *data, size_t len);
+uint8_t check8_crc(uint8_t poly, const uint8_t *data, size_t len);
+/* false if the width is not 1 to 8 bits or out is NULL. */
+bool check8_crc_with_params(Check8CrcParams params, const uint8_t *data, size_t len, uint8_t *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CHECK8_H */
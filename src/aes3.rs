@@ -0,0 +1,108 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # AES3
+//!
+//! Helpers for the channel status CRC (CRCC) of AES3 (AES/EBU) digital audio. In professional
+//! use the 192 channel status bits of a block form 24 bytes, and byte 23 is the CRC-8/TECH-3250
+//! of bytes 0 to 22.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::presets::crc8_tech_3250;
+
+/// Calculates the CRCC of a channel status block from its first 23 bytes.
+///
+/// - **Parameter**: block - The 24-byte channel status block; byte 23 is ignored.
+/// - **Returns**: The CRCC to store in byte 23.
+pub fn channel_status_crc(block: &[u8; 24]) -> u8 {
+    crc8_tech_3250().calculate_from_byte_array(&block[..23])
+}
+
+/// Sets byte 23 of a channel status block to its CRCC.
+pub fn set_channel_status_crc(block: &mut [u8; 24]) {
+    block[23] = channel_status_crc(block);
+}
+
+/// Checks byte 23 of a channel status block against the CRCC of bytes 0 to 22.
+///
+/// - **Parameter**: block - The 24-byte channel status block.
+/// - **Returns**: true if the CRCC matches.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::aes3::{check_channel_status, set_channel_status_crc};
+/// fn main() {
+///     // professional use, 48 kHz sampling
+///     let mut block = [0u8; 24];
+///     block[0] = 0x01 | 0x40;
+///     set_channel_status_crc(&mut block);
+///     assert!(check_channel_status(&block));
+///     block[2] ^= 0x04;
+///     assert!(!check_channel_status(&block));
+/// }
+/// ```
+///
+pub fn check_channel_status(block: &[u8; 24]) -> bool {
+    channel_status_crc(block) == block[23]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crcc_covers_bytes_0_to_22() {
+        let mut block = [0u8; 24];
+        block[0] = 0x41;
+        block[22] = 0x80;
+        let expected = crc8_tech_3250().calculate_from_byte_array(&block[..23]);
+        block[23] = 0xAA;
+        assert_eq!(channel_status_crc(&block), expected)
+    }
+
+    #[test]
+    fn whole_block_leaves_zero() {
+        let mut block = [0u8; 24];
+        block[0] = 0x41;
+        block[1] = 0x02;
+        set_channel_status_crc(&mut block);
+        let mut crc = crc8_tech_3250();
+        assert_eq!(crc.calculate_from_byte_array(&block), 0x00)
+    }
+
+    #[test]
+    fn single_bit_errors_are_detected() {
+        let mut block = [0u8; 24];
+        block[0] = 0x01;
+        set_channel_status_crc(&mut block);
+        for bit in 0..192 {
+            let mut corrupted = block;
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+            assert!(!check_channel_status(&corrupted))
+        }
+    }
+}
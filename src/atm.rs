@@ -0,0 +1,97 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ATM
+//!
+//! Helpers for the header error control (HEC) of ATM cells. The HEC in the fifth header byte
+//! is the CRC-8 (polynomial 0x07) of the first four bytes XORed with the coset 0x55, which
+//! ITU-T I.432.1 adds so that an all-zero header does not have an all-zero HEC.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::presets::crc8_i_432_1;
+
+/// The coset added to the CRC to give the HEC.
+pub const ATM_HEC_COSET: u8 = 0x55;
+
+/// Calculates the HEC of an ATM cell header.
+///
+/// - **Parameter**: header - The first four bytes of the cell header.
+/// - **Returns**: The HEC, including the coset.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::atm::{atm_hec, verify_cell_header};
+/// fn main() {
+///     // the idle cell header
+///     assert_eq!(atm_hec(&[0x00, 0x00, 0x00, 0x01]), 0x52);
+///     assert!(verify_cell_header(&[0x00, 0x00, 0x00, 0x01, 0x52]));
+/// }
+/// ```
+///
+pub fn atm_hec(header: &[u8; 4]) -> u8 {
+    crc8_i_432_1().calculate_from_byte_array(header)
+}
+
+/// Checks the HEC in the fifth byte of an ATM cell header.
+///
+/// - **Parameter**: header - The five header bytes.
+/// - **Returns**: true if the HEC matches the first four bytes.
+pub fn verify_cell_header(header: &[u8; 5]) -> bool {
+    atm_hec(&[header[0], header[1], header[2], header[3]]) == header[4]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8, Check8Crc};
+
+    #[test]
+    fn known_headers() {
+        // unassigned and idle cells
+        assert_eq!(atm_hec(&[0x00, 0x00, 0x00, 0x00]), 0x55);
+        assert_eq!(atm_hec(&[0x00, 0x00, 0x00, 0x01]), 0x52)
+    }
+
+    #[test]
+    fn hec_is_crc_plus_coset() {
+        let header = [0x12, 0x34, 0x56, 0x78];
+        let crc = Check8Crc::new(0x07).calculate_from_byte_array(&header);
+        assert_eq!(atm_hec(&header), crc ^ ATM_HEC_COSET)
+    }
+
+    #[test]
+    fn verify_detects_single_bit_errors() {
+        let header = [0x0A, 0xBC, 0xDE, 0xF2];
+        let mut cell = [header[0], header[1], header[2], header[3], atm_hec(&header)];
+        assert!(verify_cell_header(&cell));
+        for bit in 0..40 {
+            cell[bit / 8] ^= 1 << (bit % 8);
+            assert!(!verify_cell_header(&cell));
+            cell[bit / 8] ^= 1 << (bit % 8);
+        }
+    }
+}
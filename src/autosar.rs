@@ -0,0 +1,169 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # AUTOSAR
+//!
+//! Helpers for the CRC of AUTOSAR End-to-End (E2E) protection.
+//!
+//! E2E Profile 1 protects a data element with an 8-bit CRC over the Data ID and the data. It
+//! chains calls of the CRC library's SAE J1850 routine, Crc_CalculateCRC8, in a way which
+//! cancels its start value and final XOR, so the CRC is the plain polynomial 0x1D CRC with an
+//! initial value and final XOR of zero. The Data ID is not transmitted; which of its bytes are
+//! included depends on the Data ID mode.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Crc, Check8CrcParams};
+
+/// The parameters of the E2E Profile 1 CRC: polynomial 0x1D, no initial value or final XOR.
+pub const E2E_P01_CRC: Check8CrcParams = Check8CrcParams::new(0x1D);
+
+/// # Variants
+///
+/// - Both: Both bytes of the Data ID are included, low byte first.
+/// - Alt: The low byte is included when the counter is even and the high byte when it is odd.
+/// - Low: Only the low byte is included; the high byte must be zero.
+/// - Nibble: The low byte and a zero byte are included; the low nibble of the high byte is
+///   transmitted in the data instead.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum E2eDataIdMode {
+    Both,
+    Alt,
+    Low,
+    Nibble,
+}
+
+/// Calculates the E2E Profile 1 CRC of a data element.
+///
+/// - **Parameter**: data_id - The 16-bit Data ID of the data element.
+/// - **Parameter**: mode - How the Data ID is included in the CRC.
+/// - **Parameter**: counter - The sequence counter of the data element, used by the Alt mode.
+/// - **Parameter**: data - The data element, including the counter.
+/// - **Parameter**: crc_index - The position of the CRC byte in data, which is skipped.
+/// - **Returns**: The CRC to store at crc_index.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::autosar::{e2e_p01_crc, E2eDataIdMode};
+/// fn main() {
+///     // CRC in byte 0, counter 3 in the low nibble of byte 1, then the signals
+///     let mut data = [0x00, 0x03, 0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+///     data[0] = e2e_p01_crc(0x0123, E2eDataIdMode::Both, 3, &data, 0);
+///     // the receiver recalculates the CRC, skipping the CRC byte
+///     assert_eq!(e2e_p01_crc(0x0123, E2eDataIdMode::Both, 3, &data, 0), data[0]);
+/// }
+/// ```
+///
+pub fn e2e_p01_crc(data_id: u16, mode: E2eDataIdMode, counter: u8, data: &[u8], crc_index: usize) -> u8 {
+    let [low, high] = data_id.to_le_bytes();
+    // the table is generated at compile time rather than on every call
+    const CRC: Check8Crc = Check8Crc::with_params(E2E_P01_CRC);
+    let mut crc = CRC;
+    match mode {
+        E2eDataIdMode::Both => {
+            crc.add(low);
+            crc.add(high);
+        }
+        E2eDataIdMode::Alt => {
+            crc.add(if counter & 0x01 == 0 { low } else { high });
+        }
+        E2eDataIdMode::Low => {
+            crc.add(low);
+        }
+        E2eDataIdMode::Nibble => {
+            crc.add(low);
+            crc.add(0x00);
+        }
+    }
+    for (i, val) in data.iter().enumerate() {
+        if i != crc_index {
+            crc.add(*val);
+        }
+    }
+    crc.get_checksum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Crc_CalculateCRC8 of the AUTOSAR CRC library, SAE J1850 with start value and final XOR 0xFF;
+    // when it is not the first call the start value is the result of the previous call
+    fn crc_calculate_crc8(data: &[u8], start_value: u8, is_first_call: bool) -> u8 {
+        let init = if is_first_call { 0xFF } else { start_value ^ 0xFF };
+        let mut crc = Check8Crc::with_width(8, 0x1D, init, false, false, 0xFF);
+        crc.calculate_from_byte_array(data)
+    }
+
+    // E2E_P01Protect as the specification writes it, for the Both mode with the CRC in byte 0
+    fn e2e_p01_protect_both(data_id: u16, data: &[u8]) -> u8 {
+        let mut crc = crc_calculate_crc8(&[data_id as u8], 0xFF, false);
+        crc = crc_calculate_crc8(&[(data_id >> 8) as u8], crc, false);
+        crc = crc_calculate_crc8(&data[1..], crc, false);
+        crc ^ 0xFF
+    }
+
+    #[test]
+    fn crc_library_examples() {
+        // the Crc_CalculateCRC8 examples from the AUTOSAR Specification of CRC Routines
+        assert_eq!(crc_calculate_crc8(&[0x00, 0x00, 0x00, 0x00], 0xFF, true), 0x59);
+        assert_eq!(crc_calculate_crc8(&[0xF2, 0x01, 0x83], 0xFF, true), 0x37);
+        assert_eq!(crc_calculate_crc8(&[0xFF, 0xFF, 0xFF, 0xFF], 0xFF, true), 0x74)
+    }
+
+    #[test]
+    fn matches_chained_crc_library_calls() {
+        let data = [0x00, 0x0A, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66];
+        for data_id in [0x0000, 0x0123, 0xFFFF, 0x8000] {
+            assert_eq!(e2e_p01_crc(data_id, E2eDataIdMode::Both, 0x0A, &data, 0), e2e_p01_protect_both(data_id, &data))
+        }
+    }
+
+    #[test]
+    fn crc_byte_is_skipped() {
+        let mut data = [0x00, 0x01, 0xAB, 0xCD];
+        let crc = e2e_p01_crc(0x0042, E2eDataIdMode::Both, 1, &data, 0);
+        data[0] = crc;
+        assert_eq!(e2e_p01_crc(0x0042, E2eDataIdMode::Both, 1, &data, 0), crc);
+        // and can be anywhere in the data element
+        let data = [0x01, 0xAB, 0xCD, 0x00];
+        assert_eq!(e2e_p01_crc(0x0042, E2eDataIdMode::Both, 1, &data, 3), crc)
+    }
+
+    #[test]
+    fn data_id_modes() {
+        let data = [0x00, 0x05, 0x10, 0x20];
+        let crc = |bytes: &[u8]| Check8Crc::with_params(E2E_P01_CRC).calculate_from_byte_array(bytes);
+        assert_eq!(e2e_p01_crc(0x1234, E2eDataIdMode::Both, 5, &data, 0), crc(&[0x34, 0x12, 0x05, 0x10, 0x20]));
+        assert_eq!(e2e_p01_crc(0x1234, E2eDataIdMode::Alt, 4, &data, 0), crc(&[0x34, 0x05, 0x10, 0x20]));
+        assert_eq!(e2e_p01_crc(0x1234, E2eDataIdMode::Alt, 5, &data, 0), crc(&[0x12, 0x05, 0x10, 0x20]));
+        assert_eq!(e2e_p01_crc(0x0034, E2eDataIdMode::Low, 5, &data, 0), crc(&[0x34, 0x05, 0x10, 0x20]));
+        assert_eq!(e2e_p01_crc(0x0234, E2eDataIdMode::Nibble, 5, &data, 0), crc(&[0x34, 0x00, 0x05, 0x10, 0x20]))
+    }
+}
@@ -0,0 +1,146 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Bluetooth
+//!
+//! Helpers for the header error check (HEC) of Bluetooth BR/EDR baseband packet headers.
+//!
+//! The 10-bit packet header (LT_ADDR, TYPE, FLOW, ARQN, SEQN) is protected by an 8-bit HEC,
+//! the CRC-8/BLUETOOTH of the header bits with the register initialised from the upper
+//! address part (UAP) of the master's address, or the default check initialisation (DCI)
+//! 0x00 before a connection. Bits are sent least significant first, so the header is added
+//! as 10 bits with add_bits.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Crc;
+use crate::Check8CrcParams;
+use crate::presets::CRC8_BLUETOOTH;
+
+/// The default check initialisation, used in place of the UAP for inquiry and paging.
+pub const DCI: u8 = 0x00;
+
+/// Calculates the HEC of a 10-bit packet header.
+///
+/// - **Parameter**: header - The header bits: LT_ADDR in bits 0-2, TYPE in 3-6, FLOW in 7, ARQN in 8 and SEQN in 9.
+/// - **Parameter**: uap - The UAP the HEC is initialised with, or DCI.
+/// - **Returns**: The HEC, with the bit sent first in bit 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::bluetooth::{header_hec, packet_header, check_packet_header};
+/// fn main() {
+///     // LT_ADDR 1, DM1 packet (TYPE 3), FLOW 1, ARQN 0, SEQN 1, with UAP 0x47
+///     let header = packet_header(1, 3, true, false, true, 0x47);
+///     assert_eq!(header >> 10, header_hec(0x0299, 0x47) as u32);
+///     assert!(check_packet_header(header, 0x47));
+/// }
+/// ```
+///
+pub fn header_hec(header: u16, uap: u8) -> u8 {
+    // the table does not depend on the UAP, so it is generated once, at compile time
+    static TABLE: [u8; 256] = Check8Crc::generate_table_with_params(CRC8_BLUETOOTH.params);
+    let mut crc = Check8Crc::with_table(&TABLE, Check8CrcParams { init: uap, ..CRC8_BLUETOOTH.params });
+    crc.add(header as u8);
+    // 2 is a valid bit count, so this cannot fail
+    let _ = crc.add_bits((header >> 8) as u8, 2);
+    crc.get_checksum()
+}
+
+/// Builds an 18-bit packet header, the 10 header bits followed by the HEC, before the 1/3 rate FEC.
+///
+/// - **Parameter**: lt_addr - The logical transport address, 0 to 7.
+/// - **Parameter**: packet_type - The packet type code, 0 to 15.
+/// - **Parameter**: flow - The FLOW bit.
+/// - **Parameter**: arqn - The ARQN bit.
+/// - **Parameter**: seqn - The SEQN bit.
+/// - **Parameter**: uap - The UAP the HEC is initialised with, or DCI.
+/// - **Returns**: The header with the bit sent first in bit 0 and the HEC in bits 10-17.
+pub fn packet_header(lt_addr: u8, packet_type: u8, flow: bool, arqn: bool, seqn: bool, uap: u8) -> u32 {
+    let header = (lt_addr as u16 & 0x07)
+        | ((packet_type as u16 & 0x0F) << 3)
+        | ((flow as u16) << 7)
+        | ((arqn as u16) << 8)
+        | ((seqn as u16) << 9);
+    header as u32 | ((header_hec(header, uap) as u32) << 10)
+}
+
+/// Checks the HEC of a received 18-bit packet header.
+///
+/// - **Parameter**: header - The header bits and HEC, as returned by packet_header.
+/// - **Parameter**: uap - The UAP the HEC was initialised with, or DCI.
+/// - **Returns**: true if the HEC matches the header bits.
+pub fn check_packet_header(header: u32, uap: u8) -> bool {
+    header_hec((header & 0x3FF) as u16, uap) == (header >> 10) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the HEC generating LFSR as the Core Specification draws it: positions 0 to 7 loaded with
+    // UAP0 to UAP7, the header shifted in from bit 0, and the HEC read out from position 7 first
+    fn lfsr_hec(header: u16, uap: u8) -> u8 {
+        let mut reg = uap;
+        for n in 0..10 {
+            let feedback = ((reg >> 7) ^ (header >> n) as u8) & 0x01;
+            reg <<= 1;
+            if feedback != 0 {
+                reg ^= 0xA7;
+            }
+        }
+        reg.reverse_bits()
+    }
+
+    #[test]
+    fn header_hec_matches_the_lfsr() {
+        for uap in [0x00, 0x47, 0x9E, 0xFF] {
+            for header in 0..0x400u16 {
+                assert_eq!(header_hec(header, uap), lfsr_hec(header, uap))
+            }
+        }
+    }
+
+    #[test]
+    fn packet_header_layout() {
+        let header = packet_header(0x07, 0x0F, true, true, true, DCI);
+        assert_eq!(header & 0x3FF, 0x3FF);
+        let header = packet_header(0x02, 0x04, false, true, false, DCI);
+        assert_eq!(header & 0x3FF, 0x122);
+        assert_eq!((header >> 10) as u8, header_hec(0x122, DCI))
+    }
+
+    #[test]
+    fn check_packet_header_detects_corruption() {
+        let header = packet_header(0x03, 0x0B, true, false, true, 0x5A);
+        assert!(check_packet_header(header, 0x5A));
+        assert!(!check_packet_header(header, 0x5B));
+        for bit in 0..18 {
+            assert!(!check_packet_header(header ^ (1 << bit), 0x5A))
+        }
+    }
+}
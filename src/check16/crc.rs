@@ -0,0 +1,145 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check16Crc
+//!
+//! A CRC checksum type of up to 16 bits with tests, implements the Checksum<u16> trait.
+//! Like Check8Crc it follows the Rocksoft model and uses a lookup table generated from the
+//! parameters. The type is defined, with Check32Crc, by the wide_crc! macro of crcwide.
+
+use crate::checksum::Checksum;  // for the Checksum trait
+use crate::crcwide::{wide_crc, CrcModel};
+use crate::Check8Error;  // for try_with_params
+
+wide_crc! {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::checksum::Checksum;
+    /// use crate::check8::check16::{Check16Crc, CRC16_IBM_3740, CRC16_XMODEM};
+    /// fn main() {
+    ///     assert_eq!(Check16Crc::with_params(CRC16_XMODEM).calculate_from_string("123456789"), 0x31C3);
+    ///     assert_eq!(Check16Crc::with_params(CRC16_IBM_3740).calculate_from_string("123456789"), 0x29B1);
+    /// }
+    /// ```
+    ///
+    Check16Crc(Check16CrcParams, u16, 16, "Check16Crc")
+}
+
+/// CRC-16/XMODEM, the CCITT polynomial with a zero initial value, as in XMODEM and ZMODEM.
+pub const CRC16_XMODEM: Check16CrcParams = Check16CrcParams::new(0x1021);
+
+/// CRC-16/IBM-3740, known as CRC-16/CCITT-FALSE, the CCITT polynomial with an initial value of 0xFFFF.
+pub const CRC16_IBM_3740: Check16CrcParams = Check16CrcParams { init: 0xFFFF, ..Check16CrcParams::new(0x1021) };
+
+/// CRC-16/KERMIT, the reflected CCITT CRC, as in Kermit and the HDLC-based Bluetooth and X.25 variants.
+pub const CRC16_KERMIT: Check16CrcParams =
+    Check16CrcParams { refin: true, refout: true, ..Check16CrcParams::new(0x1021) };
+
+/// CRC-16/MODBUS, the CRC of Modbus RTU frames, sent low byte first.
+pub const CRC16_MODBUS: Check16CrcParams =
+    Check16CrcParams { init: 0xFFFF, refin: true, refout: true, ..Check16CrcParams::new(0x8005) };
+
+/// CRC-16/ARC, the reflected 0x8005 CRC with a zero initial value.
+pub const CRC16_ARC: Check16CrcParams = Check16CrcParams { refin: true, refout: true, ..Check16CrcParams::new(0x8005) };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn catalogue_check_values() {
+        for (params, check) in [
+            (CRC16_XMODEM, 0x31C3),
+            (CRC16_IBM_3740, 0x29B1),
+            (CRC16_KERMIT, 0x2189),
+            (CRC16_MODBUS, 0x4B37),
+            (CRC16_ARC, 0xBB3D),
+            // CRC-16/X-25, the HDLC FCS
+            (Check16CrcParams { init: 0xFFFF, xorout: 0xFFFF, ..CRC16_KERMIT }, 0x906E),
+            // CRC-12/UMTS, narrower than the register
+            (Check16CrcParams { width: 12, refout: true, ..Check16CrcParams::new(0x80F) }, 0xDAF),
+            // CRC-10/ATM
+            (Check16CrcParams { width: 10, ..Check16CrcParams::new(0x233) }, 0x199),
+        ] {
+            assert_eq!(Check16Crc::with_params(params).calculate_from_byte_array(CHECK), check, "{:?}", params);
+        }
+    }
+
+    #[test]
+    fn modbus_rtu_frame() {
+        // read holding registers from slave 1, the CRC is sent low byte first
+        let frame = [0x01, 0x03, 0x00, 0x00, 0x00, 0x0A];
+        let crc = Check16Crc::with_params(CRC16_MODBUS).calculate_from_byte_array(&frame);
+        assert_eq!(crc.to_le_bytes(), [0xC5, 0xCD])
+    }
+
+    #[test]
+    fn table_matches_bitwise() {
+        for params in [CRC16_XMODEM, CRC16_MODBUS] {
+            let model = params.model();
+            let mut crc = Check16Crc::with_params(params);
+            let mut reg = model.init_register();
+            for byte in 0..=255u8 {
+                crc.add(byte);
+                reg = model.add_bitwise(reg, byte);
+            }
+            assert_eq!(crc.get_checksum() as u64, model.finalise(reg));
+        }
+    }
+
+    #[test]
+    fn try_with_params_rejects_invalid_widths() {
+        let params = Check16CrcParams { width: 17, ..CRC16_XMODEM };
+        assert_eq!(Check16Crc::try_with_params(params).err(), Some(Check8Error::InvalidWidth(17)));
+        let params = crate::check32::Check32CrcParams { width: 0, ..crate::check32::CRC32_ISO_HDLC };
+        assert_eq!(crate::check32::Check32Crc::try_with_params(params).err(), Some(Check8Error::InvalidWidth(0)));
+        assert!(Check16Crc::try_with_params(CRC16_XMODEM).is_ok())
+    }
+
+    #[test]
+    fn init_and_reset() {
+        let mut crc = Check16Crc::with_params(CRC16_IBM_3740);
+        assert_eq!(crc.get_accum(), 0xFFFF);
+        assert_eq!(crc.init(0x1234), 0x1234);
+        assert_eq!(crc.reset(), 0xFFFF);
+        crc.calculate_from_byte_array(CHECK);
+        crc.reset();
+        assert_eq!(crc.calculate_from_byte_array(CHECK), 0x29B1)
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_resumes_calculation() {
+        let mut crc = Check16Crc::with_params(CRC16_MODBUS);
+        crc.calculate_from_byte_array(&CHECK[..4]);
+        let json = serde_json::to_string(&crc).unwrap();
+        let mut resumed: Check16Crc = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.calculate_from_byte_array(&CHECK[4..]), 0x4B37)
+    }
+}
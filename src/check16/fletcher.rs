@@ -0,0 +1,155 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check16Fletcher
+//!
+//! Fletcher-16 with tests, implements the Checksum<u16> trait.
+//! Two running sums of the bytes are kept modulo 255, and the checksum is the second sum (of
+//! the first sum) in the upper byte and the first sum in the lower byte. It is the full-size
+//! counterpart of Check8Fletcher, which works on 4-bit words.
+
+use crate::checksum::Checksum;  // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check16Fletcher
+{
+    accum: u16,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u16,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator.
+/// - get_accum: Retrieves the current value of the accumulator, the second sum in the upper
+///   byte and the first sum in the lower byte.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+///   Each byte is reduced modulo 255.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator using the Fletcher algorithm and returns the updated value.
+/// - get_checksum_folded: Retrieves the checksum folded to 8 bits, the XOR of its two bytes, for
+///   protocols with a single checksum byte.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checksum::Checksum;
+/// use crate::check8::check16::Check16Fletcher;
+/// fn main() {
+///     let mut fletcher = Check16Fletcher::new(0x0000);
+///     assert_eq!(fletcher.calculate_from_string("abcde"), 0xC8F0);
+///     assert_eq!(fletcher.get_checksum_folded(), 0xC8 ^ 0xF0);
+/// }
+/// ```
+///
+impl Check16Fletcher
+{
+    pub fn new(initial: u16) -> Self {
+        let mut fletcher = Check16Fletcher { accum: 0, initial };
+        fletcher.init(initial);
+        fletcher
+    }
+
+    pub fn reset(&mut self) -> u16 {
+        self.init(self.initial)
+    }
+
+    pub fn get_checksum_folded(&self) -> u8 {
+        let [high, low] = self.get_checksum().to_be_bytes();
+        high ^ low
+    }
+}
+
+impl Checksum<u16> for Check16Fletcher
+{
+    fn get_accum(&self) -> u16 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u16) -> u16 {
+        self.accum = (((val >> 8) % 255) << 8) | ((val & 0xFF) % 255);
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u16 {
+        let sum1 = ((self.accum & 0xFF) + val as u16) % 255;
+        let sum2 = ((self.accum >> 8) + sum1) % 255;
+        self.accum = (sum2 << 8) | sum1;
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check16Fletcher {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_values() {
+        assert_eq!(Check16Fletcher::new(0).calculate_from_string("abcde"), 0xC8F0);
+        assert_eq!(Check16Fletcher::new(0).calculate_from_string("abcdef"), 0x2057);
+        assert_eq!(Check16Fletcher::new(0).calculate_from_string("abcdefgh"), 0x0627)
+    }
+
+    #[test]
+    fn folded_checksum_is_the_xor_of_the_bytes() {
+        let mut fletcher = Check16Fletcher::new(0);
+        fletcher.calculate_from_string("abcdef");
+        assert_eq!(fletcher.get_checksum_folded(), 0x20 ^ 0x57);
+        fletcher = Check16Fletcher::new(0);
+        fletcher.calculate_from_string("abcdefgh");
+        assert_eq!(fletcher.get_checksum_folded(), 0x21)
+    }
+
+    #[test]
+    fn init_reduces_each_byte_modulo_255() {
+        let mut fletcher = Check16Fletcher::new(0);
+        assert_eq!(fletcher.init(0xFFFF), 0x0000);
+        assert_eq!(fletcher.init(0xFF12), 0x0012)
+    }
+
+    #[test]
+    fn sums_stay_below_255() {
+        let mut fletcher = Check16Fletcher::new(0);
+        fletcher.calculate_from_byte_array(&[0xFF; 1000]);
+        assert_eq!(fletcher.get_accum(), 0x0000);
+        assert_eq!(fletcher.add(0xFE), 0xFEFE)
+    }
+
+    #[test]
+    fn order_sensitive() {
+        let a = Check16Fletcher::new(0).calculate_from_byte_array(&[0x01, 0x02]);
+        let b = Check16Fletcher::new(0).calculate_from_byte_array(&[0x02, 0x01]);
+        assert_ne!(a, b)
+    }
+}
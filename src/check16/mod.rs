@@ -0,0 +1,54 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check16
+//!
+//! 16-bit checksum types, implementing the Checksum<u16> trait with the same methods as the
+//! 8-bit types, for protocols that mix 8 and 16-bit checksums: CRC-16 in the Rocksoft model,
+//! with the CCITT (XMODEM, KERMIT, IBM-3740) and Modbus RTU variants as constants, and
+//! Fletcher-16.
+//!
+//! The Checksum trait must be in scope to call the methods (the example needs the crc feature):
+//!
+#![cfg_attr(feature = "crc", doc = "```rust")]
+#![cfg_attr(not(feature = "crc"), doc = "```ignore")]
+//! use crate::check8::checksum::Checksum;
+//! use crate::check8::check16::{Check16Crc, CRC16_MODBUS};
+//! fn main() {
+//!     let mut crc = Check16Crc::with_params(CRC16_MODBUS);
+//!     assert_eq!(crc.calculate_from_string("123456789"), 0x4B37);
+//! }
+//! ```
+
+#[cfg(feature = "crc")]
+mod crc;  // implements Check16Crc - a CRC checksum type of up to 16 bits
+mod fletcher;  // implements Check16Fletcher - Fletcher's checksum over bytes
+
+#[cfg(feature = "crc")]
+pub use crc::{
+    Check16Crc, Check16CrcParams, CRC16_ARC, CRC16_IBM_3740, CRC16_KERMIT, CRC16_MODBUS, CRC16_XMODEM,
+};
+pub use fletcher::Check16Fletcher;
@@ -0,0 +1,135 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check32Crc
+//!
+//! A CRC checksum type of up to 32 bits with tests, implements the Checksum<u32> trait.
+//! Like Check8Crc and Check16Crc it follows the Rocksoft model and uses a lookup table generated
+//! from the parameters. The type is defined, with Check16Crc, by the wide_crc! macro of crcwide.
+
+use crate::checksum::Checksum;  // for the Checksum trait
+use crate::crcwide::{wide_crc, CrcModel};
+use crate::Check8Error;  // for try_with_params
+
+wide_crc! {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::checksum::Checksum;
+    /// use crate::check8::check32::{Check32Crc, CRC32_ISCSI, CRC32_ISO_HDLC};
+    /// fn main() {
+    ///     assert_eq!(Check32Crc::with_params(CRC32_ISO_HDLC).calculate_from_string("123456789"), 0xCBF43926);
+    ///     assert_eq!(Check32Crc::with_params(CRC32_ISCSI).calculate_from_string("123456789"), 0xE3069283);
+    /// }
+    /// ```
+    ///
+    Check32Crc(Check32CrcParams, u32, 32, "Check32Crc")
+}
+
+/// CRC-32/ISO-HDLC, the CRC-32 of Ethernet, HDLC, zip, gzip and PNG.
+pub const CRC32_ISO_HDLC: Check32CrcParams = Check32CrcParams {
+    init: 0xFFFFFFFF,
+    refin: true,
+    refout: true,
+    xorout: 0xFFFFFFFF,
+    ..Check32CrcParams::new(0x04C11DB7)
+};
+
+/// CRC-32/ISCSI, known as CRC-32C (Castagnoli), as in iSCSI, SCTP, ext4 and Btrfs.
+pub const CRC32_ISCSI: Check32CrcParams = Check32CrcParams { poly: 0x1EDC6F41, ..CRC32_ISO_HDLC };
+
+/// CRC-32/BZIP2, the unreflected form of the CRC-32 polynomial, as in bzip2 and AAL5.
+pub const CRC32_BZIP2: Check32CrcParams =
+    Check32CrcParams { init: 0xFFFFFFFF, xorout: 0xFFFFFFFF, ..Check32CrcParams::new(0x04C11DB7) };
+
+/// CRC-32/MPEG-2, as in MPEG transport stream tables.
+pub const CRC32_MPEG2: Check32CrcParams = Check32CrcParams { init: 0xFFFFFFFF, ..Check32CrcParams::new(0x04C11DB7) };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn catalogue_check_values() {
+        for (params, check) in [
+            (CRC32_ISO_HDLC, 0xCBF43926),
+            (CRC32_ISCSI, 0xE3069283),
+            (CRC32_BZIP2, 0xFC891918),
+            (CRC32_MPEG2, 0x0376E6E7),
+            // CRC-32/CKSUM, as in POSIX cksum
+            (Check32CrcParams { xorout: 0xFFFFFFFF, ..Check32CrcParams::new(0x04C11DB7) }, 0x765E7680),
+            // CRC-24/OPENPGP, narrower than the register
+            (Check32CrcParams { width: 24, init: 0xB704CE, ..Check32CrcParams::new(0x864CFB) }, 0x21CF02),
+        ] {
+            assert_eq!(Check32Crc::with_params(params).calculate_from_byte_array(CHECK), check, "{:?}", params);
+        }
+    }
+
+    #[test]
+    fn known_strings() {
+        let mut crc = Check32Crc::with_params(CRC32_ISO_HDLC);
+        assert_eq!(crc.calculate_from_string("The quick brown fox jumps over the lazy dog"), 0x414FA339);
+        crc.reset();
+        assert_eq!(crc.calculate_from_byte_array(&[]), 0x00000000)
+    }
+
+    #[test]
+    fn table_matches_bitwise() {
+        for params in [CRC32_ISCSI, CRC32_BZIP2] {
+            let model = params.model();
+            let mut crc = Check32Crc::with_params(params);
+            let mut reg = model.init_register();
+            for byte in 0..=255u8 {
+                crc.add(byte);
+                reg = model.add_bitwise(reg, byte);
+            }
+            assert_eq!(crc.get_checksum() as u64, model.finalise(reg));
+        }
+    }
+
+    #[test]
+    fn init_and_reset() {
+        let mut crc = Check32Crc::with_params(CRC32_ISO_HDLC);
+        assert_eq!(crc.get_accum(), 0xFFFFFFFF);
+        assert_eq!(crc.init(0x12345678), 0x12345678);
+        assert_eq!(crc.reset(), 0xFFFFFFFF);
+        crc.calculate_from_byte_array(CHECK);
+        crc.reset();
+        assert_eq!(crc.calculate_from_byte_array(CHECK), 0xCBF43926)
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_resumes_calculation() {
+        let mut crc = Check32Crc::with_params(CRC32_ISCSI);
+        crc.calculate_from_byte_array(&CHECK[..4]);
+        let json = serde_json::to_string(&crc).unwrap();
+        let mut resumed: Check32Crc = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.calculate_from_byte_array(&CHECK[4..]), 0xE3069283)
+    }
+}
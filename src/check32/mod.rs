@@ -0,0 +1,46 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check32
+//!
+//! 32-bit checksum types, implementing the Checksum<u32> trait with the same methods as the
+//! 8 and 16-bit types: CRC-32 in the Rocksoft model, with CRC-32/ISO-HDLC (the CRC-32 of
+//! Ethernet, zip and PNG) and CRC-32C (iSCSI, ext4, SCTP) as constants.
+//!
+//! The Checksum trait must be in scope to call the methods:
+//!
+//! ```rust
+//! use crate::check8::checksum::Checksum;
+//! use crate::check8::check32::{Check32Crc, CRC32_ISO_HDLC};
+//! fn main() {
+//!     let mut crc = Check32Crc::with_params(CRC32_ISO_HDLC);
+//!     assert_eq!(crc.calculate_from_string("123456789"), 0xCBF43926);
+//! }
+//! ```
+
+mod crc;  // implements Check32Crc - a CRC checksum type of up to 32 bits
+
+pub use crc::{Check32Crc, Check32CrcParams, CRC32_BZIP2, CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_MPEG2};
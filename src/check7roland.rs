@@ -0,0 +1,148 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check7Roland
+//!
+//! A 7-bit Roland MIDI System Exclusive checksum type with tests, implements Check8 trait.
+//! The address and data bytes of a Data Set (DT1) or Data Request (RQ1) message are summed,
+//! and the checksum is the value which brings the low 7 bits of the sum to zero, so that it
+//! is itself a valid MIDI data byte (0x00 to 0x7F).
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check7Roland
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator.
+/// - get_accum: Retrieves the current value of the accumulator, the wrapping sum of the bytes added.
+/// - get_checksum: Retrieves the Roland checksum, 128 minus the low 7 bits of the sum, masked to 7 bits.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check7Roland, Checksum};
+/// fn main() {
+///     // GS reset, F0 41 10 42 12 40 00 7F 00 41 F7: the checksum covers the address and data
+///     let mut roland = Check7Roland::new(0x00);
+///     let result = roland.calculate_from_byte_array(&[0x40, 0x00, 0x7F, 0x00]);
+///     assert_eq!(result, 0x41);
+/// }
+/// ```
+///
+impl Check8 for Check7Roland
+{
+    fn new(initial: u8) -> Self {
+        Check7Roland { accum: initial, initial }
+    }
+}
+
+impl Check7Roland
+{
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Checksum<u8> for Check7Roland
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn get_checksum(&self) -> u8 {
+        0x80u8.wrapping_sub(self.accum & 0x7F) & 0x7F
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.accum.wrapping_add(val);
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check7Roland {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sets_initial() {
+        let roland = Check7Roland::new(10);
+        assert_eq!(roland.get_accum(), 10)
+    }
+
+    #[test]
+    fn gs_reset() {
+        let mut roland = Check7Roland::new(0);
+        assert_eq!(roland.calculate_from_byte_array(&[0x40, 0x00, 0x7F, 0x00]), 0x41)
+    }
+
+    #[test]
+    fn sum_multiple_of_128_gives_zero() {
+        let mut roland = Check7Roland::new(0);
+        assert_eq!(roland.calculate_from_byte_array(&[]), 0x00);
+        let mut roland = Check7Roland::new(0);
+        assert_eq!(roland.calculate_from_byte_array(&[0x40, 0x40]), 0x00)
+    }
+
+    #[test]
+    fn checksum_is_a_midi_data_byte() {
+        for val in 0..=255u8 {
+            let mut roland = Check7Roland::new(0);
+            assert!(roland.calculate_from_byte_array(&[val]) <= 0x7F)
+        }
+    }
+
+    #[test]
+    fn data_plus_checksum_is_zero_mod_128() {
+        let data = [0x10, 0x00, 0x00, 0x01, 0x02, 0x7F, 0x33];
+        let checksum = Check7Roland::new(0).calculate_from_byte_array(&data);
+        let total = data.iter().fold(checksum, |acc, &b| acc.wrapping_add(b));
+        assert_eq!(total & 0x7F, 0x00)
+    }
+}
@@ -0,0 +1,153 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Bsd
+//!
+//! An 8-bit BSD rotating sum checksum type with tests, implements Check8 trait.
+//! An 8-bit adaptation of the BSD sum algorithm: the accumulator is rotated right by one bit
+//! before each byte is added, so that transposed bytes give a different checksum.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8Bsd
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Rotates the accumulator right by one bit, adds a given value and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Bsd, Checksum};
+/// fn main() {
+///     let mut sum = Check8Bsd::new(0x00);
+///     sum.init(0x03);
+///     let result = sum.add(0x01);
+///     // 0x03 rotated right is 0x81, plus 0x01
+///     assert_eq!(result, 0x82);
+///     assert_eq!(sum.get_accum(), 0x82);
+/// }
+/// ```
+///
+impl Check8 for Check8Bsd
+{
+    fn new(initial: u8) -> Self {
+        Check8Bsd { accum: initial, initial }
+    }
+}
+
+impl Check8Bsd
+{
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Checksum<u8> for Check8Bsd
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.accum.rotate_right(1).wrapping_add(val);
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Bsd {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sets_initial() {
+        let sum = Check8Bsd::new(10);
+        assert_eq!(sum.get_accum(), 10)
+    }
+
+    #[test]
+    fn init_with_value_returns_value() {
+        let mut sum = Check8Bsd::new(0);
+        let result = sum.init(255);
+        assert_eq!(result, 255)
+    }
+
+    #[test]
+    fn add_rotates_then_adds() {
+        let mut sum = Check8Bsd::new(0);
+        sum.init(0x03);
+        assert_eq!(sum.add(0x01), 0x82);
+        // rotation carries the low bit round to the top, and the addition wraps
+        sum.init(0xFF);
+        assert_eq!(sum.add(0x01), 0x00)
+    }
+
+    #[test]
+    fn calculate_from_string_returns_correct_sum() {
+        let test_string = "hello";
+
+        // calculate the 8-bit BSD sum of the test string, with the rotation written out
+        let mut expected: u8 = 0;
+        for val in test_string.as_bytes() {
+            expected = ((expected >> 1) | ((expected & 0x01) << 7)).wrapping_add(*val);
+        }
+
+        let mut sum = Check8Bsd::new(0);
+        let result = sum.calculate_from_string(test_string);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn detects_transposed_bytes() {
+        let mut a = Check8Bsd::new(0);
+        let mut b = Check8Bsd::new(0);
+        assert_ne!(a.calculate_from_byte_array(&[0x01, 0x02]), b.calculate_from_byte_array(&[0x02, 0x01]))
+    }
+}
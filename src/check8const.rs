@@ -0,0 +1,185 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 const functions
+//!
+//! One-shot checksum functions that can be evaluated at compile time, e.g. to embed the
+//! expected checksum of a firmware image section as a constant. Each produces the same
+//! result as the corresponding Check8 type processing the whole slice.
+//!
+//! Trait methods cannot be const, so these stand alongside the Check8 types rather than
+//! replacing them. The CRC is calculated bitwise, so no lookup table is needed at runtime.
+
+#[cfg(feature = "crc")]
+use crate::Check8CrcParams;     // for const_crc8_with_params
+
+/// Calculates the 8-bit wrapping arithmetic sum of a slice, as Check8Sum would.
+///
+/// # Examples
+///
+/// ```rust
+/// const CK: u8 = check8::const_sum8(0x00, b"BOOT");
+/// fn main() {
+///     assert_eq!(CK, 0x34);
+/// }
+/// ```
+///
+#[cfg(feature = "sum")]
+pub const fn const_sum8(initial: u8, array: &[u8]) -> u8 {
+    let mut accum = initial;
+    let mut rest = array;
+    while let [byte, tail @ ..] = rest {
+        accum = accum.wrapping_add(*byte);
+        rest = tail;
+    }
+    accum
+}
+
+/// Calculates the 8-bit XOR of a slice, as Check8Xor would.
+///
+/// # Examples
+///
+/// ```rust
+/// const CK: u8 = check8::const_xor8(0x00, b"BOOT");
+/// fn main() {
+///     assert_eq!(CK, 0x16);
+/// }
+/// ```
+///
+#[cfg(feature = "xor")]
+pub const fn const_xor8(initial: u8, array: &[u8]) -> u8 {
+    let mut accum = initial;
+    let mut rest = array;
+    while let [byte, tail @ ..] = rest {
+        accum ^= *byte;
+        rest = tail;
+    }
+    accum
+}
+
+/// Calculates the 8-bit CRC of a slice with the accumulator initialised to zero, as Check8Crc would.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Checksum};
+/// const CK: u8 = check8::const_crc8(0x07, b"BOOT");
+/// fn main() {
+///     assert_eq!(CK, Check8Crc::new(0x07).calculate_from_string("BOOT"));
+/// }
+/// ```
+///
+#[cfg(feature = "crc")]
+pub const fn const_crc8(poly: u8, array: &[u8]) -> u8 {
+    const_crc8_with_params(Check8CrcParams::new(poly), array)
+}
+
+/// Calculates the CRC of a slice for any set of CRC parameters, as Check8Crc::with_params would.
+#[cfg(feature = "crc")]
+pub const fn const_crc8_with_params(params: Check8CrcParams, array: &[u8]) -> u8 {
+    let mut reg = params.init_register();
+    let mut rest = array;
+    while let [byte, tail @ ..] = rest {
+        reg = params.add_bitwise(reg, *byte);
+        rest = tail;
+    }
+    params.finalise(reg)
+}
+
+/// Adds a single byte to a CRC accumulator, returning the updated value.
+#[cfg(feature = "crc")]
+pub const fn const_crc8_add(poly: u8, accum: u8, val: u8) -> u8 {
+    Check8CrcParams::new(poly).add_bitwise(accum, val)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8, Checksum};
+    #[cfg(feature = "crc")]
+    use crate::Check8Crc;
+    #[cfg(feature = "sum")]
+    use crate::Check8Sum;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    const TEST_DATA: &[u8] = b"123456789";
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn const_sum8_matches_check8sum() {
+        const RESULT: u8 = const_sum8(0x10, TEST_DATA);
+        let mut sum = Check8Sum::new(0x10);
+        assert_eq!(RESULT, sum.calculate_from_byte_array(TEST_DATA));
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn const_xor8_matches_check8xor() {
+        const RESULT: u8 = const_xor8(0x10, TEST_DATA);
+        let mut xor = Check8Xor::new(0x10);
+        assert_eq!(RESULT, xor.calculate_from_byte_array(TEST_DATA));
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn const_crc8_matches_check8crc() {
+        const RESULT: u8 = const_crc8(0x07, TEST_DATA);
+        assert_eq!(RESULT, 0xF4);
+        let mut crc = Check8Crc::new(0x07);
+        assert_eq!(RESULT, crc.calculate_from_byte_array(TEST_DATA));
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn const_crc8_with_params_uses_init() {
+        const RESULT: u8 = const_crc8_with_params(Check8CrcParams { init: 0xFF, ..Check8CrcParams::new(0x31) }, TEST_DATA);
+        let mut crc = Check8Crc::new(0x31);
+        crc.init(0xFF);
+        assert_eq!(RESULT, crc.calculate_from_byte_array(TEST_DATA));
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn const_crc8_with_params_handles_narrow_reflected_crcs() {
+        // CRC-5/USB
+        const PARAMS: Check8CrcParams = Check8CrcParams {
+            width: 5, poly: 0x05, init: 0x1F, refin: true, refout: true, xorout: 0x1F,
+        };
+        const RESULT: u8 = const_crc8_with_params(PARAMS, TEST_DATA);
+        assert_eq!(RESULT, 0x19);
+        assert_eq!(RESULT, Check8Crc::with_params(PARAMS).calculate_from_byte_array(TEST_DATA));
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn const_crc8_add_matches_table() {
+        let table = Check8Crc::generate_table(0x1D);
+        for (i, entry) in table.iter().enumerate() {
+            assert_eq!(const_crc8_add(0x1D, 0, i as u8), *entry);
+        }
+    }
+}
@@ -26,27 +26,262 @@ SOFTWARE.
 
 //! # Check8Crc
 //!
-//! An 8-bit CRC checksum type with tests, implements Check8 trait.
-//! Uses a lookup table generated from a polynomial.
+//! A CRC checksum type of up to 8 bits with tests, implements Check8 trait.
+//! Uses a lookup table generated from the CRC parameters: width, polynomial, initial value,
+//! input and output reflection, and final XOR, as in the Rocksoft model of CRC algorithms.
+//! Widths below 8 (e.g. CRC-4, CRC-5, CRC-7) are handled by working with the register
+//! left-aligned in a byte, or right-aligned for reflected algorithms.
+//! Also provides crc8_combine, which merges the CRCs of two concatenated segments.
 
 use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Error;     // for add_bits and try_with_params
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Check8CrcRepr", into = "Check8CrcRepr"))]
 pub struct Check8Crc {
+    params: Check8CrcParams,
     accum: u8,
-    table: [u8; 256],
+    table: CrcTable,
+}
+
+// The lookup table: generated for the instance, or a static one borrowed with with_table.
+// The owned table is kept inline, as it always was, so that no_std targets need no allocator.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone)]
+enum CrcTable {
+    Owned([u8; 256]),
+    Borrowed(&'static [u8; 256]),
+}
+
+impl CrcTable {
+    const fn get(&self) -> &[u8; 256] {
+        match self {
+            CrcTable::Owned(table) => table,
+            CrcTable::Borrowed(table) => table,
+        }
+    }
+}
+
+/// The parameters that determine how a CRC is calculated, following the Rocksoft model.
+///
+/// - width: The number of bits in the CRC, 1 to 8.
+/// - poly: The polynomial, right-aligned and without the implicit top bit, in normal (unreflected) form.
+/// - init: The initial register value, in normal (unreflected) form.
+/// - refin: Whether each input byte is processed least significant bit first.
+/// - refout: Whether the final register is reflected before the final XOR.
+/// - xorout: The value XORed with the register to give the checksum.
+///
+/// Check8CrcParams::new gives the plain CRC-8 for a polynomial (width 8, init 0, no
+/// reflection, no final XOR), the other fields can be set with struct update syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Check8CrcParams, Checksum};
+/// fn main() {
+///     // CRC-8/MAXIM, as used by Dallas/Maxim 1-Wire devices
+///     let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+///     let mut crc = Check8Crc::with_params(params);
+///     assert_eq!(crc.calculate_from_string("123456789"), 0xA1);
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(C)]  // passed by value through the C interface of the ffi feature
+pub struct Check8CrcParams {
+    pub width: u8,
+    pub poly: u8,
+    pub init: u8,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u8,
+}
+
+// The serialised form of Check8Crc: the table is derived from the parameters, so it is
+// regenerated on deserialisation rather than stored.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Check8Crc")]
+struct Check8CrcRepr {
+    params: Check8CrcParams,
+    accum: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Check8CrcRepr> for Check8Crc {
+    type Error = Check8Error;
+
+    fn try_from(repr: Check8CrcRepr) -> Result<Self, Check8Error> {
+        let mut crc = Check8Crc::try_with_params(repr.params)?;
+        crc.init(repr.accum);
+        Ok(crc)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Check8Crc> for Check8CrcRepr {
+    fn from(crc: Check8Crc) -> Self {
+        Check8CrcRepr { params: crc.params, accum: crc.get_accum() }
+    }
+}
+
+impl Check8CrcParams {
+    /// The plain CRC-8 for a polynomial: width 8, init 0, no reflection and no final XOR.
+    pub const fn new(poly: u8) -> Self {
+        Check8CrcParams { width: 8, poly, init: 0x00, refin: false, refout: false, xorout: 0x00 }
+    }
+
+    /// Checks that the width is 1 to 8 bits, the only condition the CRC types check.
+    pub const fn validate(&self) -> Result<(), Check8Error> {
+        if self.width >= 1 && self.width <= 8 { Ok(()) } else { Err(Check8Error::InvalidWidth(self.width)) }
+    }
+
+    // The number of unused bits below a left-aligned register.
+    const fn shift(&self) -> u32 {
+        assert!(self.width >= 1 && self.width <= 8, "CRC width must be 1 to 8 bits");
+        (8 - self.width) as u32
+    }
+
+    const fn mask(&self) -> u8 {
+        0xFF >> self.shift()
+    }
+
+    // The polynomial as the table and bitwise engines use it: reflected for reflected
+    // algorithms, left-aligned otherwise.
+    pub(crate) const fn engine_poly(&self) -> u8 {
+        if self.refin {
+            reflect(self.poly & self.mask(), self.width)
+        } else {
+            (self.poly & self.mask()) << self.shift()
+        }
+    }
+
+    // Converts a right-aligned register value to the engine's internal form.
+    pub(crate) const fn encode_register(&self, val: u8) -> u8 {
+        if self.refin {
+            val & self.mask()
+        } else {
+            (val & self.mask()) << self.shift()
+        }
+    }
+
+    // Converts the engine's internal register to a right-aligned value.
+    pub(crate) const fn decode_register(&self, reg: u8) -> u8 {
+        if self.refin {
+            reg
+        } else {
+            reg >> self.shift()
+        }
+    }
+
+    // The internal register the engine starts from.
+    pub(crate) const fn init_register(&self) -> u8 {
+        if self.refin {
+            reflect(self.init & self.mask(), self.width)
+        } else {
+            self.encode_register(self.init)
+        }
+    }
+
+    // Applies output reflection and the final XOR to the internal register.
+    pub(crate) const fn finalise(&self, reg: u8) -> u8 {
+        let val = self.decode_register(reg);
+        let val = if self.refin != self.refout { reflect(val, self.width) } else { val };
+        (val ^ self.xorout) & self.mask()
+    }
+
+    // Reverses finalise, recovering the internal register from a checksum.
+    pub(crate) const fn unfinalise(&self, crc: u8) -> u8 {
+        let val = (crc ^ self.xorout) & self.mask();
+        let val = if self.refin != self.refout { reflect(val, self.width) } else { val };
+        self.encode_register(val)
+    }
+
+    // Adds a byte to the internal register one bit at a time, without a table.
+    pub(crate) const fn add_bitwise(&self, reg: u8, val: u8) -> u8 {
+        let poly = self.engine_poly();
+        let mut reg = reg ^ val;
+        let mut bit = 0;
+        while bit < 8 {
+            reg = if self.refin {
+                if (reg & 0x01) != 0 { (reg >> 1) ^ poly } else { reg >> 1 }
+            } else if (reg & 0x80) != 0 {
+                (reg << 1) ^ poly
+            } else {
+                reg << 1
+            };
+            bit += 1;
+        }
+        reg
+    }
+
+    // Adds the low nbits bits of a value to the internal register one bit at a time, most
+    // significant first for unreflected algorithms and least significant first for reflected.
+    pub(crate) const fn add_bits_bitwise(&self, reg: u8, value: u8, nbits: u8) -> u8 {
+        let poly = self.engine_poly();
+        let mut reg = reg;
+        let mut n = 0;
+        while n < nbits {
+            if self.refin {
+                let bit = (value >> n) & 0x01;
+                let low = (reg ^ bit) & 0x01;
+                reg >>= 1;
+                if low != 0 {
+                    reg ^= poly;
+                }
+            } else {
+                let bit = (value >> (nbits - 1 - n)) & 0x01;
+                let top = (reg >> 7) ^ bit;
+                reg <<= 1;
+                if top != 0 {
+                    reg ^= poly;
+                }
+            }
+            n += 1;
+        }
+        reg
+    }
+}
+
+/// Reverses the order of the low width bits of a value.
+pub(crate) const fn reflect(val: u8, width: u8) -> u8 {
+    val.reverse_bits() >> (8 - width)
 }
 
 /// # Provided Methods
 ///
 /// - new: Creates a new instance of the type, generates the CRC lookup table from the provided polynomial.
-/// - get_accum: Retrieves the current value of the accumulator.
+/// - with_params: Creates a new instance for any CRC of up to 8 bits, described by a Check8CrcParams. A const fn, so a constant instance carries a table generated at compile time.
+/// - try_with_params: As with_params, but returns an error for an invalid width rather than panicking.
+/// - with_table: Creates a new instance that borrows a static lookup table instead of generating one.
+/// - with_params_cached: As with_params, with the lookup table from a process-wide cache. Requires the std feature.
+/// - with_width: Creates a new instance for a CRC narrower than 8 bits, e.g. CRC-5 or CRC-7.
+/// - get_accum: Retrieves the current value of the accumulator, right-aligned for widths below 8.
+/// - get_checksum: Retrieves the checksum, after output reflection and the final XOR.
+/// - get_checksum_left_aligned: Retrieves the checksum shifted to the top of the byte, as some protocols transmit it.
+/// - get_poly: Retrieves the polynomial the lookup table was generated from.
+/// - get_params: Retrieves the parameters the instance was created with.
+/// - generate_table: Generates the lookup table for a polynomial, a const fn so it can be used at compile time.
 /// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Initialises the accumulator with the initial value from the parameters.
 /// - add: Adds a given value to the accumulator using the CRC algorithm and returns the updated value.
+/// - add_zeros: Adds a run of zero bytes in O(log n) steps.
+/// - add_bits: Adds the low nbits bits of a value, in the algorithm's bit order, for messages that are not a whole number of bytes.
+///
+/// For reflected algorithms the accumulator holds the register in reflected bit order, as it
+/// does in any table-driven reflected CRC, and init takes a value in that order.
+///
+/// Unlike Check8Sum and Check8Xor there is no remove: each byte's contribution to a CRC
+/// depends on the position of the byte, so it cannot be undone without the bytes that followed it.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use crate::check8::{Check8, Check8Crc};
+/// use crate::check8::{Check8, Check8Crc, Checksum};
 /// fn main() {
 ///     // Standard CRC-8 polynomial 0x07
 ///     let mut crc = Check8Crc::new(0x07);
@@ -55,54 +290,322 @@ pub struct Check8Crc {
 ///     // For poly 0x07 and input 0x01, the result is 0x07
 ///     assert_eq!(result, 0x07);
 ///     assert_eq!(crc.get_accum(), 0x07);
+///
+///     // CRC-7/MMC, as used by SD cards, has a 7-bit result
+///     let mut crc7 = Check8Crc::with_width(7, 0x09, 0x00, false, false, 0x00);
+///     assert_eq!(crc7.calculate_from_string("123456789"), 0x75);
+///     assert_eq!(crc7.get_checksum_left_aligned(), 0xEA);
 /// }
 /// ```
 ///
 impl Check8Crc {
-    fn generate_table(poly: u8) -> [u8; 256] {
+    /// Creates a new instance from the full set of CRC parameters, or returns
+    /// Check8Error::InvalidWidth if the width is not 1 to 8 bits, for parameters that are not
+    /// known to be valid at compile time.
+    pub const fn try_with_params(params: Check8CrcParams) -> Result<Self, Check8Error> {
+        match params.validate() {
+            Ok(()) => Ok(Self::with_params(params)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a new instance from the full set of CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not 1 to 8 bits.
+    pub const fn with_params(params: Check8CrcParams) -> Self {
+        Check8Crc {
+            params,
+            accum: params.init_register(),
+            table: CrcTable::Owned(Self::generate_table_with_params(params)),
+        }
+    }
+
+    /// Creates a new instance that uses a lookup table held elsewhere, e.g. in flash, generated
+    /// offline or shared with C code, rather than generating its own copy. Nothing is checked:
+    /// the table must be the one generate_table_with_params gives for the parameters, or the
+    /// results are wrong.
+    ///
+    /// - **Parameter**: table - The lookup table, borrowed for the life of the program.
+    /// - **Parameter**: params - The parameters the table was generated for.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::{Check8, Check8Crc, Check8CrcParams, Checksum};
+    /// fn main() {
+    ///     static TABLE: [u8; 256] = Check8Crc::generate_table(0x07);
+    ///     let mut crc = Check8Crc::with_table(&TABLE, Check8CrcParams::new(0x07));
+    ///     assert_eq!(crc.calculate_from_string("123456789"), 0xF4);
+    /// }
+    /// ```
+    ///
+    pub const fn with_table(table: &'static [u8; 256], params: Check8CrcParams) -> Self {
+        Check8Crc { params, accum: params.init_register(), table: CrcTable::Borrowed(table) }
+    }
+
+    /// Creates a new instance from the full set of CRC parameters, taking the lookup table from
+    /// a process-wide cache rather than generating it, for code that creates many instances
+    /// with the same custom polynomial. Each table is generated once, on first use, and is then
+    /// borrowed by every later instance with the same polynomial and input reflection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not 1 to 8 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::{Check8, Check8Crc, Check8CrcParams, Checksum};
+    /// fn main() {
+    ///     let params = Check8CrcParams::new(0x9B);
+    ///     for _ in 0..1000 {
+    ///         let mut crc = Check8Crc::with_params_cached(params);
+    ///         assert_eq!(crc.calculate_from_string("123456789"), 0xEA);
+    ///     }
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "std")]
+    pub fn with_params_cached(params: Check8CrcParams) -> Self {
+        Check8Crc {
+            params,
+            accum: params.init_register(),
+            table: CrcTable::Borrowed(crate::check8crccache::cached_table(params)),
+        }
+    }
+
+    /// Creates a new instance for a CRC of the given width, with the polynomial and initial
+    /// value right-aligned in normal (unreflected) form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not 1 to 8 bits.
+    pub const fn with_width(width: u8, poly: u8, init: u8, refin: bool, refout: bool, xorout: u8) -> Self {
+        Self::with_params(Check8CrcParams { width, poly, init, refin, refout, xorout })
+    }
+
+    pub fn get_poly(&self) -> u8 {
+        self.params.poly
+    }
+
+    pub fn get_params(&self) -> Check8CrcParams {
+        self.params
+    }
+
+    pub fn get_checksum_left_aligned(&self) -> u8 {
+        self.get_checksum() << self.params.shift()
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.accum = self.params.init_register();
+        self.get_accum()
+    }
+
+    /// Adds count zero bytes and returns the updated value, in O(log count) steps rather than
+    /// count, for long runs of zeros such as the holes in a sparse disk image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::{Check8, Check8Crc, Checksum};
+    /// fn main() {
+    ///     let mut fast = Check8Crc::new(0x07);
+    ///     fast.calculate_from_string("header");
+    ///     fast.add_zeros(100_000);
+    ///
+    ///     let mut slow = Check8Crc::new(0x07);
+    ///     slow.calculate_from_string("header");
+    ///     slow.calculate_from_byte_array(&vec![0x00; 100_000]);
+    ///     assert_eq!(fast.get_checksum(), slow.get_checksum());
+    /// }
+    /// ```
+    ///
+    pub fn add_zeros(&mut self, count: u64) -> u8 {
+        self.accum = gf2_advance(self.accum, count, self.params);
+        self.get_accum()
+    }
+
+    // The internal register after adding data to a zero register. The CRC is linear, so adding
+    // the data to any other register gives that register advanced over the data, XORed with this.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn register_from_zero(&self, data: &[u8]) -> u8 {
+        let table = self.table.get();
+        data.iter().fold(0, |reg, &val| table[(reg ^ val) as usize])
+    }
+
+    // Adds data of length len whose register_from_zero is reg, as if it had been added byte by byte.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn append_register(&mut self, reg: u8, len: u64) -> u8 {
+        self.accum = gf2_advance(self.accum, len, self.params) ^ reg;
+        self.get_accum()
+    }
+
+    /// Generates the 256-entry lookup table for a polynomial, usable at compile time.
+    pub const fn generate_table(poly: u8) -> [u8; 256] {
+        Self::generate_table_with_params(Check8CrcParams::new(poly))
+    }
+
+    /// Generates the 256-entry lookup table for a set of CRC parameters, usable at compile time.
+    pub const fn generate_table_with_params(params: Check8CrcParams) -> [u8; 256] {
         let mut table = [0u8; 256];
-        for i in 0..256 {
-            let mut crc = i as u8;
-            for _ in 0..8 {
-                if (crc & 0x80) != 0 {
-                    crc = (crc << 1) ^ poly;
-                } else {
-                    crc <<= 1;
-                }
-            }
-            table[i] = crc;
+        let mut i = 0;
+        while i < 256 {
+            table[i] = params.add_bitwise(0, i as u8);
+            i += 1;
         }
         table
     }
 }
 
 impl Check8 for Check8Crc {
-    fn new(poly: u8) -> impl Check8 {
-        Check8Crc {
-            accum: 0,
-            table: Self::generate_table(poly),
+    fn new(poly: u8) -> Self {
+        Self::with_params(Check8CrcParams::new(poly))
+    }
+
+    // processed bit by bit, as the table only covers whole bytes
+    fn add_bits(&mut self, value: u8, nbits: u8) -> Result<u8, Check8Error> {
+        if nbits > 8 {
+            return Err(Check8Error::InvalidBitCount(nbits));
         }
+        self.accum = self.params.add_bits_bitwise(self.accum, value, nbits);
+        Ok(self.get_accum())
     }
+}
 
+impl Checksum<u8> for Check8Crc {
     fn get_accum(&self) -> u8 {
-        self.accum
+        self.params.decode_register(self.accum)
+    }
+
+    fn get_checksum(&self) -> u8 {
+        self.params.finalise(self.accum)
     }
 
     fn init(&mut self, val: u8) -> u8 {
-        self.accum = val;
-        self.accum
+        self.accum = self.params.encode_register(val);
+        self.get_accum()
     }
 
     fn add(&mut self, val: u8) -> u8 {
-        self.accum = self.table[(self.accum ^ val) as usize];
-        self.accum
+        self.accum = self.table.get()[(self.accum ^ val) as usize];
+        self.get_accum()
+    }
+
+    // the table is looked up once rather than for every byte
+    fn calculate_from_byte_array(&mut self, array: &[u8]) -> u8 {
+        let table = self.table.get();
+        self.accum = array.iter().fold(self.accum, |reg, &val| table[(reg ^ val) as usize]);
+        self.get_checksum()
     }
 }
 
+/// Combines the CRCs of two consecutive segments A and B into the CRC of A followed by B,
+/// without reprocessing any bytes, in the manner of zlib's crc32_combine.
+///
+/// - **Parameter**: crc_a - The CRC of the first segment.
+/// - **Parameter**: crc_b - The CRC of the second segment, calculated independently with the same params.
+/// - **Parameter**: len_b - The length of the second segment in bytes.
+/// - **Parameter**: params - The CRC parameters both CRCs were calculated with.
+/// - **Returns**: The CRC of the concatenated segments.
+///
+/// A CRC is linear over GF(2), so the whole-message CRC is crc_a advanced over len_b zero
+/// bytes, combined with crc_b. The advance is the one add_zeros makes: repeatedly squaring
+/// the 8x8 bit matrix for "add one zero byte", so it takes O(log len_b) steps.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Check8CrcParams, Checksum, crc8_combine};
+/// fn main() {
+///     let params = Check8CrcParams::new(0x07);
+///     let crc_a = Check8Crc::new(0x07).calculate_from_string("hello, ");
+///     let crc_b = Check8Crc::new(0x07).calculate_from_string("world");
+///     let crc_ab = Check8Crc::new(0x07).calculate_from_string("hello, world");
+///     assert_eq!(crc8_combine(crc_a, crc_b, 5, params), crc_ab);
+/// }
+/// ```
+///
+pub fn crc8_combine(crc_a: u8, crc_b: u8, len_b: u64, params: Check8CrcParams) -> u8 {
+    // the registers are combined rather than the checksums, so that the final XOR and
+    // output reflection are applied once; crc_b already includes the effect of init on
+    // segment B, so only the difference between A's register and init is carried through
+    let reg_a = params.unfinalise(crc_a);
+    let reg_b = params.unfinalise(crc_b);
+    params.finalise(gf2_advance(reg_a ^ params.init_register(), len_b, params) ^ reg_b)
+}
+
+// A linear map on 8-bit values, where row i holds the image of bit i.
+type Gf2Matrix = [u8; 8];
+
+fn gf2_times(mat: &Gf2Matrix, vec: u8) -> u8 {
+    let mut result = 0;
+    for (i, row) in mat.iter().enumerate() {
+        if vec & (1 << i) != 0 {
+            result ^= row;
+        }
+    }
+    result
+}
+
+fn gf2_square(mat: &Gf2Matrix) -> Gf2Matrix {
+    let mut square = [0u8; 8];
+    for (row, image) in square.iter_mut().zip(mat.iter()) {
+        *row = gf2_times(mat, *image);
+    }
+    square
+}
+
+// The operator that advances the internal register over a single zero byte.
+fn gf2_zero_byte_operator(params: Check8CrcParams) -> Gf2Matrix {
+    let mut mat = [0u8; 8];
+    for (i, row) in mat.iter_mut().enumerate() {
+        *row = params.add_bitwise(1 << i, 0x00);
+    }
+    mat
+}
+
+// Advances the internal register over count zero bytes in O(log count) matrix operations.
+pub(crate) fn gf2_advance(mut reg: u8, mut count: u64, params: Check8CrcParams) -> u8 {
+    let mut op = gf2_zero_byte_operator(params);
+    while count != 0 {
+        if count & 1 != 0 {
+            reg = gf2_times(&op, reg);
+        }
+        op = gf2_square(&op);
+        count >>= 1;
+    }
+    reg
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // (width, poly, init, refin, refout, xorout, check) from the catalogue of parametrised CRC algorithms
+    const CATALOGUE: [(u8, u8, u8, bool, bool, u8, u8); 13] = [
+        (3, 0x03, 0x00, false, false, 0x07, 0x04),  // CRC-3/GSM
+        (3, 0x03, 0x07, true, true, 0x00, 0x06),    // CRC-3/ROHC
+        (4, 0x03, 0x00, true, true, 0x00, 0x07),    // CRC-4/G-704
+        (4, 0x03, 0x0F, false, false, 0x0F, 0x0B),  // CRC-4/INTERLAKEN
+        (5, 0x09, 0x09, false, false, 0x00, 0x00),  // CRC-5/EPC-C1G2
+        (5, 0x15, 0x00, true, true, 0x00, 0x07),    // CRC-5/G-704
+        (5, 0x05, 0x1F, true, true, 0x1F, 0x19),    // CRC-5/USB
+        (6, 0x27, 0x3F, false, false, 0x00, 0x0D),  // CRC-6/CDMA2000-A
+        (6, 0x03, 0x00, true, true, 0x00, 0x06),    // CRC-6/G-704
+        (7, 0x09, 0x00, false, false, 0x00, 0x75),  // CRC-7/MMC
+        (7, 0x45, 0x00, false, false, 0x00, 0x61),  // CRC-7/UMTS
+        (8, 0x31, 0x00, true, true, 0x00, 0xA1),    // CRC-8/MAXIM
+        (8, 0x07, 0x00, false, false, 0x00, 0xF4),  // CRC-8/SMBUS
+    ];
+
+    fn catalogue_params() -> impl Iterator<Item = (Check8CrcParams, u8)> {
+        CATALOGUE.iter().map(|&(width, poly, init, refin, refout, xorout, check)| {
+            (Check8CrcParams { width, poly, init, refin, refout, xorout }, check)
+        })
+    }
+
     #[test]
     fn test_crc8_standard_poly() {
         // Standard CRC-8 polynomial 0x07 (e.g. SMBus)
@@ -124,4 +627,232 @@ mod tests {
         crc.init(0xFF);
         assert_eq!(crc.get_accum(), 0xFF);
     }
+
+    #[test]
+    fn catalogue_check_values() {
+        for (params, check) in catalogue_params() {
+            let mut crc = Check8Crc::with_params(params);
+            assert_eq!(crc.calculate_from_string("123456789"), check, "{:?}", params);
+        }
+    }
+
+    // the table-driven types must agree with the bit-by-bit reference on every register value
+    // and input byte, not just on the check string
+    #[test]
+    fn table_types_match_bitwise_reference() {
+        for (params, _) in catalogue_params() {
+            for reg in 0..=params.mask() {
+                for val in 0..=255u8 {
+                    let mut reference = crate::Check8CrcBitwise::with_params(params);
+                    let mut crc = Check8Crc::with_params(params);
+                    let mut nibble = crate::Check8CrcNibble::with_params(params);
+                    reference.init(reg);
+                    crc.init(reg);
+                    nibble.init(reg);
+                    let expected = reference.add(val);
+                    assert_eq!(crc.add(val), expected, "{:?} {:#04X} {:#04X}", params, reg, val);
+                    assert_eq!(nibble.add(val), expected, "{:?} {:#04X} {:#04X}", params, reg, val);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn narrow_widths_are_masked() {
+        for (params, _) in catalogue_params() {
+            let mut crc = Check8Crc::with_params(params);
+            for val in 0..=255u8 {
+                crc.add(val);
+                assert!(crc.get_accum() <= params.mask(), "{:?}", params);
+                assert!(crc.get_checksum() <= params.mask(), "{:?}", params);
+            }
+        }
+    }
+
+    #[test]
+    fn left_aligned_checksum_fills_top_bits() {
+        let mut crc = Check8Crc::with_width(5, 0x05, 0x1F, true, true, 0x1F);
+        assert_eq!(crc.calculate_from_string("123456789"), 0x19);
+        assert_eq!(crc.get_checksum_left_aligned(), 0x19 << 3);
+    }
+
+    #[test]
+    fn reset_restores_initial_value() {
+        let mut crc = Check8Crc::with_width(6, 0x27, 0x3F, false, false, 0x00);
+        crc.calculate_from_string("abc");
+        assert_eq!(crc.reset(), 0x3F);
+        assert_eq!(crc.calculate_from_string("123456789"), 0x0D);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_width_panics() {
+        Check8Crc::with_width(0, 0x01, 0x00, false, false, 0x00);
+    }
+
+    #[test]
+    fn combine_matches_whole_message_crc() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        for (params, _) in catalogue_params() {
+            let expected = Check8Crc::with_params(params).calculate_from_byte_array(data);
+
+            for split in 0..=data.len() {
+                let mut crc = Check8Crc::with_params(params);
+                let crc_a = crc.calculate_from_byte_array(&data[..split]);
+                crc.reset();
+                let crc_b = crc.calculate_from_byte_array(&data[split..]);
+                let len_b = (data.len() - split) as u64;
+                assert_eq!(crc8_combine(crc_a, crc_b, len_b, params), expected, "{:?}", params);
+            }
+        }
+    }
+
+    #[test]
+    fn advance_over_zeros_matches_adding_zeros() {
+        // advancing over a long run of zeros matches adding them one at a time
+        let mut crc = Check8Crc::new(0x07);
+        crc.init(0x5A);
+        for _ in 0..100_000 {
+            crc.add(0);
+        }
+        assert_eq!(gf2_advance(0x5A, 100_000, Check8CrcParams::new(0x07)), crc.get_accum());
+    }
+
+    #[test]
+    fn add_zeros_matches_adding_zeros() {
+        for (params, _) in catalogue_params() {
+            let mut fast = Check8Crc::with_params(params);
+            let mut slow = Check8Crc::with_params(params);
+            fast.calculate_from_string("123");
+            slow.calculate_from_string("123");
+            for count in 0..300u64 {
+                let mut run = fast.clone();
+                assert_eq!(run.add_zeros(count), slow.get_accum(), "{:?} {}", params, count);
+                slow.add(0x00);
+            }
+            // and a run past the end carries on as normal
+            fast.add_zeros(300);
+            assert_eq!(fast.calculate_from_string("456"), slow.calculate_from_string("456"), "{:?}", params);
+        }
+    }
+
+    #[test]
+    fn add_bits_matches_add_for_whole_bytes() {
+        for (params, _) in catalogue_params() {
+            let mut crc = Check8Crc::with_params(params);
+            let mut expected = Check8Crc::with_params(params);
+            for val in b"123456789" {
+                assert_eq!(crc.add_bits(*val, 8), Ok(expected.add(*val)));
+            }
+        }
+    }
+
+    #[test]
+    fn add_bits_can_split_a_byte() {
+        let mut crc = Check8Crc::new(0x31);
+        let mut expected = Check8Crc::new(0x31);
+        crc.add_bits(0b101, 3).unwrap();
+        assert_eq!(crc.add_bits(0b1_1001, 5), Ok(expected.add(0b1011_1001)));
+
+        // reflected algorithms take the least significant bits first
+        let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+        let mut crc = Check8Crc::with_params(params);
+        let mut expected = Check8Crc::with_params(params);
+        crc.add_bits(0b1001, 4).unwrap();
+        assert_eq!(crc.add_bits(0b1011, 4), Ok(expected.add(0b1011_1001)));
+    }
+
+    #[test]
+    fn add_bits_handles_messages_that_are_not_whole_bytes() {
+        // a 37-bit message: 4 whole bytes and 5 further bits, padded with 3 zero bits
+        // the padded CRC is the 37-bit CRC advanced over 3 zero bits
+        let mut crc = Check8Crc::new(0x07);
+        crc.calculate_from_byte_array(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        crc.add_bits(0b10110, 5).unwrap();
+        let result = crc.add_bits(0, 3).unwrap();
+
+        let mut expected = Check8Crc::new(0x07);
+        assert_eq!(result, expected.calculate_from_byte_array(&[0xDE, 0xAD, 0xBE, 0xEF, 0b1011_0000]));
+        assert_eq!(crc.add_bits(0, 9), Err(Check8Error::InvalidBitCount(9)));
+    }
+
+    #[test]
+    fn get_poly_returns_poly() {
+        let crc = Check8Crc::new(0x31);
+        assert_eq!(crc.get_poly(), 0x31);
+        assert_eq!(crc.get_params(), Check8CrcParams::new(0x31));
+    }
+
+    #[test]
+    fn reflect_reverses_low_bits() {
+        assert_eq!(reflect(0b0000_0001, 8), 0b1000_0000);
+        assert_eq!(reflect(0b0000_0110, 5), 0b0000_1100);
+        assert_eq!(reflect(0x05, 5), 0x14);
+    }
+
+    #[test]
+    fn borrowed_table_matches_owned() {
+        static MAXIM: [u8; 256] = Check8Crc::generate_table_with_params(crate::presets::CRC8_MAXIM.params);
+        const CRC: Check8Crc = Check8Crc::with_table(&MAXIM, crate::presets::CRC8_MAXIM.params);
+        let mut borrowed = CRC;
+        let mut owned = crate::presets::crc8_maxim();
+        assert_eq!(borrowed.calculate_from_string("123456789"), owned.calculate_from_string("123456789"));
+        for val in 0..=255u8 {
+            assert_eq!(borrowed.add(val), owned.add(val));
+        }
+    }
+
+    #[test]
+    fn try_with_params_rejects_invalid_widths() {
+        for width in [0, 9, 255] {
+            let params = Check8CrcParams { width, ..Check8CrcParams::new(0x07) };
+            assert_eq!(params.validate(), Err(Check8Error::InvalidWidth(width)));
+            assert_eq!(Check8Crc::try_with_params(params).err(), Some(Check8Error::InvalidWidth(width)));
+            assert!(crate::Check8CrcNibble::try_with_params(params).is_err());
+            assert!(crate::Check8CrcBitwise::try_with_params(params).is_err());
+            assert!(crate::Check8CrcSlice8::try_with_params(params).is_err());
+        }
+        let mut crc = Check8Crc::try_with_params(Check8CrcParams { width: 7, ..Check8CrcParams::new(0x09) }).unwrap();
+        assert_eq!(crc.calculate_from_string("123456789"), 0x75)
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_widths() {
+        let json = "{\"params\":{\"width\":9,\"poly\":7,\"init\":0,\"refin\":false,\"refout\":false,\"xorout\":0},\"accum\":0}";
+        let error = serde_json::from_str::<Check8Crc>(json).err().unwrap();
+        assert!(error.to_string().contains("invalid CRC width 9"));
+        assert!(serde_json::from_str::<crate::Check8CrcSlice8>(json).is_err())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_regenerates_table() {
+        let mut crc = Check8Crc::new(0x07);
+        crc.calculate_from_byte_array(&[1, 2]);
+
+        let json = serde_json::to_string(&crc).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                "{{\"params\":{{\"width\":8,\"poly\":7,\"init\":0,\"refin\":false,\"refout\":false,\"xorout\":0}},\"accum\":{}}}",
+                crc.get_accum()
+            )
+        );
+
+        // resume from the persisted state and finish the calculation
+        let mut resumed: Check8Crc = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.calculate_from_byte_array(&[3]), 72);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_narrow_reflected() {
+        let mut crc = Check8Crc::with_width(5, 0x05, 0x1F, true, true, 0x1F);
+        crc.calculate_from_string("1234");
+
+        let json = serde_json::to_string(&crc).unwrap();
+        let mut resumed: Check8Crc = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.calculate_from_string("56789"), 0x19);
+    }
 }
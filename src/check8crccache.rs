@@ -0,0 +1,72 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Check8Crc table cache
+//!
+//! A process-wide cache of CRC lookup tables for Check8Crc::with_params_cached. A table depends
+//! only on the polynomial as the engine uses it and on the input reflection, so there are at
+//! most 512 distinct tables; each is generated the first time it is asked for and borrowed
+//! from the cache after that. Available with the std feature.
+
+use std::sync::OnceLock;
+
+use crate::Check8CrcParams;
+
+// indexed by input reflection, then by the engine polynomial
+static TABLES: [[OnceLock<[u8; 256]>; 256]; 2] = [const { [const { OnceLock::new() }; 256] }; 2];
+
+/// Retrieves the lookup table for a set of CRC parameters, generating it on first use.
+pub(crate) fn cached_table(params: Check8CrcParams) -> &'static [u8; 256] {
+    let slot = &TABLES[params.refin as usize][params.engine_poly() as usize];
+    slot.get_or_init(|| crate::Check8Crc::generate_table_with_params(params))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Check8Crc;
+
+    #[test]
+    fn cached_table_matches_generated() {
+        let maxim = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+        let crc5 = Check8CrcParams { width: 5, poly: 0x15, refin: true, refout: true, ..Check8CrcParams::new(0x15) };
+        for params in [Check8CrcParams::new(0x07), maxim, crc5] {
+            // later lookups are served from the cache
+            assert_eq!(*cached_table(params), Check8Crc::generate_table_with_params(params), "{:?}", params);
+            assert!(core::ptr::eq(cached_table(params), cached_table(params)), "{:?}", params);
+        }
+    }
+
+    #[test]
+    fn tables_are_shared_across_threads() {
+        let params = Check8CrcParams::new(0x9B);
+        let tables: Vec<&[u8; 256]> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4).map(|_| scope.spawn(|| cached_table(params))).collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+        assert!(tables.iter().all(|table| core::ptr::eq(*table, tables[0])));
+        assert_eq!(*tables[0], Check8Crc::generate_table_with_params(params))
+    }
+}
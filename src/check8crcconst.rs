@@ -0,0 +1,142 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8CrcConst
+//!
+//! An 8-bit CRC checksum type with tests, implements Check8 trait.
+//! The polynomial is a const generic parameter, so the lookup table is generated at
+//! compile time and stored once as a static, in flash on embedded targets.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Crc;       // for the const table generator
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8CrcConst<const POLY: u8> {
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator. Unlike Check8Crc,
+///   the polynomial is given by the type rather than the argument, and no table is built.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator using the CRC algorithm and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8CrcConst, Checksum};
+/// fn main() {
+///     // Standard CRC-8 polynomial 0x07, the instance is just the accumulator and its initial value
+///     let mut crc = Check8CrcConst::<0x07>::new(0x00);
+///     let result = crc.add(0x01);
+///     assert_eq!(result, 0x07);
+///     assert_eq!(std::mem::size_of_val(&crc), 2);
+/// }
+/// ```
+///
+impl<const POLY: u8> Check8CrcConst<POLY> {
+    const TABLE: &'static [u8; 256] = &Check8Crc::generate_table(POLY);
+
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl<const POLY: u8> Check8 for Check8CrcConst<POLY> {
+    fn new(initial: u8) -> Self {
+        Check8CrcConst { accum: initial, initial }
+    }
+}
+
+impl<const POLY: u8> Checksum<u8> for Check8CrcConst<POLY> {
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = Self::TABLE[(self.accum ^ val) as usize];
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl<const POLY: u8> Default for Check8CrcConst<POLY> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_runtime_table_crc() {
+        let data = b"123456789";
+
+        let mut expected = Check8Crc::new(0x07);
+        let mut crc = Check8CrcConst::<0x07>::new(0);
+        assert_eq!(crc.calculate_from_byte_array(data), expected.calculate_from_byte_array(data));
+
+        let mut expected = Check8Crc::new(0x9B);
+        expected.init(0xFF);
+        let mut crc = Check8CrcConst::<0x9B>::new(0xFF);
+        assert_eq!(crc.calculate_from_byte_array(data), expected.calculate_from_byte_array(data));
+    }
+
+    #[test]
+    fn table_is_not_stored_in_instances() {
+        assert_eq!(Check8CrcConst::<0x31>::TABLE, &Check8Crc::generate_table(0x31));
+        assert_eq!(std::mem::size_of::<Check8CrcConst<0x31>>(), 2);
+    }
+
+    #[test]
+    fn init_works() {
+        let mut crc = Check8CrcConst::<0x07>::new(0);
+        crc.init(0xFF);
+        assert_eq!(crc.get_accum(), 0xFF);
+    }
+
+    #[test]
+    fn reset_restores_the_initial_value() {
+        let mut crc = Check8CrcConst::<0x07>::new(0xFF);
+        crc.calculate_from_string("123456789");
+        assert_eq!(crc.reset(), 0xFF);
+        assert_eq!(crc.get_accum(), 0xFF);
+    }
+}
@@ -0,0 +1,256 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Check8CrcNibble
+//!
+//! An 8-bit CRC checksum type with two 16-entry lookup tables, implements Check8 trait
+//!
+//! The CRC of a byte is linear in the byte, so the 256-entry table of Check8Crc is the XOR of
+//! a table for the high nibble and a table for the low nibble. Each byte takes two lookups
+//! instead of one, with 32 bytes of tables instead of 256, for targets where the full table
+//! does not fit comfortably but the bit-by-bit loop is too slow. Takes the same parameters as
+//! Check8Crc and gives the same results.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8CrcParams; // for with_params
+use crate::Check8Error;     // for add_bits and try_with_params
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Check8CrcNibbleRepr", into = "Check8CrcNibbleRepr"))]
+pub struct Check8CrcNibble {
+    params: Check8CrcParams,
+    accum: u8,
+    low: [u8; 16],
+    high: [u8; 16],
+}
+
+// The serialised form of Check8CrcNibble: the tables are derived from the parameters, so they
+// are regenerated on deserialisation rather than stored.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Check8CrcNibble")]
+struct Check8CrcNibbleRepr {
+    params: Check8CrcParams,
+    accum: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Check8CrcNibbleRepr> for Check8CrcNibble {
+    type Error = Check8Error;
+
+    fn try_from(repr: Check8CrcNibbleRepr) -> Result<Self, Check8Error> {
+        let mut crc = Check8CrcNibble::try_with_params(repr.params)?;
+        crc.init(repr.accum);
+        Ok(crc)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Check8CrcNibble> for Check8CrcNibbleRepr {
+    fn from(crc: Check8CrcNibble) -> Self {
+        Check8CrcNibbleRepr { params: crc.params, accum: crc.get_accum() }
+    }
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, generates the nibble tables from the provided polynomial.
+/// - with_params: Creates a new instance for any CRC of up to 8 bits, described by a Check8CrcParams.
+/// - try_with_params: As with_params, but returns an error for an invalid width rather than panicking.
+/// - get_accum: Retrieves the current value of the accumulator, right-aligned for widths below 8.
+/// - get_checksum: Retrieves the checksum, after output reflection and the final XOR.
+/// - get_params: Retrieves the parameters the instance was created with.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Initialises the accumulator with the initial value from the parameters.
+/// - add: Adds a given value to the accumulator using the CRC algorithm and returns the updated value.
+/// - add_zeros: Adds a run of zero bytes in O(log n) steps.
+/// - add_bits: Adds the low nbits bits of a value, in the algorithm's bit order.
+///
+/// The accumulator holds the register in the same form as Check8Crc, so the two can be used
+/// interchangeably.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Check8CrcNibble, Check8CrcParams, Checksum};
+/// fn main() {
+///     let mut crc = Check8CrcNibble::new(0x07);
+///     assert_eq!(crc.calculate_from_string("123456789"), 0xF4);
+///
+///     // CRC-8/MAXIM, the same as the full-table Check8Crc
+///     let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+///     let mut nibble = Check8CrcNibble::with_params(params);
+///     let mut full = Check8Crc::with_params(params);
+///     assert_eq!(nibble.calculate_from_string("123456789"), full.calculate_from_string("123456789"));
+/// }
+/// ```
+///
+impl Check8CrcNibble {
+    /// Creates a new instance from the full set of CRC parameters, or returns
+    /// Check8Error::InvalidWidth if the width is not 1 to 8 bits, for parameters that are not
+    /// known to be valid at compile time.
+    pub const fn try_with_params(params: Check8CrcParams) -> Result<Self, Check8Error> {
+        match params.validate() {
+            Ok(()) => Ok(Self::with_params(params)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a new instance from the full set of CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not 1 to 8 bits.
+    pub const fn with_params(params: Check8CrcParams) -> Self {
+        let mut low = [0u8; 16];
+        let mut high = [0u8; 16];
+        let mut i = 0;
+        while i < 16 {
+            low[i] = params.add_bitwise(0, i as u8);
+            high[i] = params.add_bitwise(0, (i as u8) << 4);
+            i += 1;
+        }
+        Check8CrcNibble { params, accum: params.init_register(), low, high }
+    }
+
+    pub fn get_params(&self) -> Check8CrcParams {
+        self.params
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.accum = self.params.init_register();
+        self.get_accum()
+    }
+
+    /// Adds count zero bytes in O(log count) steps, as Check8Crc::add_zeros, and returns the updated value.
+    pub fn add_zeros(&mut self, count: u64) -> u8 {
+        self.accum = crate::check8crc::gf2_advance(self.accum, count, self.params);
+        self.get_accum()
+    }
+}
+
+impl Check8 for Check8CrcNibble {
+    fn new(poly: u8) -> Self {
+        Self::with_params(Check8CrcParams::new(poly))
+    }
+
+    fn add_bits(&mut self, value: u8, nbits: u8) -> Result<u8, Check8Error> {
+        if nbits > 8 {
+            return Err(Check8Error::InvalidBitCount(nbits));
+        }
+        self.accum = self.params.add_bits_bitwise(self.accum, value, nbits);
+        Ok(self.get_accum())
+    }
+}
+
+impl Checksum<u8> for Check8CrcNibble {
+    fn get_accum(&self) -> u8 {
+        self.params.decode_register(self.accum)
+    }
+
+    fn get_checksum(&self) -> u8 {
+        self.params.finalise(self.accum)
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = self.params.encode_register(val);
+        self.get_accum()
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        let index = self.accum ^ val;
+        self.accum = self.high[(index >> 4) as usize] ^ self.low[(index & 0x0F) as usize];
+        self.get_accum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Check8Crc;
+    use crate::presets::PRESETS;
+
+    #[test]
+    fn presets_have_their_check_values() {
+        for preset in PRESETS {
+            let mut crc = Check8CrcNibble::with_params(preset.params);
+            assert_eq!(crc.calculate_from_string("123456789"), preset.check, "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn matches_full_table_for_every_byte() {
+        for preset in PRESETS {
+            let mut nibble = Check8CrcNibble::with_params(preset.params);
+            let mut full = Check8Crc::with_params(preset.params);
+            for val in 0..=255u8 {
+                assert_eq!(nibble.add(val), full.add(val), "{}", preset.name);
+            }
+        }
+    }
+
+    #[test]
+    fn tables_are_32_bytes() {
+        assert!(core::mem::size_of::<Check8CrcNibble>() <= 48)
+    }
+
+    #[test]
+    fn add_zeros_matches_check8crc() {
+        let mut crc = Check8CrcNibble::with_params(crate::presets::CRC8_MAXIM.params);
+        let mut full = crate::Check8Crc::with_params(crate::presets::CRC8_MAXIM.params);
+        crc.add(0x5A);
+        full.add(0x5A);
+        assert_eq!(crc.add_zeros(12345), full.add_zeros(12345))
+    }
+
+    #[test]
+    fn init_and_reset() {
+        let params = Check8CrcParams { init: 0xFF, ..Check8CrcParams::new(0x1D) };
+        let mut crc = Check8CrcNibble::with_params(params);
+        assert_eq!(crc.init(0x12), 0x12);
+        assert_eq!(crc.reset(), 0xFF)
+    }
+
+    #[test]
+    fn add_bits_matches_check8crc() {
+        let mut nibble = Check8CrcNibble::with_params(crate::presets::CRC5_USB.params);
+        let mut full = Check8Crc::with_params(crate::presets::CRC5_USB.params);
+        assert_eq!(nibble.add_bits(0x15, 8), full.add_bits(0x15, 8));
+        assert_eq!(nibble.add_bits(0x07, 3), full.add_bits(0x07, 3));
+        assert_eq!(nibble.add_bits(0x00, 9), Err(Check8Error::InvalidBitCount(9)))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_state() {
+        let mut crc = Check8CrcNibble::new(0x07);
+        crc.calculate_from_string("1234");
+        let json = serde_json::to_string(&crc).unwrap();
+        let mut restored: Check8CrcNibble = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.calculate_from_string("56789"), 0xF4)
+    }
+}
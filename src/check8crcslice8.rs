@@ -0,0 +1,281 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Check8CrcSlice8
+//!
+//! An 8-bit CRC checksum type using slicing-by-8, implements Check8 trait
+//!
+//! As well as the table of Check8Crc, table k holds the effect of a byte followed by k zero
+//! bytes. The CRC is linear, so the register after 8 bytes is the XOR of one lookup in each
+//! table, and calculate_from_byte_array takes 8 bytes per step with no dependency between the
+//! lookups, rather than a chain of 8 dependent lookups. The tables take 2 KiB, so an instance
+//! is best created once and reused or cloned. Takes the same parameters as Check8Crc and
+//! gives the same results.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8CrcParams; // for with_params
+use crate::Check8Error;     // for add_bits and try_with_params
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Check8CrcSlice8Repr", into = "Check8CrcSlice8Repr"))]
+pub struct Check8CrcSlice8 {
+    params: Check8CrcParams,
+    accum: u8,
+    tables: [[u8; 256]; 8],
+}
+
+// The serialised form of Check8CrcSlice8: the tables are derived from the parameters, so they
+// are regenerated on deserialisation rather than stored.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Check8CrcSlice8")]
+struct Check8CrcSlice8Repr {
+    params: Check8CrcParams,
+    accum: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Check8CrcSlice8Repr> for Check8CrcSlice8 {
+    type Error = Check8Error;
+
+    fn try_from(repr: Check8CrcSlice8Repr) -> Result<Self, Check8Error> {
+        let mut crc = Check8CrcSlice8::try_with_params(repr.params)?;
+        crc.init(repr.accum);
+        Ok(crc)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Check8CrcSlice8> for Check8CrcSlice8Repr {
+    fn from(crc: Check8CrcSlice8) -> Self {
+        Check8CrcSlice8Repr { params: crc.params, accum: crc.get_accum() }
+    }
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, generates the 8 lookup tables from the provided polynomial.
+/// - with_params: Creates a new instance for any CRC of up to 8 bits, described by a Check8CrcParams.
+/// - try_with_params: As with_params, but returns an error for an invalid width rather than panicking.
+/// - get_accum: Retrieves the current value of the accumulator, right-aligned for widths below 8.
+/// - get_checksum: Retrieves the checksum, after output reflection and the final XOR.
+/// - get_params: Retrieves the parameters the instance was created with.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Initialises the accumulator with the initial value from the parameters.
+/// - add: Adds a given value to the accumulator using the CRC algorithm and returns the updated value.
+/// - add_zeros: Adds a run of zero bytes in O(log n) steps.
+/// - add_bits: Adds the low nbits bits of a value, in the algorithm's bit order.
+///
+/// The accumulator holds the register in the same form as Check8Crc, so the two can be used
+/// interchangeably.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Check8CrcSlice8, Check8CrcParams, Checksum};
+/// fn main() {
+///     let mut crc = Check8CrcSlice8::new(0x07);
+///     assert_eq!(crc.calculate_from_string("123456789"), 0xF4);
+///
+///     // CRC-8/MAXIM over a frame, the same as the single-table Check8Crc
+///     let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+///     let frame: Vec<u8> = (0..1500u32).map(|i| i as u8).collect();
+///     let mut sliced = Check8CrcSlice8::with_params(params);
+///     let mut single = Check8Crc::with_params(params);
+///     assert_eq!(sliced.calculate_from_byte_array(&frame), single.calculate_from_byte_array(&frame));
+/// }
+/// ```
+///
+impl Check8CrcSlice8 {
+    /// Creates a new instance from the full set of CRC parameters, or returns
+    /// Check8Error::InvalidWidth if the width is not 1 to 8 bits, for parameters that are not
+    /// known to be valid at compile time.
+    pub const fn try_with_params(params: Check8CrcParams) -> Result<Self, Check8Error> {
+        match params.validate() {
+            Ok(()) => Ok(Self::with_params(params)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a new instance from the full set of CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is not 1 to 8 bits.
+    pub const fn with_params(params: Check8CrcParams) -> Self {
+        let mut tables = [crate::Check8Crc::generate_table_with_params(params); 8];
+        // a zero byte takes the register r to tables[0][r], so adding one more zero byte to
+        // table k - 1 gives table k
+        let mut k = 1;
+        while k < 8 {
+            let mut i = 0;
+            while i < 256 {
+                tables[k][i] = tables[0][tables[k - 1][i] as usize];
+                i += 1;
+            }
+            k += 1;
+        }
+        Check8CrcSlice8 { params, accum: params.init_register(), tables }
+    }
+
+    pub fn get_params(&self) -> Check8CrcParams {
+        self.params
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.accum = self.params.init_register();
+        self.get_accum()
+    }
+
+    /// Adds count zero bytes in O(log count) steps, as Check8Crc::add_zeros, and returns the updated value.
+    pub fn add_zeros(&mut self, count: u64) -> u8 {
+        self.accum = crate::check8crc::gf2_advance(self.accum, count, self.params);
+        self.get_accum()
+    }
+}
+
+impl Check8 for Check8CrcSlice8 {
+    fn new(poly: u8) -> Self {
+        Self::with_params(Check8CrcParams::new(poly))
+    }
+
+    fn add_bits(&mut self, value: u8, nbits: u8) -> Result<u8, Check8Error> {
+        if nbits > 8 {
+            return Err(Check8Error::InvalidBitCount(nbits));
+        }
+        self.accum = self.params.add_bits_bitwise(self.accum, value, nbits);
+        Ok(self.get_accum())
+    }
+}
+
+impl Checksum<u8> for Check8CrcSlice8 {
+    fn get_accum(&self) -> u8 {
+        self.params.decode_register(self.accum)
+    }
+
+    fn get_checksum(&self) -> u8 {
+        self.params.finalise(self.accum)
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = self.params.encode_register(val);
+        self.get_accum()
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.tables[0][(self.accum ^ val) as usize];
+        self.get_accum()
+    }
+
+    // 8 bytes a step: the first byte, with the register, is followed by 7 more bytes and so is
+    // looked up in table 7, down to the last byte in table 0
+    fn calculate_from_byte_array(&mut self, array: &[u8]) -> u8 {
+        let t = &self.tables;
+        let (steps, tail) = array.as_chunks::<8>();
+        for b in steps {
+            self.accum = t[7][(self.accum ^ b[0]) as usize]
+                ^ t[6][b[1] as usize]
+                ^ t[5][b[2] as usize]
+                ^ t[4][b[3] as usize]
+                ^ t[3][b[4] as usize]
+                ^ t[2][b[5] as usize]
+                ^ t[1][b[6] as usize]
+                ^ t[0][b[7] as usize];
+        }
+        for val in tail {
+            self.add(*val);
+        }
+        self.get_checksum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Check8CrcBitwise;
+    use crate::presets::PRESETS;
+
+    #[test]
+    fn presets_have_their_check_values() {
+        for preset in PRESETS {
+            let mut crc = Check8CrcSlice8::with_params(preset.params);
+            assert_eq!(crc.calculate_from_string("123456789"), preset.check, "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn matches_bitwise_reference_at_every_length() {
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 37 + 11) as u8).collect();
+        for preset in PRESETS {
+            for len in 0..data.len() {
+                let mut sliced = Check8CrcSlice8::with_params(preset.params);
+                let mut reference = Check8CrcBitwise::with_params(preset.params);
+                // start part way through a step, to check the register carries over
+                sliced.add(0xA5);
+                reference.add(0xA5);
+                let expected = reference.calculate_from_byte_array(&data[..len]);
+                assert_eq!(sliced.calculate_from_byte_array(&data[..len]), expected, "{} {}", preset.name, len);
+            }
+        }
+    }
+
+    #[test]
+    fn add_zeros_matches_check8crc() {
+        let mut crc = Check8CrcSlice8::with_params(crate::presets::CRC8_MAXIM.params);
+        let mut full = crate::Check8Crc::with_params(crate::presets::CRC8_MAXIM.params);
+        crc.add(0x5A);
+        full.add(0x5A);
+        assert_eq!(crc.add_zeros(12345), full.add_zeros(12345))
+    }
+
+    #[test]
+    fn init_and_reset() {
+        let params = Check8CrcParams { init: 0xFF, ..Check8CrcParams::new(0x1D) };
+        let mut crc = Check8CrcSlice8::with_params(params);
+        assert_eq!(crc.init(0x12), 0x12);
+        assert_eq!(crc.reset(), 0xFF)
+    }
+
+    #[test]
+    fn add_bits_matches_bitwise_reference() {
+        let mut sliced = Check8CrcSlice8::with_params(crate::presets::CRC5_USB.params);
+        let mut reference = Check8CrcBitwise::with_params(crate::presets::CRC5_USB.params);
+        assert_eq!(sliced.add_bits(0x15, 8), reference.add_bits(0x15, 8));
+        assert_eq!(sliced.add_bits(0x07, 3), reference.add_bits(0x07, 3));
+        assert_eq!(sliced.add_bits(0x00, 9), Err(Check8Error::InvalidBitCount(9)))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_state() {
+        let mut crc = Check8CrcSlice8::new(0x07);
+        crc.calculate_from_string("1234");
+        let json = serde_json::to_string(&crc).unwrap();
+        let mut restored: Check8CrcSlice8 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.calculate_from_string("56789"), 0xF4)
+    }
+}
@@ -0,0 +1,170 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 digest
+//!
+//! Implements the Update, FixedOutput, FixedOutputReset and Reset traits of the RustCrypto
+//! digest crate for the checksum types, so they can fill slots generic over those traits.
+//! The output is the checksum, one byte for the 8-bit types and big-endian for the wider
+//! ones. Reset keeps any configuration and returns the accumulator to the value the checksum
+//! was created with, or for the CRC types with parameters, to the initial value of their
+//! parameters. Available with the digest feature.
+//!
+//! The types with a Default, where new(0) gives the plain algorithm, also implement Digest.
+//! The HashMarker the Digest blanket implementation requires does not make a checksum a
+//! cryptographic hash: these detect accidental errors only. For a CRC with a Default, use
+//! Check8CrcConst, which takes its polynomial as a type parameter.
+
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::checksum::{for_each_checksum_type, Checksum};  // for the methods at every width
+
+macro_rules! impl_digest {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> HashMarker for $t {}
+
+        impl<$($gen)*> OutputSizeUser for $t {
+            type OutputSize = output_size!($width);
+        }
+
+        impl<$($gen)*> Update for $t {
+            fn update(&mut self, data: &[u8]) {
+                Checksum::<$width>::calculate_from_byte_array(self, data);
+            }
+        }
+
+        impl<$($gen)*> FixedOutput for $t {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&Checksum::<$width>::get_checksum(&self).to_be_bytes());
+            }
+        }
+
+        impl<$($gen)*> Reset for $t {
+            fn reset(&mut self) {
+                <$t>::reset(self);
+            }
+        }
+
+        impl<$($gen)*> FixedOutputReset for $t {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&Checksum::<$width>::get_checksum(self).to_be_bytes());
+                Reset::reset(self);
+            }
+        }
+    };
+}
+
+// the number of bytes in the output of a checksum of the given width
+macro_rules! output_size {
+    (u8) => { digest::consts::U1 };
+    (u16) => { digest::consts::U2 };
+    (u32) => { digest::consts::U4 };
+}
+
+for_each_checksum_type!(impl_digest);
+
+#[cfg(test)]
+mod tests {
+    use digest::{Digest, FixedOutput, FixedOutputReset, Reset, Update};
+
+    use crate::check32::{Check32Crc, CRC32_ISO_HDLC};
+    use crate::{Check8CrcConst, Check8Lrc, Check8Sum};
+
+    // a component generic over Digest
+    fn digest_of<D: Digest>(data: &[u8]) -> Vec<u8> {
+        D::digest(data).to_vec()
+    }
+
+    #[test]
+    fn check8_types_are_digests() {
+        assert_eq!(digest_of::<Check8CrcConst<0x07>>(b"123456789"), [0xF4]);
+        assert_eq!(digest_of::<Check8Sum>(b"123456789"), [0xDD]);
+        assert_eq!(<Check8Lrc as Digest>::output_size(), 1)
+    }
+
+    #[test]
+    fn update_finalize_and_reset() {
+        use crate::{Check8, Check8Crc};
+        let mut crc = Check8Crc::new(0x07);
+        Update::update(&mut crc, b"1234");
+        Update::update(&mut crc, b"56789");
+        assert_eq!(crc.finalize_fixed_reset()[0], 0xF4);
+        Update::update(&mut crc, b"123456789");
+        assert_eq!(crc.finalize_fixed()[0], 0xF4)
+    }
+
+    #[test]
+    fn wider_output_is_big_endian() {
+        let mut crc = Check32Crc::with_params(CRC32_ISO_HDLC);
+        Update::update(&mut crc, b"12345");
+        Reset::reset(&mut crc);
+        Update::update(&mut crc, b"123456789");
+        assert_eq!(crc.finalize_fixed().as_slice(), [0xCB, 0xF4, 0x39, 0x26])
+    }
+
+    #[test]
+    fn reset_keeps_configuration() {
+        use crate::{Checksum, Finalise};
+        let mut sum = Check8Sum::with_finalise(0, Finalise::Negate);
+        Update::update(&mut sum, &[0x01, 0x02]);
+        assert_eq!(sum.finalize_fixed_reset()[0], 0xFD);
+        assert_eq!(sum.get_accum(), 0x00);
+        Update::update(&mut sum, &[0x03]);
+        assert_eq!(sum.finalize_fixed()[0], 0xFD)
+    }
+
+    #[test]
+    fn reset_restores_the_seed() {
+        use crate::{Check8, Check8Fletcher, Check8Pearson, Check8Xor, Checksum};
+        use crate::check16::Check16Fletcher;
+        let mut xor = <Check8Xor as Check8>::new(0x5A);
+        Update::update(&mut xor, b"123456789");
+        Reset::reset(&mut xor);
+        assert_eq!(xor.get_accum(), 0x5A);
+
+        let mut crc = <Check8CrcConst<0x07> as Check8>::new(0xFF);
+        Update::update(&mut crc, b"123456789");
+        Reset::reset(&mut crc);
+        assert_eq!(crc.get_accum(), 0xFF);
+
+        let mut pearson = <Check8Pearson as Check8>::new(0x11);
+        let expected = <Check8Pearson as Check8>::new(0x11).calculate_from_string("abc");
+        Update::update(&mut pearson, b"xyz");
+        Reset::reset(&mut pearson);
+        Update::update(&mut pearson, b"abc");
+        assert_eq!(pearson.finalize_fixed()[0], expected);
+
+        let mut fletcher = <Check8Fletcher as Check8>::new(0xFF);
+        Update::update(&mut fletcher, b"123");
+        Reset::reset(&mut fletcher);
+        assert_eq!(fletcher.get_accum(), 0x00);
+
+        let mut fletcher = Check16Fletcher::new(0x1234);
+        Update::update(&mut fletcher, b"123");
+        assert_eq!(fletcher.finalize_fixed_reset().len(), 2);
+        assert_eq!(fletcher.get_accum(), 0x1234)
+    }
+}
@@ -0,0 +1,208 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 embedded-io
+//!
+//! Implements the traits of the embedded-io crate, the no_std counterparts of std::io::Read
+//! and Write, so firmware can stream from a UART driver into a checksum with the same code as
+//! the host. The checksum types implement Write, never failing, and ChecksumReader and
+//! ChecksumWriter implement Read and Write over embedded-io readers and writers, failing with
+//! Check8IoError. Available with the embedded-io feature.
+
+use core::convert::Infallible;
+use core::fmt;
+
+use embedded_io::{ErrorKind, ErrorType, Read, Write};
+
+use crate::check8error::reported_part;
+use crate::checksum::{for_each_checksum_type, Checksum};  // for calculate_from_byte_array at every width
+use crate::{Check8, Check8Error, ChecksumReader, ChecksumWriter};
+
+/// The error of ChecksumReader and ChecksumWriter over embedded-io readers and writers.
+///
+/// # Variants
+///
+/// - Io: An error from the inner reader or writer.
+/// - Check8: The inner reader or writer reported more bytes than the buffer it was given
+///   (Check8Error::InvalidLength), so the checksum could not follow the stream.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Check8IoError<E> {
+    Io(E),
+    Check8(Check8Error),
+}
+
+impl<E> From<Check8Error> for Check8IoError<E> {
+    fn from(error: Check8Error) -> Self {
+        Check8IoError::Check8(error)
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for Check8IoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check8IoError::Io(error) => write!(f, "I/O error: {:?}", error),
+            Check8IoError::Check8(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for Check8IoError<E> {}
+
+impl<E: embedded_io::Error> embedded_io::Error for Check8IoError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Check8IoError::Io(error) => error.kind(),
+            Check8IoError::Check8(_) => ErrorKind::InvalidData,
+        }
+    }
+}
+
+macro_rules! impl_write {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> ErrorType for $t {
+            type Error = Infallible;
+        }
+
+        impl<$($gen)*> Write for $t {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+                Checksum::calculate_from_byte_array(self, buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> Result<(), Infallible> {
+                Ok(())
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_write);
+
+impl<R: ErrorType, C> ErrorType for ChecksumReader<R, C> {
+    type Error = Check8IoError<R::Error>;
+}
+
+impl<R: Read, C: Check8> Read for ChecksumReader<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let read = self.get_mut().read(buf).map_err(Check8IoError::Io)?;
+        self.add_read(reported_part(buf, read)?);
+        Ok(read)
+    }
+}
+
+impl<W: ErrorType, C> ErrorType for ChecksumWriter<W, C> {
+    type Error = Check8IoError<W::Error>;
+}
+
+impl<W: Write, C: Check8> Write for ChecksumWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let written = self.get_mut().write(buf).map_err(Check8IoError::Io)?;
+        self.add_written(reported_part(buf, written)?);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.get_mut().flush().map_err(Check8IoError::Io)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_io::{Read, Write};
+
+    use super::Check8IoError;
+    use crate::{Check8, Check8Error, Check8Lrc, Checksum, ChecksumReader, ChecksumWriter};
+
+    // a UART-like reader that delivers at most 2 bytes per call
+    struct Uart<'a>(&'a [u8]);
+
+    impl embedded_io::ErrorType for Uart<'_> {
+        type Error = embedded_io::ErrorKind;
+    }
+
+    impl Read for Uart<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(2).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn checksum_is_a_writer() {
+        let mut lrc = Check8Lrc::new(0);
+        Write::write_all(&mut lrc, b"123456789").unwrap();
+        Write::flush(&mut lrc).unwrap();
+        assert_eq!(lrc.get_checksum(), 0x23)
+    }
+
+    #[test]
+    fn reader_accumulates_what_is_read() {
+        let mut reader = ChecksumReader::new(Uart(b"123456789"), Check8Lrc::new(0));
+        let mut frame = [0u8; 9];
+        reader.read_exact(&mut frame).unwrap();
+        assert_eq!(&frame, b"123456789");
+        assert_eq!(reader.checksum(), 0x23)
+    }
+
+    #[test]
+    fn over_reported_write_is_an_error() {
+        struct Liar;
+
+        impl embedded_io::ErrorType for Liar {
+            type Error = embedded_io::ErrorKind;
+        }
+
+        impl Write for Liar {
+            fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+                Ok(buf.len() + 1)
+            }
+
+            fn flush(&mut self) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        let mut writer = ChecksumWriter::new(Liar, Check8Lrc::new(0));
+        let error = Write::write(&mut writer, &[0x01, 0x02]).unwrap_err();
+        assert_eq!(error, Check8IoError::Check8(Check8Error::InvalidLength(3)));
+        assert_eq!(embedded_io::Error::kind(&error), embedded_io::ErrorKind::InvalidData);
+        assert_eq!(writer.checksum(), 0x00)
+    }
+
+    #[test]
+    fn writer_forwards_and_accumulates() {
+        let mut out = [0u8; 8];
+        let mut writer = ChecksumWriter::new(&mut out[..], Check8Lrc::new(0));
+        Write::write_all(&mut writer, &[0x01, 0x02, 0x03]).unwrap();
+        let (_, checksum) = writer.into_parts();
+        assert_eq!(checksum, 0xFA);
+        assert_eq!(out[..3], [0x01, 0x02, 0x03])
+    }
+}
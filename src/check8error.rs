@@ -0,0 +1,85 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Error
+//!
+//! The error type returned by fallible Check8 operations.
+
+use core::fmt;
+
+/// # Variants
+///
+/// - Unsupported: The operation is not supported by the checksum algorithm.
+/// - InvalidBitCount: A bit count outside 0..=8 was given to add_bits.
+/// - InvalidWidth: A CRC width of 0, or wider than the CRC type, was given in its parameters.
+/// - InvalidTable: A lookup table that should be a permutation of 0 to 255 is not.
+/// - Cancelled: A long-running calculation was stopped through its cancellation flag.
+/// - InvalidLength: A reader or writer reported handling more bytes than the buffer it was given.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Check8Error {
+    Unsupported,
+    InvalidBitCount(u8),
+    InvalidWidth(u8),
+    InvalidTable,
+    Cancelled,
+    InvalidLength(usize),
+}
+
+impl fmt::Display for Check8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check8Error::Unsupported => write!(f, "operation not supported by this checksum algorithm"),
+            Check8Error::InvalidBitCount(nbits) => write!(f, "invalid bit count {}, expected 0 to 8", nbits),
+            Check8Error::InvalidWidth(width) => write!(f, "invalid CRC width {}", width),
+            Check8Error::InvalidTable => write!(f, "lookup table is not a permutation of 0 to 255"),
+            Check8Error::Cancelled => write!(f, "calculation cancelled"),
+            Check8Error::InvalidLength(len) => write!(f, "reported length {} is longer than the buffer", len),
+        }
+    }
+}
+
+impl core::error::Error for Check8Error {}
+
+// InvalidLength means the data is not what the source claimed, the rest are of no std kind
+#[cfg(feature = "std")]
+impl From<Check8Error> for std::io::Error {
+    fn from(error: Check8Error) -> Self {
+        match error {
+            Check8Error::InvalidLength(_) => std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+            _ => std::io::Error::other(error),
+        }
+    }
+}
+
+// the first n bytes of a buffer that a reader or writer reported handling. An implementation that
+// reports more than the buffer holds is broken, and checksumming a different length from the one
+// passed on would silently put the checksum out of step with the stream, so it is an error.
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub(crate) fn reported_part(buf: &[u8], n: usize) -> Result<&[u8], Check8Error> {
+    buf.get(..n).ok_or(Check8Error::InvalidLength(n))
+}
@@ -0,0 +1,102 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 Extend
+//!
+//! Implements Extend<u8> and Extend<&u8> for the checksum types, so bytes from any iterator
+//! can be added with extend, and the types compose with code written against Extend.
+
+use crate::checksum::{for_each_checksum_type, Checksum};  // for add at every width
+
+macro_rules! impl_extend {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> Extend<u8> for $t {
+            fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+                for val in iter {
+                    Checksum::add(self, val);
+                }
+            }
+        }
+
+        impl<'a, $($gen)*> Extend<&'a u8> for $t {
+            fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+                for val in iter {
+                    Checksum::add(self, *val);
+                }
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_extend);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "crc")]
+    use crate::check16::{Check16Crc, CRC16_XMODEM};
+    #[cfg(feature = "crc")]
+    use crate::{Check8Crc, Check8CrcConst};
+    use crate::{Check8, Check8Lrc, Checksum};
+
+    // code written against Extend
+    fn fill<E: Extend<u8>>(sink: &mut E) {
+        sink.extend(b"123456789".iter().copied());
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn extend_with_owned_and_borrowed_bytes() {
+        let payload = b"123456789";
+        let mut crc = Check8Crc::new(0x07);
+        crc.extend(payload[..4].iter().copied());
+        crc.extend(&payload[4..]);
+        assert_eq!(crc.get_checksum(), 0xF4)
+    }
+
+    #[test]
+    fn generic_over_extend() {
+        #[cfg(feature = "crc")]
+        {
+            let mut crc = Check8CrcConst::<0x07>::new(0);
+            fill(&mut crc);
+            assert_eq!(crc.get_checksum(), 0xF4);
+        }
+
+        let mut lrc = Check8Lrc::new(0);
+        let mut bytes = Vec::new();
+        fill(&mut lrc);
+        fill(&mut bytes);
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_byte_array(&bytes))
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn wider_types_extend() {
+        let mut crc16 = Check16Crc::with_params(CRC16_XMODEM);
+        fill(&mut crc16);
+        assert_eq!(crc16.get_checksum(), 0x31C3)
+    }
+}
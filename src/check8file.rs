@@ -0,0 +1,160 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 files
+//!
+//! Reads a file for calculate_from_file, in chunks through a buffer or, with the mmap
+//! feature, by memory-mapping files large enough for it to pay off and passing the map on
+//! in chunks. Available with the std
+//! feature.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+// files at least this large are memory-mapped when the mmap feature is enabled
+#[cfg(feature = "mmap")]
+const MMAP_THRESHOLD: u64 = 1 << 20;
+
+// a mapped file is passed on in chunks of this size, so progress can be reported
+#[cfg(feature = "mmap")]
+const MMAP_CHUNK_SIZE: usize = 1 << 20;
+
+/// Passes the contents of a file, in order, to a function taking chunks of it, stopping at
+/// the first error from the function.
+pub(crate) fn for_each_chunk<P: AsRef<Path>>(path: P, mut f: impl FnMut(&[u8]) -> io::Result<()>) -> io::Result<()> {
+    let file = File::open(path)?;
+
+    #[cfg(feature = "mmap")]
+    if file.metadata()?.len() >= MMAP_THRESHOLD {
+        // SAFETY: the map is read-only and dropped before returning; as with any mmap the file
+        // must not be truncated by another process while it is read
+        #[allow(unsafe_code)]
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        for chunk in map.chunks(MMAP_CHUNK_SIZE) {
+            f(chunk)?;
+        }
+        return Ok(());
+    }
+
+    crate::check8io::read_chunks(file, &mut f)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[cfg(feature = "crc")]
+    use crate::check32::{Check32Crc, CRC32_ISO_HDLC};
+    use crate::{Check8, Check8Error, Check8Lrc, Checksum};
+
+    // a file in the temporary directory, removed when dropped
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, contents: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!("check8-{}-{}", std::process::id(), name));
+            std::fs::write(&path, contents).unwrap();
+            TempFile(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn small_file() {
+        let file = TempFile::new("small", b"123456789");
+        assert_eq!(Check8Lrc::new(0).calculate_from_file(&file.0).unwrap(), 0x23)
+    }
+
+    #[test]
+    fn empty_file() {
+        let file = TempFile::new("empty", b"");
+        assert_eq!(Check8Lrc::new(0).calculate_from_file(&file.0).unwrap(), 0x00)
+    }
+
+    // larger than the mmap threshold, so mapped when the mmap feature is enabled
+    #[test]
+    fn large_file() {
+        let data: Vec<u8> = (0..(3u32 << 19)).map(|i| (i * 31 + 7) as u8).collect();
+        let file = TempFile::new("large", &data);
+        let expected = Check8Lrc::new(0).calculate_from_byte_array(&data);
+        assert_eq!(Check8Lrc::new(0).calculate_from_file(&file.0).unwrap(), expected)
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn large_file_at_32_bits() {
+        let data: Vec<u8> = (0..(3u32 << 19)).map(|i| (i * 17 + 3) as u8).collect();
+        let file = TempFile::new("large32", &data);
+        let expected = Check32Crc::with_params(CRC32_ISO_HDLC).calculate_from_byte_array(&data);
+        assert_eq!(Check32Crc::with_params(CRC32_ISO_HDLC).calculate_from_file(&file.0).unwrap(), expected)
+    }
+
+    #[test]
+    fn progress_is_reported_per_chunk() {
+        let data = vec![0x5Au8; 3 << 20];
+        let file = TempFile::new("progress", &data);
+        let cancel = AtomicBool::new(false);
+        let mut reported = Vec::new();
+        let result = Check8Lrc::new(0).calculate_from_file_with_progress(&file.0, |n| reported.push(n), &cancel);
+        assert_eq!(result.unwrap(), Check8Lrc::new(0).calculate_from_byte_array(&data));
+        assert!(reported.len() > 1);
+        assert!(reported.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(reported.last(), Some(&(data.len() as u64)))
+    }
+
+    #[test]
+    fn cancelled_between_chunks() {
+        let data = vec![0x5Au8; 3 << 20];
+        let file = TempFile::new("cancel", &data);
+        let cancel = AtomicBool::new(false);
+        let mut chunks = 0;
+        let result = Check8Lrc::new(0).calculate_from_file_with_progress(
+            &file.0,
+            |_| {
+                chunks += 1;
+                cancel.store(true, Ordering::Relaxed);
+            },
+            &cancel,
+        );
+        let error = result.unwrap_err();
+        assert_eq!(error.get_ref().and_then(|e| e.downcast_ref::<Check8Error>()), Some(&Check8Error::Cancelled));
+        assert_eq!(chunks, 1)
+    }
+
+    #[test]
+    fn missing_file() {
+        let path = std::env::temp_dir().join("check8-does-not-exist");
+        let result = Check8Lrc::new(0).calculate_from_file(path);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::NotFound)
+    }
+}
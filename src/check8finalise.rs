@@ -0,0 +1,70 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Finalise
+//!
+//! The finalisation step applied to an accumulator to give the checksum, for checksum types
+//! where it is selectable at construction.
+
+/// # Variants
+///
+/// - None: The checksum is the accumulator.
+/// - XorOut: The checksum is the accumulator XORed with a value, e.g. 0xFF to invert it.
+/// - Negate: The checksum is the two's complement of the accumulator.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Finalise {
+    #[default]
+    None,
+    XorOut(u8),
+    Negate,
+}
+
+impl Finalise {
+    /// Applies the finalisation to an accumulator value.
+    pub const fn apply(self, accum: u8) -> u8 {
+        match self {
+            Finalise::None => accum,
+            Finalise::XorOut(xorout) => accum ^ xorout,
+            Finalise::Negate => accum.wrapping_neg(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_finalises_accumulator() {
+        assert_eq!(Finalise::None.apply(0x12), 0x12);
+        assert_eq!(Finalise::XorOut(0xFF).apply(0x12), 0xED);
+        assert_eq!(Finalise::Negate.apply(0x12), 0xEE);
+        assert_eq!(Finalise::Negate.apply(0x00), 0x00);
+    }
+}
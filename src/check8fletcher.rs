@@ -0,0 +1,163 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Fletcher
+//!
+//! An 8-bit Fletcher checksum type with tests, implements Check8 trait.
+//! Each byte is taken as two 4-bit words, most significant first, and two running sums of
+//! the words are kept modulo 15. The second sum (of the first sum) makes the checksum
+//! sensitive to the order of the data, unlike the plain arithmetic sum.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8Fletcher
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator.
+/// - get_accum: Retrieves the current value of the accumulator, the second sum in the upper
+///   4 bits and the first sum in the lower 4 bits.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+///   Each 4-bit half is reduced modulo 15.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator using the Fletcher algorithm and returns the updated value.
+///
+/// Fletcher-16 keeps two full 8-bit sums, so it does not fit an 8-bit accumulator; it is provided by check16::Check16Fletcher,
+/// whose get_checksum_folded gives it folded to a u8.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Fletcher, Check8Lrc, Checksum};
+/// fn main() {
+///     let mut fletcher = Check8Fletcher::new(0x00);
+///     let result = fletcher.add(0x12);
+///     // words 0x1 then 0x2: sum1 = 3, sum2 = 1 + 3 = 4
+///     assert_eq!(result, 0x43);
+///
+///     // transposed bytes change the Fletcher checksum, but not the LRC, which is built on the arithmetic sum
+///     let a = Check8Fletcher::new(0).calculate_from_byte_array(&[0x01, 0x02]);
+///     let b = Check8Fletcher::new(0).calculate_from_byte_array(&[0x02, 0x01]);
+///     assert_ne!(a, b);
+///     assert_eq!(Check8Lrc::new(0).calculate_from_byte_array(&[0x01, 0x02]),
+///                Check8Lrc::new(0).calculate_from_byte_array(&[0x02, 0x01]));
+/// }
+/// ```
+///
+impl Check8 for Check8Fletcher
+{
+    fn new(initial: u8) -> Self {
+        let mut fletcher = Check8Fletcher { accum: 0, initial };
+        fletcher.init(initial);
+        fletcher
+    }
+}
+
+impl Check8Fletcher
+{
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Checksum<u8> for Check8Fletcher
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = (((val >> 4) % 15) << 4) | ((val & 0x0F) % 15);
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        let mut sum1 = self.accum & 0x0F;
+        let mut sum2 = self.accum >> 4;
+        for word in [val >> 4, val & 0x0F] {
+            sum1 = (sum1 + word) % 15;
+            sum2 = (sum2 + sum1) % 15;
+        }
+        self.accum = (sum2 << 4) | sum1;
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Fletcher {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sets_initial() {
+        let fletcher = Check8Fletcher::new(0x21);
+        assert_eq!(fletcher.get_accum(), 0x21)
+    }
+
+    #[test]
+    fn init_reduces_each_half_modulo_15() {
+        let mut fletcher = Check8Fletcher::new(0);
+        assert_eq!(fletcher.init(0xFF), 0x00);
+        assert_eq!(fletcher.init(0xF3), 0x03)
+    }
+
+    #[test]
+    fn add_returns_correct_result() {
+        let mut fletcher = Check8Fletcher::new(0);
+        assert_eq!(fletcher.add(0x12), 0x43)
+    }
+
+    #[test]
+    fn calculate_from_string_returns_correct_checksum() {
+        // the words of "abcde" are 6 1 6 2 6 3 6 4 6 5, so sum1 runs
+        // 6 7 13 0 6 9 0 4 10 0 and sum2 runs 6 13 11 11 2 11 11 0 10 10
+        assert_eq!(Check8Fletcher::new(0).calculate_from_string("abcde"), 0xA0);
+        // the strings of the Fletcher-16 examples, over 4-bit words
+        assert_eq!(Check8Fletcher::new(0).calculate_from_string("abcdef"), 0xDC);
+        assert_eq!(Check8Fletcher::new(0).calculate_from_string("abcdefgh"), 0x69)
+    }
+
+    #[test]
+    fn detects_transposed_bytes() {
+        let mut a = Check8Fletcher::new(0);
+        let mut b = Check8Fletcher::new(0);
+        assert_ne!(a.calculate_from_byte_array(&[0x10, 0x20, 0x30]), b.calculate_from_byte_array(&[0x20, 0x10, 0x30]))
+    }
+}
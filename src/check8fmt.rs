@@ -0,0 +1,72 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 fmt
+//!
+//! Implements core::fmt::Write for the checksum types, so formatted text can be checksummed
+//! as it is produced, without allocating, e.g. with write!. Each string adds its UTF-8 bytes
+//! and writing never fails.
+
+use core::fmt;
+
+use crate::checksum::{for_each_checksum_type, Checksum};  // for calculate_from_string at every width
+
+macro_rules! impl_fmt_write {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> fmt::Write for $t {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                Checksum::calculate_from_string(self, s);
+                Ok(())
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_fmt_write);
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use crate::{Check8, Check8Lrc, Checksum};
+
+    #[test]
+    #[cfg(feature = "protocols")]
+    fn nmea_sentence_as_it_is_produced() {
+        let (lat, lon) = ("4916.45,N", "12311.12,W");
+        let mut ck = crate::Check8Xor::new(0);
+        write!(ck, "GPGLL,{},{},225444,A", lat, lon).unwrap();
+        assert_eq!(ck.get_checksum(), crate::nmea::compute("GPGLL,4916.45,N,12311.12,W,225444,A"))
+    }
+
+    #[test]
+    fn chars_add_their_utf8_bytes() {
+        let mut lrc = Check8Lrc::new(0);
+        lrc.write_char('é').unwrap();
+        lrc.write_str("123").unwrap();
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_string("é123"))
+    }
+}
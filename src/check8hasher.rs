@@ -0,0 +1,93 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 Hasher
+//!
+//! Implements core::hash::Hasher for the checksum types, so a checksum can be used where code
+//! is generic over Hasher, e.g. to fingerprint content in tests. write adds the bytes and
+//! finish returns the checksum, the accumulator after any finalisation, widened to u64.
+//! Available with the hasher feature.
+//!
+//! An 8-bit checksum makes a poor hash for HashMap keys, with only 256 possible values.
+
+use core::hash::Hasher;
+
+use crate::checksum::{for_each_checksum_type, Checksum, Width};  // for the methods at every width
+
+macro_rules! impl_hasher {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> Hasher for $t {
+            fn write(&mut self, bytes: &[u8]) {
+                Checksum::calculate_from_byte_array(self, bytes);
+            }
+
+            fn finish(&self) -> u64 {
+                Checksum::get_checksum(self).to_u64()
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_hasher);
+
+#[cfg(test)]
+mod tests {
+    use core::hash::{Hash, Hasher};
+
+    use crate::check32::{Check32Crc, CRC32_ISO_HDLC};
+    use crate::{Check8, Check8Crc, Check8Lrc};
+
+    // fingerprints a value with any Hasher
+    fn fingerprint<H: Hasher>(value: &impl Hash, mut hasher: H) -> u64 {
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn write_and_finish() {
+        let mut crc = Check8Crc::new(0x07);
+        crc.write(b"1234");
+        crc.write(b"56789");
+        assert_eq!(crc.finish(), 0xF4)
+    }
+
+    #[test]
+    fn finish_is_the_finalised_checksum() {
+        let mut lrc = Check8Lrc::new(0);
+        Hasher::write(&mut lrc, &[0x01, 0x02]);
+        assert_eq!(lrc.finish(), 0xFD)
+    }
+
+    #[test]
+    fn generic_over_hasher() {
+        let a = fingerprint(&"content", Check32Crc::with_params(CRC32_ISO_HDLC));
+        let b = fingerprint(&"content", Check32Crc::with_params(CRC32_ISO_HDLC));
+        let c = fingerprint(&"contents", Check32Crc::with_params(CRC32_ISO_HDLC));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(fingerprint(&[1u8, 2, 3], Check8Crc::new(0x07)) <= 0xFF)
+    }
+}
@@ -0,0 +1,220 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 I/O
+//!
+//! Implements std::io::Write for the checksum types, so a checksum can be the destination of
+//! io::copy or write!. Each write adds the whole buffer to the accumulator and flush does
+//! nothing. Also provides copy_with_checksum, which copies a reader to a writer while
+//! checksumming the data, and the chunked reading behind calculate_from_reader. Available
+//! with the std feature.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::Check8;          // for copy_with_checksum
+use crate::Check8Error;     // for Cancelled
+use crate::check8error::reported_part;
+use crate::checksum::{for_each_checksum_type, Checksum};  // for calculate_from_byte_array at every width
+
+macro_rules! impl_write {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> io::Write for $t {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Checksum::calculate_from_byte_array(self, buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_write);
+
+// reads a source to its end through a stack buffer, passing each chunk to a function and
+// retrying reads that were interrupted
+pub(crate) fn read_chunks<R: io::Read>(mut reader: R, mut f: impl FnMut(&[u8]) -> io::Result<()>) -> io::Result<()> {
+    let mut buf = [0u8; crate::READ_BUFFER_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => f(reported_part(&buf, n)?)?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// wraps a function that adds chunks to a checksum so that it reports the number of bytes
+// processed after each chunk, and fails with Check8Error::Cancelled once the flag is set
+pub(crate) fn with_progress<'a>(
+    mut add: impl FnMut(&[u8]) + 'a,
+    mut progress: impl FnMut(u64) + 'a,
+    cancel: &'a AtomicBool,
+) -> impl FnMut(&[u8]) -> io::Result<()> + 'a {
+    let mut processed = 0u64;
+    move |chunk| {
+        if cancel.load(Ordering::Relaxed) {
+            return Err(io::Error::other(Check8Error::Cancelled));
+        }
+        add(chunk);
+        processed += chunk.len() as u64;
+        progress(processed);
+        Ok(())
+    }
+}
+
+/// Copies the whole of a reader to a writer, like io::copy, adding the data to a checksum as
+/// it goes, so a large copy and its integrity check take a single pass.
+///
+/// - **Parameter**: reader - The source.
+/// - **Parameter**: writer - The destination.
+/// - **Parameter**: checksum - The checksum to add the data to, which may already have a value.
+/// - **Returns**: The number of bytes copied, or the first error other than Interrupted.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{copy_with_checksum, Check8, Check8Lrc, Checksum};
+/// fn main() -> std::io::Result<()> {
+///     let mut lrc = Check8Lrc::new(0);
+///     let mut copy = Vec::new();
+///     let copied = copy_with_checksum(&mut &b"123456789"[..], &mut copy, &mut lrc)?;
+///     assert_eq!(copied, 9);
+///     assert_eq!(lrc.get_checksum(), 0x23);
+///     Ok(())
+/// }
+/// ```
+///
+pub fn copy_with_checksum<R, W, C>(reader: &mut R, writer: &mut W, checksum: &mut C) -> io::Result<u64>
+where
+    R: io::Read + ?Sized,
+    W: io::Write + ?Sized,
+    C: Check8 + ?Sized,
+{
+    let mut buf = [0u8; crate::READ_BUFFER_SIZE];
+    let mut copied = 0u64;
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => return Ok(copied),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let chunk = reported_part(&buf, n)?;
+        writer.write_all(chunk)?;
+        checksum.calculate_from_byte_array(chunk);
+        copied += chunk.len() as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::copy_with_checksum;
+
+    use crate::{Check8, Check8Lrc, Checksum};
+    #[cfg(feature = "crc")]
+    use crate::Check8Crc;
+    #[cfg(feature = "crc")]
+    use crate::check32::{Check32Crc, CRC32_ISO_HDLC};
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn io_copy_into_checksum() {
+        let data = b"123456789";
+        let mut crc = Check8Crc::new(0x07);
+        let copied = std::io::copy(&mut &data[..], &mut crc).unwrap();
+        assert_eq!(copied, 9);
+        assert_eq!(crc.get_checksum(), 0xF4)
+    }
+
+    #[test]
+    fn write_macro_formats_into_checksum() {
+        let mut lrc = Check8Lrc::new(0);
+        write!(lrc, "{}{}", 1234, 56789).unwrap();
+        lrc.flush().unwrap();
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_string("123456789"));
+
+        let mut lrc = Check8Lrc::new(0);
+        lrc.write_all(b"123456789").unwrap();
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_string("123456789"))
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn wider_types_are_writers() {
+        let mut crc = Check32Crc::with_params(CRC32_ISO_HDLC);
+        crc.write_all(b"123456789").unwrap();
+        assert_eq!(crc.get_checksum(), 0xCBF43926)
+    }
+
+    #[test]
+    fn copy_with_checksum_matches_separate_passes() {
+        let data: Vec<u8> = (0..50000u32).map(|i| (i * 13 + 5) as u8).collect();
+        let mut lrc = Check8Lrc::new(0);
+        let mut copy = Vec::new();
+        let copied = copy_with_checksum(&mut &data[..], &mut copy, &mut lrc).unwrap();
+        assert_eq!(copied, data.len() as u64);
+        assert_eq!(copy, data);
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_byte_array(&data))
+    }
+
+    #[test]
+    fn copy_with_checksum_reports_write_errors() {
+        let mut lrc = Check8Lrc::new(0);
+        let mut full = [0u8; 4];
+        let result = copy_with_checksum(&mut &b"123456789"[..], &mut &mut full[..], &mut lrc);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::WriteZero)
+    }
+
+    #[test]
+    fn copy_with_checksum_rejects_over_reported_reads() {
+        struct Liar;
+
+        impl std::io::Read for Liar {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                Ok(buf.len() + 1)
+            }
+        }
+
+        let mut lrc = Check8Lrc::new(0);
+        let result = copy_with_checksum(&mut Liar, &mut Vec::new(), &mut lrc);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData)
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn const_crc_is_a_writer() {
+        let mut crc = crate::Check8CrcConst::<0x07>::new(0);
+        crc.write_all(b"123456789").unwrap();
+        assert_eq!(crc.get_checksum(), 0xF4)
+    }
+}
@@ -0,0 +1,209 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8IterExt
+//!
+//! An extension trait for iterators of bytes, for code that works with iterators rather than
+//! contiguous slices. It is implemented for everything that is IntoIterator with u8 or &u8
+//! items, including iterators, slices, arrays and vectors.
+//!
+//! RunningChecksum, from scan_check8, yields the running checksum after each byte, e.g. to
+//! find the offset at which two images diverge by comparing prefix checksums.
+
+use core::borrow::Borrow;
+
+use crate::Check8;          // for the Check8 trait
+
+/// # Provided Methods
+///
+/// - check8:
+///   Calculates the checksum of the bytes with a new instance of a checksum type, created with
+///   new(0). For types whose new takes something other than an initial value (e.g. the
+///   polynomial of Check8Crc), use check8_with.
+///   - **Returns**: The checksum of the bytes.
+///
+/// - check8_with:
+///   Adds the bytes to an existing checksum.
+///   - **Parameter**: checksum - The checksum to add the bytes to.
+///   - **Returns**: The checksum after the bytes have been added.
+///
+/// - scan_check8:
+///   Wraps the bytes in a RunningChecksum, which yields each byte with the checksum of the
+///   bytes up to and including it.
+///   - **Parameter**: checksum - The checksum to add the bytes to, which may already have a value.
+///   - **Returns**: The RunningChecksum adapter.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8IterExt, Check8Lrc, Check8Parity, Checksum};
+/// fn main() {
+///     let bytes = [0x01u8, 0x02, 0x04];
+///     assert_eq!(bytes.iter().check8::<Check8Parity>(), 0x07);
+///
+///     let mut lrc = Check8Lrc::new(0);
+///     assert_eq!((b'1'..=b'9').check8_with(&mut lrc), 0x23);
+/// }
+/// ```
+///
+pub trait Check8IterExt: IntoIterator + Sized {
+    fn check8<C: Check8>(self) -> u8
+    where
+        Self::Item: Borrow<u8>,
+    {
+        self.check8_with(&mut C::new(0))
+    }
+
+    fn check8_with<C: Check8 + ?Sized>(self, checksum: &mut C) -> u8
+    where
+        Self::Item: Borrow<u8>,
+    {
+        for val in self {
+            checksum.add(*val.borrow());
+        }
+        checksum.get_checksum()
+    }
+
+    fn scan_check8<C: Check8>(self, checksum: C) -> RunningChecksum<Self::IntoIter, C>
+    where
+        Self::Item: Borrow<u8>,
+    {
+        RunningChecksum { iter: self.into_iter(), checksum }
+    }
+}
+
+impl<I: IntoIterator> Check8IterExt for I {}
+
+/// An iterator of (byte, running checksum) pairs, created by Check8IterExt::scan_check8.
+///
+/// For just the running values, map the pairs with `.map(|(_, checksum)| checksum)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Fletcher, Check8IterExt, Checksum};
+/// fn main() {
+///     let image_a = b"firmware v1.0.3 build 1";
+///     let image_b = b"firmware v1.0.4 build 1";
+///     let prefixes_a = image_a.scan_check8(Check8Fletcher::new(0));
+///     let prefixes_b = image_b.scan_check8(Check8Fletcher::new(0));
+///     let diverges_at = prefixes_a.zip(prefixes_b).position(|((_, a), (_, b))| a != b);
+///     assert_eq!(diverges_at, Some(14));
+/// }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct RunningChecksum<I, C> {
+    iter: I,
+    checksum: C,
+}
+
+impl<I, C: Check8> RunningChecksum<I, C> {
+    /// Retrieves the checksum of the bytes yielded so far.
+    pub fn checksum(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+}
+
+impl<I, C> Iterator for RunningChecksum<I, C>
+where
+    I: Iterator,
+    I::Item: Borrow<u8>,
+    C: Check8,
+{
+    type Item = (u8, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = *self.iter.next()?.borrow();
+        self.checksum.add(val);
+        Some((val, self.checksum.get_checksum()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8Lrc, Checksum};
+    #[cfg(feature = "crc")]
+    use crate::Check8Crc;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    #[test]
+    fn owned_and_borrowed_items() {
+        let data = b"123456789";
+        let expected = Check8Lrc::new(0).calculate_from_byte_array(data);
+        assert_eq!(data.iter().check8::<Check8Lrc>(), expected);
+        assert_eq!(data.iter().copied().check8::<Check8Lrc>(), expected);
+        assert_eq!(data.to_vec().check8::<Check8Lrc>(), expected);
+        assert_eq!(data.check8::<Check8Lrc>(), expected)
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn check8_with_continues_a_checksum() {
+        let mut crc = Check8Crc::new(0x07);
+        crc.calculate_from_string("1234");
+        assert_eq!(b"56789".iter().check8_with(&mut crc), 0xF4)
+    }
+
+    #[test]
+    fn finalisation_is_applied() {
+        let data = [0x10u8, 0x20, 0x30];
+        assert_eq!(data.iter().check8::<Check8Lrc>(), Check8Lrc::new(0).calculate_from_byte_array(&data))
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn running_checksum_yields_prefix_checksums() {
+        let data = b"123456789";
+        let mut running = data.scan_check8(Check8Crc::new(0x07));
+        assert_eq!(running.size_hint(), (9, Some(9)));
+        for n in 1..=data.len() {
+            let expected = Check8Crc::new(0x07).calculate_from_byte_array(&data[..n]);
+            assert_eq!(running.next(), Some((data[n - 1], expected)));
+        }
+        assert_eq!(running.next(), None);
+        assert_eq!(running.checksum(), 0xF4)
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn running_values_only() {
+        let values: Vec<u8> = [0x01u8, 0x02, 0x04].scan_check8(Check8Xor::new(0)).map(|(_, c)| c).collect();
+        assert_eq!(values, [0x01, 0x03, 0x07])
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn empty_iterator() {
+        assert_eq!(core::iter::empty::<u8>().check8::<Check8Xor>(), 0x00)
+    }
+}
@@ -0,0 +1,156 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Lrc
+//!
+//! An 8-bit longitudinal redundancy check (LRC) type with tests, implements Check8 trait.
+//! The bytes are summed as in Check8Sum, and the checksum is the two's complement of the sum,
+//! so that the sum of the message and its LRC is zero. This is the LRC of Modbus ASCII and
+//! many PLC serial protocols.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8Lrc
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator.
+/// - get_accum: Retrieves the current value of the accumulator, the wrapping sum of the bytes added.
+/// - get_checksum: Retrieves the LRC, the two's complement of the accumulator.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Lrc, Checksum};
+/// fn main() {
+///     // Modbus ASCII read holding registers request, ":F7031389000A60"
+///     let mut lrc = Check8Lrc::new(0x00);
+///     let result = lrc.calculate_from_byte_array(&[0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A]);
+///     assert_eq!(result, 0x60);
+///     // the message and its LRC sum to zero
+///     assert_eq!(lrc.add(result), 0x00);
+/// }
+/// ```
+///
+impl Check8 for Check8Lrc
+{
+    fn new(initial: u8) -> Self {
+        Check8Lrc { accum: initial, initial }
+    }
+}
+
+impl Check8Lrc
+{
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Checksum<u8> for Check8Lrc
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn get_checksum(&self) -> u8 {
+        self.accum.wrapping_neg()
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.accum.wrapping_add(val);
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Lrc {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "sum")]
+    use crate::Check8Sum;
+
+    #[test]
+    fn new_sets_initial() {
+        let lrc = Check8Lrc::new(10);
+        assert_eq!(lrc.get_accum(), 10)
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn checksum_is_twos_complement_of_sum() {
+        let mut lrc = Check8Lrc::new(0);
+        let mut sum = Check8Sum::new(0);
+        let result = lrc.calculate_from_string("hello");
+        assert_eq!(result, sum.calculate_from_string("hello").wrapping_neg());
+        assert_eq!(lrc.get_accum(), sum.get_accum())
+    }
+
+    #[test]
+    fn modbus_ascii_examples() {
+        let mut lrc = Check8Lrc::new(0);
+        assert_eq!(lrc.calculate_from_byte_array(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x01]), 0xFB);
+        let mut lrc = Check8Lrc::new(0);
+        assert_eq!(lrc.calculate_from_byte_array(&[0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A]), 0x60)
+    }
+
+    #[test]
+    fn empty_message_has_zero_lrc() {
+        let mut lrc = Check8Lrc::new(0);
+        assert_eq!(lrc.calculate_from_byte_array(&[]), 0x00)
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn message_plus_lrc_sums_to_zero() {
+        let data = b"PLC serial frame";
+        let checksum = Check8Lrc::new(0).calculate_from_byte_array(data);
+        let mut sum = Check8Sum::new(0);
+        sum.calculate_from_byte_array(data);
+        assert_eq!(sum.add(checksum), 0x00)
+    }
+}
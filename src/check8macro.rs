@@ -0,0 +1,252 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # check8!
+//!
+//! The check8! macro, which expands to the checksum of a string or byte literal at compile time.
+//!
+//! The algorithm is selected by name from the `named` module, where each algorithm is a const
+//! fn taking the bytes to check. The literal is converted to bytes by `Literal`, which has a
+//! const as_bytes for both string and byte string literals.
+
+/// Expands to the checksum of a string or byte literal, calculated at compile time.
+///
+/// The first argument names the algorithm: sum, xor, or one of the CRC presets, e.g. crc8_smbus or crc7_mmc.
+/// sum and xor need the features of the same names, and the CRC presets the presets feature.
+///
+/// # Examples
+///
+/// The example needs the presets and xor features, and is not run without them.
+///
+#[cfg_attr(all(feature = "presets", feature = "xor"), doc = "```rust")]
+#[cfg_attr(not(all(feature = "presets", feature = "xor")), doc = "```ignore")]
+/// use crate::check8::{check8, Check8, Check8Crc, Checksum};
+/// fn main() {
+///     const HELLO_CRC: u8 = check8!(crc8_smbus, "hello");
+///     assert_eq!(HELLO_CRC, Check8Crc::new(0x07).calculate_from_string("hello"));
+///
+///     assert_eq!(check8!(xor, b"\x01\x02"), 0x03);
+/// }
+/// ```
+///
+#[macro_export]
+macro_rules! check8 {
+    ($algorithm:ident, $literal:expr) => {{
+        const CHECK8: u8 = $crate::check8macro::named::$algorithm(
+            $crate::check8macro::Literal($literal).as_bytes()
+        );
+        CHECK8
+    }};
+}
+
+pub struct Literal<T>(pub T);
+
+impl<'a> Literal<&'a str> {
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> Literal<&'a [u8]> {
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a, const N: usize> Literal<&'a [u8; N]> {
+    pub const fn as_bytes(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+/// The algorithms available to check8!, by name.
+pub mod named {
+    #[cfg(feature = "sum")]
+    use crate::const_sum8;
+    #[cfg(feature = "xor")]
+    use crate::const_xor8;
+    #[cfg(feature = "presets")]
+    use crate::const_crc8_with_params;
+
+    #[cfg(feature = "sum")]
+    pub const fn sum(array: &[u8]) -> u8 {
+        const_sum8(0x00, array)
+    }
+
+    #[cfg(feature = "xor")]
+    pub const fn xor(array: &[u8]) -> u8 {
+        const_xor8(0x00, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_smbus(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_SMBUS.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_maxim(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_MAXIM.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_autosar(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_AUTOSAR.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_sae_j1850(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_SAE_J1850.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_dvb_s2(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_DVB_S2.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_cdma2000(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_CDMA2000.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_darc(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_DARC.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_gsm_b(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_GSM_B.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_wcdma(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_WCDMA.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_bluetooth(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_BLUETOOTH.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_rohc(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_ROHC.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_nrsc_5(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_NRSC_5.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_opensafety(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_OPENSAFETY.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_mifare_mad(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_MIFARE_MAD.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_tech_3250(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_TECH_3250.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_i_432_1(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC8_I_432_1.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc7_mmc(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC7_MMC.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc5_usb(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::CRC5_USB.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_koopman_a6(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::KOOPMAN_A6.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_koopman_97(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::KOOPMAN_97.params, array)
+    }
+
+    #[cfg(feature = "presets")]
+    pub const fn crc8_koopman_9c(array: &[u8]) -> u8 {
+        const_crc8_with_params(crate::presets::KOOPMAN_9C.params, array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]  // unused when neither sum nor xor is enabled
+    use crate::{Check8, Checksum};
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn string_literal_matches_runtime_calculation() {
+        use crate::Check8Sum;
+        assert_eq!(check8!(sum, "hello"), Check8Sum::new(0).calculate_from_string("hello"));
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn xor_of_literals_matches_runtime_calculation() {
+        use crate::Check8Xor;
+        assert_eq!(check8!(xor, "hello"), Check8Xor::new(0).calculate_from_string("hello"));
+        assert_eq!(check8!(xor, b"\x01\x02"), 0x03);
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn byte_literal_matches_runtime_calculation() {
+        assert_eq!(check8!(sum, b"\x01\x02"), 0x03);
+    }
+
+    #[test]
+    #[cfg(feature = "presets")]
+    fn crc_presets_match_runtime_calculation() {
+        use crate::Check8Crc;
+        assert_eq!(check8!(crc8_smbus, "hello"), Check8Crc::new(0x07).calculate_from_string("hello"));
+        assert_eq!(check8!(crc8_smbus, b"123456789"), 0xF4);
+        assert_eq!(check8!(crc7_mmc, b"123456789"), 0x75);
+        assert_eq!(check8!(crc8_maxim, b"123456789"), 0xA1);
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn can_be_used_in_const_items() {
+        const TABLE: [u8; 2] = [check8!(sum, "ab"), check8!(sum, b"ab")];
+        assert_eq!(TABLE, [0xC3, 0xC3]);
+    }
+}
@@ -0,0 +1,170 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8OnesComplement
+//!
+//! An 8-bit ones' complement sum checksum type with tests, implements Check8 trait.
+//! Carries out of the top bit are folded back into the bottom of the accumulator (end-around
+//! carry), as in the Internet checksum, and the checksum is optionally the complement of the sum.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8OnesComplement
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+    complement: bool,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type, initialises the accumulator. The checksum is the sum itself.
+/// - with_complement: Creates a new instance of the type, selecting whether the checksum is the complement of the sum.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - get_checksum: Retrieves the checksum, the accumulator complemented if selected.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator with end-around carry and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8OnesComplement, Checksum};
+/// fn main() {
+///     let mut sum = Check8OnesComplement::new(0x00);
+///     sum.init(0xFF);
+///     // the carry out of 0xFF + 0x02 is added back in, giving 0x02 rather than 0x01
+///     let result = sum.add(0x02);
+///     assert_eq!(result, 0x02);
+///
+///     let mut sum = Check8OnesComplement::with_complement(0x00, true);
+///     assert_eq!(sum.calculate_from_byte_array(&[0xFF, 0x02]), 0xFD);
+/// }
+/// ```
+///
+impl Check8OnesComplement {
+    pub fn with_complement(initial: u8, complement: bool) -> Self {
+        Check8OnesComplement { accum: initial, initial, complement }
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Check8 for Check8OnesComplement
+{
+    fn new(initial: u8) -> Self {
+        Self::with_complement(initial, false)
+    }
+}
+
+impl Checksum<u8> for Check8OnesComplement
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn get_checksum(&self) -> u8 {
+        if self.complement { !self.accum } else { self.accum }
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        let (sum, carry) = self.accum.overflowing_add(val);
+        // cannot overflow again: the largest sum is 0xFF + 0xFF = 0x1FE, which folds to 0xFF
+        self.accum = sum + carry as u8;
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8OnesComplement {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sets_initial() {
+        let sum = Check8OnesComplement::new(10);
+        assert_eq!(sum.get_accum(), 10)
+    }
+
+    #[test]
+    fn add_folds_carry_back_in() {
+        let mut sum = Check8OnesComplement::new(0);
+        sum.init(0xFF);
+        assert_eq!(sum.add(0x01), 0x01);
+        sum.init(0xFF);
+        assert_eq!(sum.add(0xFF), 0xFF)
+    }
+
+    #[test]
+    fn calculate_from_string_returns_correct_sum() {
+        let test_string = "hello";
+
+        // calculate the 8-bit ones' complement sum of the test string, folding carries at the end
+        let mut expected: u32 = 0;
+        for val in test_string.as_bytes() {
+            expected += *val as u32;
+        }
+        while expected > 0xFF {
+            expected = (expected & 0xFF) + (expected >> 8);
+        }
+
+        let mut sum = Check8OnesComplement::new(0);
+        let result = sum.calculate_from_string(test_string);
+        assert_eq!(result, expected as u8);
+
+        let mut sum = Check8OnesComplement::with_complement(0, true);
+        let result = sum.calculate_from_string(test_string);
+        assert_eq!(result, !(expected as u8))
+    }
+
+    #[test]
+    fn complemented_checksum_verifies_to_all_ones() {
+        // as with the Internet checksum, the sum of the data and its complemented checksum is 0xFF
+        let data = [0x12, 0xF0, 0x9A, 0x77];
+        let checksum = Check8OnesComplement::with_complement(0, true).calculate_from_byte_array(&data);
+
+        let mut verify = Check8OnesComplement::new(0);
+        verify.calculate_from_byte_array(&data);
+        assert_eq!(verify.add(checksum), 0xFF)
+    }
+}
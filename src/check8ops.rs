@@ -0,0 +1,134 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 operators
+//!
+//! Implements AddAssign for the checksum types, so `ck += byte;` and `ck += &frame[..];` add
+//! data to a checksum with its algorithm, as add and calculate_from_byte_array do. The +=
+//! stands for adding data, not for arithmetic on the checksum: for a CRC it performs the CRC
+//! update. Check8Xor also implements BitXorAssign, so `ck ^= byte;` can be used for it.
+
+use core::ops::AddAssign;
+#[cfg(feature = "xor")]
+use core::ops::BitXorAssign;
+
+#[cfg(feature = "xor")]
+use crate::Check8Xor;
+use crate::checksum::{for_each_checksum_type, Checksum};  // for the methods at every width
+
+macro_rules! impl_add_assign {
+    ([$($gen:tt)*] $t:ty, $width:ident) => {
+        impl<$($gen)*> AddAssign<u8> for $t {
+            fn add_assign(&mut self, val: u8) {
+                Checksum::add(self, val);
+            }
+        }
+
+        impl<$($gen)*> AddAssign<&[u8]> for $t {
+            fn add_assign(&mut self, array: &[u8]) {
+                Checksum::calculate_from_byte_array(self, array);
+            }
+        }
+
+        impl<const N: usize, $($gen)*> AddAssign<&[u8; N]> for $t {
+            fn add_assign(&mut self, array: &[u8; N]) {
+                Checksum::calculate_from_byte_array(self, array);
+            }
+        }
+    };
+}
+
+for_each_checksum_type!(impl_add_assign);
+
+#[cfg(feature = "xor")]
+impl BitXorAssign<u8> for Check8Xor {
+    fn bitxor_assign(&mut self, val: u8) {
+        Checksum::add(self, val);
+    }
+}
+
+#[cfg(feature = "xor")]
+impl BitXorAssign<&[u8]> for Check8Xor {
+    fn bitxor_assign(&mut self, array: &[u8]) {
+        Checksum::calculate_from_byte_array(self, array);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]  // unused when no algorithm family is enabled
+    use crate::{Check8, Checksum};
+    #[cfg(feature = "crc")]
+    use crate::{Check8Crc, Check8CrcConst};
+    #[cfg(feature = "sum")]
+    use crate::Check8Sum;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn add_assign_bytes_and_slices() {
+        let frame = b"123456789";
+        let mut crc = Check8Crc::new(0x07);
+        crc += frame[0];
+        crc += &frame[1..5];
+        crc += b"6789";
+        assert_eq!(crc.get_checksum(), 0xF4);
+
+        let mut crc = Check8CrcConst::<0x07>::new(0);
+        crc += &frame[..];
+        assert_eq!(crc.get_checksum(), 0xF4)
+    }
+
+    #[cfg(any(feature = "sum", feature = "xor"))]
+    #[test]
+    fn add_assign_uses_the_algorithm() {
+        #[cfg(feature = "sum")]
+        {
+            let mut sum = Check8Sum::new(0);
+            sum += 0xFF;
+            sum += 0x02;
+            assert_eq!(sum.get_checksum(), 0x01);
+        }
+
+        #[cfg(feature = "xor")]
+        {
+            let mut xor = Check8Xor::new(0);
+            xor += 0x03;
+            xor += 0x01;
+            assert_eq!(xor.get_checksum(), 0x02)
+        }
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn bitxor_assign_for_xor() {
+        let mut xor = Check8Xor::new(0);
+        xor ^= 0x01;
+        xor ^= &[0x02, 0x04][..];
+        assert_eq!(xor.get_checksum(), 0x07)
+    }
+}
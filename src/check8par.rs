@@ -0,0 +1,169 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Check8Par
+//!
+//! Implements Check8Par, which checksums a large buffer on the rayon thread pool: the buffer
+//! is split into chunks, each chunk is checksummed on its own, and the results are merged.
+//! For Check8Sum and Check8Xor the merge is a sum or an XOR. For Check8Crc the CRCs of the
+//! chunks are merged with the same GF(2) advance as crc8_combine, so no byte is processed
+//! twice. Available with the rayon feature.
+
+use rayon::prelude::*;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+// buffers are split into chunks of this size, and buffers smaller than two chunks are
+// checksummed on the calling thread, where the thread pool would cost more than it saves
+const PAR_CHUNK_SIZE: usize = 64 * 1024;
+
+/// # Required Methods
+///
+/// - par_calculate:
+///   Adds a buffer to the checksum, splitting it across the rayon thread pool, then retrieves the checksum.
+///   The result is the same as calculate_from_byte_array.
+///   - **Parameter**: array - The bytes to add.
+///   - **Returns**: The final checksum value as an u8.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Crc, Check8Par, Checksum};
+/// fn main() {
+///     let data = vec![0xA5u8; 1 << 20];
+///     let parallel = Check8Crc::new(0x07).par_calculate(&data);
+///     assert_eq!(parallel, Check8Crc::new(0x07).calculate_from_byte_array(&data));
+/// }
+/// ```
+///
+pub trait Check8Par: Check8 {
+    fn par_calculate(&mut self, array: &[u8]) -> u8;
+}
+
+#[cfg(feature = "sum")]
+impl Check8Par for crate::Check8Sum {
+    fn par_calculate(&mut self, array: &[u8]) -> u8 {
+        if array.len() < 2 * PAR_CHUNK_SIZE {
+            return self.calculate_from_byte_array(array);
+        }
+        let sum = array
+            .par_chunks(PAR_CHUNK_SIZE)
+            .map(crate::check8words::sum_bytes)
+            .reduce(|| 0, u8::wrapping_add);
+        self.add(sum);
+        self.get_checksum()
+    }
+}
+
+#[cfg(feature = "xor")]
+impl Check8Par for crate::Check8Xor {
+    fn par_calculate(&mut self, array: &[u8]) -> u8 {
+        if array.len() < 2 * PAR_CHUNK_SIZE {
+            return self.calculate_from_byte_array(array);
+        }
+        let xor = array
+            .par_chunks(PAR_CHUNK_SIZE)
+            .map(crate::check8words::xor_bytes)
+            .reduce(|| 0, |a, b| a ^ b);
+        self.add(xor);
+        self.get_checksum()
+    }
+}
+
+#[cfg(feature = "crc")]
+impl Check8Par for crate::Check8Crc {
+    fn par_calculate(&mut self, array: &[u8]) -> u8 {
+        if array.len() < 2 * PAR_CHUNK_SIZE {
+            return self.calculate_from_byte_array(array);
+        }
+        let params = self.get_params();
+        // each chunk as (register from zero, length), merged in order by advancing the
+        // earlier register over the later chunk; (0, 0) is the empty buffer
+        let (reg, len) = array
+            .par_chunks(PAR_CHUNK_SIZE)
+            .map(|chunk| (self.register_from_zero(chunk), chunk.len() as u64))
+            .reduce(
+                || (0, 0),
+                |(reg_a, len_a), (reg_b, len_b)| {
+                    (crate::check8crc::gf2_advance(reg_a, len_b, params) ^ reg_b, len_a + len_b)
+                },
+            );
+        self.append_register(reg, len);
+        self.get_checksum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8Crc, Check8CrcParams, Check8Sum, Check8Xor};
+
+    // long enough for several chunks and not a whole number of them
+    fn data() -> Vec<u8> {
+        (0..(5 * PAR_CHUNK_SIZE + 12345)).map(|i| (i * 31 + i / 977) as u8).collect()
+    }
+
+    #[test]
+    fn sum_matches_sequential() {
+        let data = data();
+        assert_eq!(Check8Sum::new(0x5A).par_calculate(&data), Check8Sum::new(0x5A).calculate_from_byte_array(&data))
+    }
+
+    #[test]
+    fn xor_matches_sequential() {
+        let data = data();
+        assert_eq!(Check8Xor::new(0x5A).par_calculate(&data), Check8Xor::new(0x5A).calculate_from_byte_array(&data))
+    }
+
+    #[test]
+    fn crc_matches_sequential() {
+        let data = data();
+        let maxim = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+        let crc5 = Check8CrcParams { width: 5, poly: 0x09, init: 0x09, ..Check8CrcParams::new(0x09) };
+        for params in [Check8CrcParams::new(0x07), maxim, crc5] {
+            let expected = Check8Crc::with_params(params).calculate_from_byte_array(&data);
+            assert_eq!(Check8Crc::with_params(params).par_calculate(&data), expected, "{:?}", params);
+        }
+    }
+
+    #[test]
+    fn crc_continues_from_existing_state() {
+        let data = data();
+        let mut parallel = Check8Crc::new(0x07);
+        parallel.calculate_from_string("header");
+        parallel.par_calculate(&data);
+        let mut sequential = Check8Crc::new(0x07);
+        sequential.calculate_from_string("header");
+        sequential.calculate_from_byte_array(&data);
+        assert_eq!(parallel.get_checksum(), sequential.get_checksum())
+    }
+
+    #[test]
+    fn small_buffers_stay_sequential() {
+        assert_eq!(Check8Crc::new(0x07).par_calculate(b"123456789"), 0xF4);
+        assert_eq!(Check8Sum::new(0).par_calculate(&[]), 0x00)
+    }
+}
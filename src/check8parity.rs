@@ -0,0 +1,181 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Parity
+//!
+//! An 8-bit longitudinal parity checksum type with tests, implements Check8 trait.
+//! Each bit of the checksum is the parity bit for that bit position across all the bytes,
+//! chosen to give either even or odd parity, and the checksum can optionally be complemented.
+//! Even parity without complement is the same as Check8Xor.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+/// The parity each bit position has once the checksum is included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8Parity
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+    parity: Parity,
+    complement: bool,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type for even parity, initialises the accumulator.
+/// - with_parity: Creates a new instance of the type for even or odd parity, optionally complementing the checksum.
+/// - get_accum: Retrieves the current value of the accumulator, the XOR of the bytes added.
+/// - get_checksum: Retrieves the checksum, the parity bits for the selected parity, complemented if selected.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8Parity, Checksum, Parity};
+/// fn main() {
+///     let mut lrc = Check8Parity::with_parity(0x00, Parity::Odd, false);
+///     let result = lrc.calculate_from_byte_array(&[0x01, 0x07]);
+///     // the XOR is 0x06, odd parity sets every bit position with an even count
+///     assert_eq!(result, 0xF9);
+/// }
+/// ```
+///
+impl Check8Parity {
+    pub fn with_parity(initial: u8, parity: Parity, complement: bool) -> Self {
+        Check8Parity { accum: initial, initial, parity, complement }
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Check8 for Check8Parity
+{
+    fn new(initial: u8) -> Self {
+        Self::with_parity(initial, Parity::Even, false)
+    }
+}
+
+impl Checksum<u8> for Check8Parity
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn get_checksum(&self) -> u8 {
+        let parity_bits = match self.parity {
+            Parity::Even => self.accum,
+            Parity::Odd => !self.accum,
+        };
+        if self.complement { !parity_bits } else { parity_bits }
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum ^= val;
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Parity {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    // counts the set bits in each bit position over the data and the checksum
+    fn column_counts(array: &[u8], checksum: u8) -> [u32; 8] {
+        let mut counts = [0u32; 8];
+        for val in array.iter().chain([checksum].iter()) {
+            for (bit, count) in counts.iter_mut().enumerate() {
+                *count += ((val >> bit) & 0x01) as u32;
+            }
+        }
+        counts
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn even_parity_matches_xor() {
+        let mut lrc = Check8Parity::new(0);
+        let mut xor = Check8Xor::new(0);
+        assert_eq!(lrc.calculate_from_string("hello"), xor.calculate_from_string("hello"))
+    }
+
+    #[test]
+    fn even_parity_gives_even_columns() {
+        let data = b"hello";
+        let checksum = Check8Parity::with_parity(0, Parity::Even, false).calculate_from_byte_array(data);
+        assert!(column_counts(data, checksum).iter().all(|count| count % 2 == 0))
+    }
+
+    #[test]
+    fn odd_parity_gives_odd_columns() {
+        let data = b"hello";
+        let checksum = Check8Parity::with_parity(0, Parity::Odd, false).calculate_from_byte_array(data);
+        assert!(column_counts(data, checksum).iter().all(|count| count % 2 == 1))
+    }
+
+    #[test]
+    fn complement_inverts_checksum() {
+        let data = b"hello";
+        let plain = Check8Parity::with_parity(0, Parity::Odd, false).calculate_from_byte_array(data);
+        let complemented = Check8Parity::with_parity(0, Parity::Odd, true).calculate_from_byte_array(data);
+        assert_eq!(complemented, !plain)
+    }
+
+    #[test]
+    fn accumulator_is_unaffected_by_parity() {
+        let mut lrc = Check8Parity::with_parity(0, Parity::Odd, true);
+        lrc.calculate_from_byte_array(&[0x01, 0x07]);
+        assert_eq!(lrc.get_accum(), 0x06)
+    }
+}
@@ -0,0 +1,343 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8Pearson
+//!
+//! An 8-bit Pearson hash type with tests, implements Check8 trait.
+//! Each byte is XORed into the accumulator and the result is looked up in a 256-entry
+//! permutation table, which mixes the bits far better than a sum or XOR. The canonical table
+//! is the one from Pearson's 1990 paper; a user-supplied table, or one shuffled from a seed,
+//! can be used instead.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Error;     // for with_table
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Check8PearsonRepr", into = "Check8PearsonRepr"))]
+pub struct Check8Pearson
+{
+    accum: u8,
+    initial: u8,
+    table: [u8; 256],
+}
+
+// The serialised form of Check8Pearson: the table is read back through with_table, so a
+// table that is not a permutation is rejected just as it is when constructed.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Check8Pearson")]
+struct Check8PearsonRepr {
+    accum: u8,
+    #[serde(default)]
+    initial: u8,
+    #[serde(with = "serde_table")]
+    table: [u8; 256],
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Check8PearsonRepr> for Check8Pearson {
+    type Error = Check8Error;
+
+    fn try_from(repr: Check8PearsonRepr) -> Result<Self, Check8Error> {
+        Check8Pearson::with_table(repr.initial, repr.table).map(|pearson| Check8Pearson { accum: repr.accum, ..pearson })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Check8Pearson> for Check8PearsonRepr {
+    fn from(pearson: Check8Pearson) -> Self {
+        Check8PearsonRepr { accum: pearson.accum, initial: pearson.initial, table: pearson.table }
+    }
+}
+
+/// The permutation table from Pearson, "Fast Hashing of Variable-Length Text Strings" (CACM, 1990),
+/// Table I.
+pub const PEARSON_TABLE: [u8; 256] = [
+      1, 87, 49, 12,176,178,102,166,121,193,  6, 84,249,230, 44,163,
+     14,197,213,181,161, 85,218, 80, 64,239, 24,226,236,142, 38,200,
+    110,177,104,103,141,253,255, 50, 77,101, 81, 18, 45, 96, 31,222,
+     25,107,190, 70, 86,237,240, 34, 72,242, 20,214,244,227,149,235,
+     97,234, 57, 22, 60,250, 82,175,208,  5,127,199,111, 62,135,248,
+    174,169,211, 58, 66,154,106,195,245,171, 17,187,182,179,  0,243,
+    132, 56,148, 75,128,133,158,100,130,126, 91, 13,153,246,216,219,
+    119, 68,223, 78, 83, 88,201, 99,122, 11, 92, 32,136,114, 52, 10,
+    138, 30, 48,183,156, 35, 61, 26,143, 74,251, 94,129,162, 63,152,
+    170,  7,115,167,241,206,  3,150, 55, 59,151,220, 90, 53, 23,131,
+    125,173, 15,238, 79, 95, 89, 16,105,137,225,224,217,160, 37,123,
+    118, 73,  2,157, 46,116,  9,145,134,228,207,212,202,215, 69,229,
+     27,188, 67,124,168,252, 42,  4, 29,108, 21,247, 19,205, 39,203,
+    233, 40,186,147,198,192,155, 33,164,191, 98,204,165,180,117, 76,
+    140, 36,210,172, 41, 54,159,  8,185,232,113,196,231, 47,146,120,
+     51, 65, 28,144,254,221, 93,189,194,139,112, 43, 71,109,184,209
+];
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type with the canonical table, initialises the accumulator.
+/// - with_table: Creates a new instance of the type with a user-supplied table, which must be a permutation of 0 to 255.
+/// - with_seed: Creates a new instance of the type with the canonical table shuffled by a seed.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - get_table: Retrieves the permutation table.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Adds a given value to the accumulator using the Pearson algorithm and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Pearson, Checksum};
+/// fn main() {
+///     let mut pearson = Check8Pearson::new(0x00);
+///     assert_eq!(pearson.calculate_from_string("hello"), 0x8F);
+///
+///     // a different seed gives a different, but repeatable, hash function
+///     let a = Check8Pearson::with_seed(0x00, 42).calculate_from_string("hello");
+///     let b = Check8Pearson::with_seed(0x00, 42).calculate_from_string("hello");
+///     assert_eq!(a, b);
+/// }
+/// ```
+///
+impl Check8Pearson {
+    pub fn with_table(initial: u8, table: [u8; 256]) -> Result<Self, Check8Error> {
+        let mut seen = [false; 256];
+        for entry in table {
+            if seen[entry as usize] {
+                return Err(Check8Error::InvalidTable);
+            }
+            seen[entry as usize] = true;
+        }
+        Ok(Check8Pearson { accum: initial, initial, table })
+    }
+
+    /// The shuffle is a Fisher-Yates shuffle of the canonical table driven by SplitMix64, so a
+    /// given seed always gives the same table.
+    pub fn with_seed(initial: u8, seed: u64) -> Self {
+        let mut table = PEARSON_TABLE;
+        let mut state = seed;
+        for i in (1..table.len()).rev() {
+            let j = (splitmix64(&mut state) % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+        Check8Pearson { accum: initial, initial, table }
+    }
+
+    pub fn get_table(&self) -> &[u8; 256] {
+        &self.table
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Check8 for Check8Pearson
+{
+    fn new(initial: u8) -> Self {
+        Check8Pearson { accum: initial, initial, table: PEARSON_TABLE }
+    }
+}
+
+impl Checksum<u8> for Check8Pearson
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.table[(self.accum ^ val) as usize];
+        self.accum
+    }
+}
+
+// serde only derives for arrays of up to 32 elements, so the table is written as a sequence
+// and read back into the array entry by entry, which needs no allocator
+#[cfg(feature = "serde")]
+mod serde_table {
+    use core::fmt;
+
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(table: &[u8; 256], serializer: S) -> Result<S::Ok, S::Error> {
+        table.as_slice().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 256], D::Error> {
+        deserializer.deserialize_seq(TableVisitor)
+    }
+
+    struct TableVisitor;
+
+    impl<'de> Visitor<'de> for TableVisitor {
+        type Value = [u8; 256];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a 256-entry table")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; 256], A::Error> {
+            let mut table = [0u8; 256];
+            for (i, entry) in table.iter_mut().enumerate() {
+                *entry = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(de::Error::invalid_length(257, &self));
+            }
+            Ok(table)
+        }
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Pearson {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_sets_initial() {
+        let pearson = Check8Pearson::new(10);
+        assert_eq!(pearson.get_accum(), 10)
+    }
+
+    #[test]
+    fn canonical_table_is_a_permutation() {
+        assert!(Check8Pearson::with_table(0, PEARSON_TABLE).is_ok())
+    }
+
+    #[test]
+    fn calculate_from_string_returns_correct_hash() {
+        let test_string = "hello";
+
+        // calculate the Pearson hash of the test string
+        let mut expected: u8 = 0;
+        for val in test_string.as_bytes() {
+            expected = PEARSON_TABLE[(expected ^ *val) as usize];
+        }
+
+        let mut pearson = Check8Pearson::new(0);
+        let result = pearson.calculate_from_string(test_string);
+        assert_eq!(result, expected);
+        assert_eq!(result, 0x8F)
+    }
+
+    #[test]
+    fn table_matches_the_paper() {
+        // the first and last rows of Table I in Pearson's paper
+        assert_eq!(PEARSON_TABLE[..8], [1, 87, 49, 12, 176, 178, 102, 166]);
+        assert_eq!(PEARSON_TABLE[248..], [194, 139, 112, 43, 71, 109, 184, 209]);
+        // a one-byte message hashes to its table entry
+        assert_eq!(Check8Pearson::new(0).calculate_from_string("a"), 56)
+    }
+
+    #[test]
+    fn with_table_rejects_non_permutations() {
+        let mut table = PEARSON_TABLE;
+        table[0] = table[1];
+        assert_eq!(Check8Pearson::with_table(0, table).err(), Some(Check8Error::InvalidTable))
+    }
+
+    #[test]
+    fn with_table_uses_given_table() {
+        let mut identity = [0u8; 256];
+        for (i, entry) in identity.iter_mut().enumerate() {
+            *entry = i as u8;
+        }
+        // with the identity table the hash degenerates to XOR
+        let mut pearson = Check8Pearson::with_table(0, identity).unwrap();
+        assert_eq!(pearson.calculate_from_byte_array(&[0x01, 0x07]), 0x06)
+    }
+
+    #[test]
+    fn with_seed_gives_repeatable_permutations() {
+        let a = Check8Pearson::with_seed(0, 1);
+        let b = Check8Pearson::with_seed(0, 1);
+        let c = Check8Pearson::with_seed(0, 2);
+        assert_eq!(a.get_table(), b.get_table());
+        assert_ne!(a.get_table(), c.get_table());
+        assert!(Check8Pearson::with_table(0, *c.get_table()).is_ok())
+    }
+
+    #[test]
+    fn detects_transposed_bytes() {
+        let mut a = Check8Pearson::new(0);
+        let mut b = Check8Pearson::new(0);
+        assert_ne!(a.calculate_from_byte_array(&[0x01, 0x02]), b.calculate_from_byte_array(&[0x02, 0x01]))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_table() {
+        let mut pearson = Check8Pearson::with_seed(0, 7);
+        pearson.calculate_from_string("hel");
+
+        let json = serde_json::to_string(&pearson).unwrap();
+        let mut resumed: Check8Pearson = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.get_table(), pearson.get_table());
+        assert_eq!(resumed.calculate_from_string("lo"), Check8Pearson::with_seed(0, 7).calculate_from_string("hello"))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_tables_of_the_wrong_length() {
+        let mut value = serde_json::to_value(Check8Pearson::new(0)).unwrap();
+        let table = value["table"].as_array_mut().unwrap();
+        table.pop();
+        assert!(serde_json::from_value::<Check8Pearson>(value.clone()).is_err());
+        let table = value["table"].as_array_mut().unwrap();
+        table.extend([0.into(), 0.into()]);
+        assert!(serde_json::from_value::<Check8Pearson>(value).is_err())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_tables_that_are_not_permutations() {
+        let mut value = serde_json::to_value(Check8Pearson::new(0)).unwrap();
+        value["table"][0] = value["table"][1].clone();
+        let error = serde_json::from_value::<Check8Pearson>(value).err().unwrap();
+        assert!(error.to_string().contains("permutation"))
+    }
+}
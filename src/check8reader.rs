@@ -0,0 +1,168 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ChecksumReader
+//!
+//! A pass-through reader that adds every byte read from an inner reader to a checksum, so a
+//! parser can consume a stream normally and then check a trailing checksum without reading
+//! the data twice. Available with the std feature, for std::io::Read, or the embedded-io
+//! feature, for embedded_io::Read.
+
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+#[cfg(feature = "std")]
+use crate::check8error::reported_part;
+use crate::Check8;          // for the Check8 trait
+
+/// # Provided Methods
+///
+/// - new: Wraps a reader and a checksum, which may already have a value.
+/// - get_ref, get_mut: Retrieve the inner reader.
+/// - checksum: Retrieves the checksum of everything read so far.
+/// - into_parts: Returns the inner reader and the final checksum value.
+///
+/// Bytes read through get_mut bypass the checksum.
+///
+/// # Examples
+///
+/// With the std feature:
+///
+#[cfg_attr(feature = "std", doc = "```rust")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use std::io::Read;
+/// use crate::check8::{Check8, Check8Lrc, Checksum, ChecksumReader};
+/// fn main() -> std::io::Result<()> {
+///     // a 4-byte record followed by its LRC
+///     let file: &[u8] = &[0x10, 0x20, 0x30, 0x40, 0x60];
+///     let mut reader = ChecksumReader::new(file, Check8Lrc::new(0));
+///     let mut record = [0u8; 4];
+///     reader.read_exact(&mut record)?;
+///     let calculated = reader.checksum();
+///
+///     let (mut rest, _) = reader.into_parts();
+///     let mut trailer = [0u8; 1];
+///     rest.read_exact(&mut trailer)?;
+///     assert_eq!(calculated, trailer[0]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct ChecksumReader<R, C> {
+    inner: R,
+    checksum: C,
+}
+
+impl<R, C: Check8> ChecksumReader<R, C> {
+    pub fn new(inner: R, checksum: C) -> Self {
+        ChecksumReader { inner, checksum }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn checksum(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    pub fn into_parts(self) -> (R, u8) {
+        let checksum = self.checksum.get_checksum();
+        (self.inner, checksum)
+    }
+
+    // adds the bytes the inner reader reported as read
+    pub(crate) fn add_read(&mut self, bytes: &[u8]) {
+        self.checksum.calculate_from_byte_array(bytes);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, C: Check8> Read for ChecksumReader<R, C> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.add_read(reported_part(buf, read)?);
+        Ok(read)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Check8Lrc, Checksum};
+
+    #[test]
+    fn accumulates_what_is_read() {
+        let mut reader = ChecksumReader::new(&b"123456789"[..], Check8Lrc::new(0));
+        let mut first = [0u8; 4];
+        reader.read_exact(&mut first).unwrap();
+        assert_eq!(reader.checksum(), Check8Lrc::new(0).calculate_from_string("1234"));
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"56789");
+        let (inner, checksum) = reader.into_parts();
+        assert!(inner.is_empty());
+        assert_eq!(checksum, 0x23)
+    }
+
+    #[test]
+    fn unread_bytes_are_not_counted() {
+        let data: &[u8] = &[0x01, 0x02, 0x04, 0x08];
+        let mut reader = ChecksumReader::new(data, Check8Lrc::new(0));
+        let mut buf = [0u8; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(reader.checksum(), 0xFD);
+        assert_eq!(reader.get_ref(), &[0x04, 0x08])
+    }
+
+    #[test]
+    fn over_reported_read_is_an_error() {
+        struct Liar;
+
+        impl Read for Liar {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                Ok(buf.len() + 1)
+            }
+        }
+
+        let mut reader = ChecksumReader::new(Liar, Check8Lrc::new(0));
+        let error = reader.read(&mut [0u8; 4]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(reader.checksum(), 0x00)
+    }
+
+    #[test]
+    fn copy_from_reader() {
+        let mut reader = ChecksumReader::new(&b"123456789"[..], Check8Lrc::new(0));
+        io::copy(&mut reader, &mut io::sink()).unwrap();
+        assert_eq!(reader.checksum(), 0x23)
+    }
+}
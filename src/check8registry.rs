@@ -0,0 +1,89 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Algorithm registry
+//!
+//! The checksum algorithms by name, for the language bindings, where a checksum is chosen with
+//! a string: the simple checksum types under short names, with their Default parameters, and
+//! every CRC in the presets module under its catalogue name. Names are not case-sensitive.
+
+use alloc::boxed::Box;
+
+use crate::presets::PRESETS;
+
+/// A checksum of any 8-bit type, as the bindings hold it.
+pub(crate) type AnyChecksum = Box<dyn crate::checksum::Checksum<u8> + Send + Sync>;
+
+// the names of the simple checksum types, in the order by_name matches them
+const SIMPLE_NAMES: [&str; 10] =
+    ["sum", "xor", "xor-rotate", "lrc", "bsd", "parity", "ones-complement", "fletcher", "pearson", "roland"];
+
+/// Creates the checksum for an algorithm name, or returns None for an unknown name.
+pub(crate) fn by_name(name: &str) -> Option<AnyChecksum> {
+    let checksum: AnyChecksum = match name.to_ascii_lowercase().as_str() {
+        "sum" => Box::new(crate::Check8Sum::default()),
+        "xor" => Box::new(crate::Check8Xor::default()),
+        "xor-rotate" => Box::new(crate::Check8XorRotate::default()),
+        "lrc" => Box::new(crate::Check8Lrc::default()),
+        "bsd" => Box::new(crate::Check8Bsd::default()),
+        "parity" => Box::new(crate::Check8Parity::default()),
+        "ones-complement" => Box::new(crate::Check8OnesComplement::default()),
+        "fletcher" => Box::new(crate::Check8Fletcher::default()),
+        "pearson" => Box::new(crate::Check8Pearson::default()),
+        "roland" => Box::new(crate::Check7Roland::default()),
+        _ => Box::new(crate::presets::by_name(name)?.crc()),
+    };
+    Some(checksum)
+}
+
+/// The names by_name accepts: the simple checksums, then the CRC presets.
+pub(crate) fn names() -> impl Iterator<Item = &'static str> {
+    SIMPLE_NAMES.into_iter().chain(PRESETS.iter().map(|preset| preset.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8, Check8Lrc, Check8Sum, Checksum};
+
+    #[test]
+    fn every_name_is_known() {
+        for name in names() {
+            assert!(by_name(name).is_some(), "{}", name);
+            assert!(by_name(&name.to_ascii_uppercase()).is_some(), "{}", name);
+        }
+        assert!(by_name("crc-8/unknown").is_none())
+    }
+
+    #[test]
+    fn names_match_the_rust_types() {
+        let data = b"123456789";
+        let mut sum = by_name("sum").unwrap();
+        assert_eq!(sum.calculate_from_byte_array(data), Check8Sum::new(0).calculate_from_byte_array(data));
+        let mut lrc = by_name("LRC").unwrap();
+        assert_eq!(lrc.calculate_from_byte_array(data), Check8Lrc::new(0).calculate_from_byte_array(data));
+        assert_eq!(by_name("crc-8/smbus").unwrap().calculate_from_byte_array(data), 0xF4)
+    }
+}
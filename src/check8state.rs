@@ -0,0 +1,55 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8State
+//!
+//! A plain snapshot of a checksum's accumulator, used to checkpoint and resume a calculation.
+
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8Fletcher, Checksum};
+/// fn main() {
+///     let mut fletcher = Check8Fletcher::new(0);
+///     fletcher.calculate_from_byte_array(&[1, 2]);
+///     let state = fletcher.snapshot();
+///
+///     // ... later, possibly in another process
+///     let mut resumed = Check8Fletcher::new(0);
+///     resumed.restore(&state);
+///     assert_eq!(resumed.calculate_from_byte_array(&[3]), Check8Fletcher::new(0).calculate_from_byte_array(&[1, 2, 3]));
+/// }
+/// ```
+///
+/// The state records only the accumulator, so it must be restored into an instance
+/// of the same algorithm with the same parameters (e.g. the same CRC polynomial).
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Check8State {
+    pub accum: u8,
+}
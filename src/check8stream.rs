@@ -0,0 +1,259 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 streams
+//!
+//! Adapters between checksums and futures Streams of byte chunks, such as the bodies of hyper
+//! and reqwest responses: calculate_from_stream and try_calculate_from_stream consume a stream
+//! and return its checksum, and the Check8StreamExt combinators checksummed and
+//! try_checksummed pass the chunks through while accumulating. Chunks may be any type that
+//! is AsRef<[u8]>, e.g. Bytes, Vec<u8> or &[u8]. Available with the futures feature.
+//!
+//! As for the tokio adapters the stream must be Unpin; one that is not can be wrapped in Box::pin.
+
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+
+use futures_core::Stream;
+
+use crate::Check8;          // for the Check8 trait
+
+/// Consumes a stream of chunks, adding each to a checksum.
+///
+/// - **Parameter**: stream - The stream to consume.
+/// - **Parameter**: checksum - The checksum to add the data to, which may already have a value.
+/// - **Returns**: The final checksum value.
+///
+pub async fn calculate_from_stream<S, C>(mut stream: S, checksum: &mut C) -> u8
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    C: Check8 + ?Sized,
+{
+    while let Some(chunk) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        checksum.calculate_from_byte_array(chunk.as_ref());
+    }
+    checksum.get_checksum()
+}
+
+/// Consumes a stream of fallible chunks, adding each to a checksum, and stops at the first error.
+///
+/// - **Parameter**: stream - The stream to consume.
+/// - **Parameter**: checksum - The checksum to add the data to, which may already have a value.
+/// - **Returns**: The final checksum value, or the first error from the stream.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{try_calculate_from_stream, Check8, Check8Crc, Checksum};
+/// fn main() {
+///     let body = futures::stream::iter([Ok::<_, std::io::Error>(b"1234".to_vec()), Ok(b"56789".to_vec())]);
+///     let mut crc = Check8Crc::new(0x07);
+///     let result = futures::executor::block_on(try_calculate_from_stream(body, &mut crc));
+///     assert_eq!(result.unwrap(), 0xF4);
+/// }
+/// ```
+///
+pub async fn try_calculate_from_stream<S, T, E, C>(mut stream: S, checksum: &mut C) -> Result<u8, E>
+where
+    S: Stream<Item = Result<T, E>> + Unpin,
+    T: AsRef<[u8]>,
+    C: Check8 + ?Sized,
+{
+    while let Some(chunk) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        checksum.calculate_from_byte_array(chunk?.as_ref());
+    }
+    Ok(checksum.get_checksum())
+}
+
+/// A stream that passes the chunks of an inner stream through, adding each to a checksum.
+///
+/// # Provided Methods
+///
+/// - get_ref, get_mut: Retrieve the inner stream.
+/// - checksum: Retrieves the checksum of the chunks passed through so far.
+/// - into_parts: Returns the inner stream and the final checksum value.
+///
+#[derive(Debug)]
+pub struct ChecksummedStream<S, C> {
+    inner: S,
+    checksum: C,
+}
+
+/// As ChecksummedStream, for a stream of fallible chunks; errors are passed through and do not
+/// change the checksum.
+#[derive(Debug)]
+pub struct TryChecksummedStream<S, C> {
+    inner: S,
+    checksum: C,
+}
+
+macro_rules! impl_accessors {
+    ($($t:ident),*) => {
+        $(
+            impl<S, C: Check8> $t<S, C> {
+                pub fn get_ref(&self) -> &S {
+                    &self.inner
+                }
+
+                pub fn get_mut(&mut self) -> &mut S {
+                    &mut self.inner
+                }
+
+                pub fn checksum(&self) -> u8 {
+                    self.checksum.get_checksum()
+                }
+
+                pub fn into_parts(self) -> (S, u8) {
+                    let checksum = self.checksum.get_checksum();
+                    (self.inner, checksum)
+                }
+            }
+        )*
+    };
+}
+
+impl_accessors!(ChecksummedStream, TryChecksummedStream);
+
+impl<S, C> Stream for ChecksummedStream<S, C>
+where
+    S: Stream + Unpin,
+    S::Item: AsRef<[u8]>,
+    C: Check8 + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let chunk = ready!(Pin::new(&mut this.inner).poll_next(cx));
+        if let Some(chunk) = &chunk {
+            this.checksum.calculate_from_byte_array(chunk.as_ref());
+        }
+        Poll::Ready(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, T, E, C> Stream for TryChecksummedStream<S, C>
+where
+    S: Stream<Item = Result<T, E>> + Unpin,
+    T: AsRef<[u8]>,
+    C: Check8 + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let chunk = ready!(Pin::new(&mut this.inner).poll_next(cx));
+        if let Some(Ok(chunk)) = &chunk {
+            this.checksum.calculate_from_byte_array(chunk.as_ref());
+        }
+        Poll::Ready(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// # Provided Methods
+///
+/// - checksummed: Wraps a stream of chunks in a ChecksummedStream.
+/// - try_checksummed: Wraps a stream of fallible chunks in a TryChecksummedStream.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use crate::check8::{Check8, Check8StreamExt, Check8Xor, Checksum};
+/// fn main() {
+///     let chunks = futures::stream::iter([&[0x01u8, 0x02][..], &[0x04][..]]);
+///     let mut stream = chunks.checksummed(Check8Xor::new(0));
+///     let forwarded: Vec<&[u8]> = futures::executor::block_on((&mut stream).collect());
+///     assert_eq!(forwarded.concat(), [0x01, 0x02, 0x04]);
+///     assert_eq!(stream.checksum(), 0x07);
+/// }
+/// ```
+///
+pub trait Check8StreamExt: Stream + Sized {
+    fn checksummed<C: Check8>(self, checksum: C) -> ChecksummedStream<Self, C> {
+        ChecksummedStream { inner: self, checksum }
+    }
+
+    fn try_checksummed<C: Check8>(self, checksum: C) -> TryChecksummedStream<Self, C> {
+        TryChecksummedStream { inner: self, checksum }
+    }
+}
+
+impl<S: Stream> Check8StreamExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use futures::executor::block_on;
+    use futures::stream;
+
+    use super::*;
+    use crate::{Check8Crc, Check8Sum, Checksum};
+
+    #[test]
+    fn calculate_from_stream_of_chunks() {
+        let chunks = stream::iter([b"12".to_vec(), b"3456".to_vec(), vec![], b"789".to_vec()]);
+        let mut crc = Check8Crc::new(0x07);
+        assert_eq!(block_on(calculate_from_stream(chunks, &mut crc)), 0xF4)
+    }
+
+    #[test]
+    fn try_calculate_stops_at_the_first_error() {
+        let chunks = stream::iter([Ok(&b"12"[..]), Err("reset"), Ok(&b"3"[..])]);
+        let mut sum = Check8Sum::new(0);
+        assert_eq!(block_on(try_calculate_from_stream(chunks, &mut sum)), Err("reset"));
+        assert_eq!(sum.get_checksum(), Check8Sum::new(0).calculate_from_string("12"))
+    }
+
+    #[test]
+    fn checksummed_passes_chunks_through() {
+        let mut stream = stream::iter([&b"1234"[..], &b"56789"[..]]).checksummed(Check8Crc::new(0x07));
+        assert_eq!(stream.size_hint(), (2, Some(2)));
+        let chunks: Vec<&[u8]> = block_on((&mut stream).collect());
+        assert_eq!(chunks.concat(), b"123456789");
+        let (_, checksum) = stream.into_parts();
+        assert_eq!(checksum, 0xF4)
+    }
+
+    #[test]
+    fn try_checksummed_skips_errors() {
+        let chunks = stream::iter([Ok(vec![0x01]), Err(()), Ok(vec![0x02])]);
+        let mut stream = chunks.try_checksummed(Check8Sum::new(0));
+        let items: Vec<Result<Vec<u8>, ()>> = block_on((&mut stream).collect());
+        assert_eq!(items, [Ok(vec![0x01]), Err(()), Ok(vec![0x02])]);
+        assert_eq!(stream.checksum(), 0x03)
+    }
+}
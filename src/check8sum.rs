@@ -29,45 +29,67 @@ SOFTWARE.
 //! An 8-bit "wrapping" arithmetic sum checksum type with tests, implements Check8 trait
 
 use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Finalise;        // for with_finalise
 
 // NOTE: we deliberately do not document the private fields
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Check8Sum
 {
     accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    finalise: Finalise,
 }
 
 /// # Provided Methods
 ///
 /// - new: Creates a new instance of the type, initialises the accumulator.
+/// - with_finalise: Creates a new instance of the type, initialises the accumulator and selects how the checksum is finalised.
 /// - get_accum: Retrieves the current value of the accumulator.
+/// - get_checksum: Retrieves the checksum, the accumulator after the selected finalisation.
 /// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
 /// - add: Adds a given value to the accumulator using the appropriate algorithm and returns the updated value.
+/// - remove: Removes a previously added value from the accumulator and returns the updated value.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use crate::check8::{Check8, Check8Sum};
+/// use crate::check8::{Check8, Check8Sum, Checksum, Finalise};
 /// fn main() {
 ///     let mut sum = Check8Sum::new(0x00);
 ///     sum.init(0xFF);
 ///     let result = sum.add(0x01);
 ///     assert_eq!(result, 0x00);
 ///     assert_eq!(sum.get_accum(), 0x00);
+///
+///     // "sum starting from 0xA5", then negated
+///     let mut sum = Check8Sum::with_finalise(0xA5, Finalise::Negate);
+///     assert_eq!(sum.calculate_from_byte_array(&[0x01, 0x02]), 0x58);
 /// }
 /// ```
 ///
-
 impl Check8 for Check8Sum {
 
-    fn new(initial: u8) -> impl Check8 {
-        Check8Sum { accum: initial }
+    fn new(initial: u8) -> Self {
+        Self::with_finalise(initial, Finalise::None)
     }
+}
+
+impl Checksum<u8> for Check8Sum {
 
     fn get_accum(&self) -> u8 {
         self.accum
     }
 
+    fn get_checksum(&self) -> u8 {
+        self.finalise.apply(self.accum)
+    }
+
     fn init(&mut self, val: u8) -> u8 {
         self.accum = val;
         self.accum
@@ -78,6 +100,42 @@ impl Check8 for Check8Sum {
         self.accum
     }
 
+    // 8 bytes at a time, see check8words
+    fn calculate_from_byte_array(&mut self, array: &[u8]) -> u8 {
+        self.accum = self.accum.wrapping_add(crate::check8words::sum_bytes(array));
+        self.get_checksum()
+    }
+}
+
+impl Check8Sum {
+    /// Creates a new instance with the accumulator seeded from `initial` and the given finalisation
+    /// applied by get_checksum, e.g. `Finalise::XorOut(0xFF)` to invert or `Finalise::Negate`.
+    pub fn with_finalise(initial: u8, finalise: Finalise) -> Self {
+        Check8Sum { accum: initial, initial, finalise }
+    }
+
+    /// Removes a value that was previously added, using wrapping subtraction, and returns the updated value.
+    ///
+    /// The checksum does not depend on the order of the bytes, so when one byte of a record
+    /// changes the checksum can be updated by removing the old value and adding the new one,
+    /// rather than recalculating over the whole record.
+    pub fn remove(&mut self, val: u8) -> u8 {
+        self.accum = self.accum.wrapping_sub(val);
+        self.accum
+    }
+
+    /// Returns the accumulator to the value it was created with, keeping the finalisation, and
+    /// returns it. init does not change that value.
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Sum {
+    fn default() -> Self {
+        Self::new(0)
+    }
 }
 
 #[cfg(test)]
@@ -143,4 +201,60 @@ mod tests {
         let result = sum.calculate_from_string("hello");
         assert_eq!(result, expected as u8)
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_accum() {
+        let mut sum = Check8Sum::new(0);
+        sum.calculate_from_string("hel");
+
+        let json = serde_json::to_string(&sum).unwrap();
+        let mut resumed: Check8Sum = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.get_accum(), sum.get_accum());
+
+        let mut whole = Check8Sum::new(0);
+        assert_eq!(resumed.calculate_from_string("lo"), whole.calculate_from_string("hello"))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_loads_state_saved_without_finalise() {
+        let resumed: Check8Sum = serde_json::from_str(r#"{"accum":5}"#).unwrap();
+        assert_eq!(resumed.get_accum(), 5);
+        assert_eq!(resumed.get_checksum(), 5)
+    }
+
+    #[test]
+    fn remove_then_add_matches_recalculation() {
+        let mut record: [u8; 4] = [0x10, 0x20, 0x30, 0x40];
+        let mut sum = Check8Sum::new(0);
+        sum.calculate_from_byte_array(&record);
+
+        sum.remove(record[2]);
+        record[2] = 0xFE;
+        let result = sum.add(record[2]);
+
+        let mut expected = Check8Sum::new(0);
+        assert_eq!(result, expected.calculate_from_byte_array(&record))
+    }
+
+    #[test]
+    fn remove_undoes_add() {
+        let mut sum = Check8Sum::new(0x5A);
+        sum.add(0xC3);
+        assert_eq!(sum.remove(0xC3), 0x5A)
+    }
+
+    #[test]
+    fn finalise_applies_to_checksum_not_accumulator() {
+        let mut plain = Check8Sum::new(0xA5);
+        let expected = plain.calculate_from_string("hello");
+
+        let mut inverted = Check8Sum::with_finalise(0xA5, Finalise::XorOut(0xFF));
+        assert_eq!(inverted.calculate_from_string("hello"), !expected);
+        assert_eq!(inverted.get_accum(), expected);
+
+        let mut negated = Check8Sum::with_finalise(0xA5, Finalise::Negate);
+        assert_eq!(negated.calculate_from_string("hello"), expected.wrapping_neg())
+    }
+}
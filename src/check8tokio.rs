@@ -0,0 +1,256 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8 tokio
+//!
+//! Asynchronous counterparts of ChecksumReader, ChecksumWriter and calculate_from_reader for
+//! tokio, so async code can checksum a stream without bridging through blocking tasks.
+//! Available with the tokio feature.
+//!
+//! The inner reader or writer must be Unpin; a type that is not can be wrapped in Box::pin.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf};
+
+use crate::Check8;          // for the Check8 trait
+use crate::check8error::reported_part;
+
+/// A pass-through AsyncRead that adds every byte read from an inner reader to a checksum.
+///
+/// # Provided Methods
+///
+/// - new: Wraps a reader and a checksum, which may already have a value.
+/// - get_ref, get_mut: Retrieve the inner reader.
+/// - checksum: Retrieves the checksum of everything read so far.
+/// - into_parts: Returns the inner reader and the final checksum value.
+///
+/// # Examples
+///
+/// ```rust
+/// use tokio::io::AsyncReadExt;
+/// use crate::check8::{AsyncChecksumReader, Check8, Check8Crc, Checksum};
+/// # #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> std::io::Result<()> {
+///     let mut reader = AsyncChecksumReader::new(&b"123456789"[..], Check8Crc::new(0x07));
+///     let mut frame = Vec::new();
+///     reader.read_to_end(&mut frame).await?;
+///     assert_eq!(reader.checksum(), 0xF4);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct AsyncChecksumReader<R, C> {
+    inner: R,
+    checksum: C,
+}
+
+impl<R: AsyncRead + Unpin, C: Check8 + Unpin> AsyncChecksumReader<R, C> {
+    pub fn new(inner: R, checksum: C) -> Self {
+        AsyncChecksumReader { inner, checksum }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    pub fn checksum(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    pub fn into_parts(self) -> (R, u8) {
+        let checksum = self.checksum.get_checksum();
+        (self.inner, checksum)
+    }
+}
+
+impl<R: AsyncRead + Unpin, C: Check8 + Unpin> AsyncRead for AsyncChecksumReader<R, C> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        this.checksum.calculate_from_byte_array(&buf.filled()[before..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// A pass-through AsyncWrite that forwards everything to an inner writer while adding the
+/// bytes it accepted to a checksum.
+///
+/// # Provided Methods
+///
+/// - new: Wraps a writer and a checksum, which may already have a value.
+/// - get_ref, get_mut: Retrieve the inner writer.
+/// - checksum: Retrieves the checksum so far.
+/// - into_parts: Returns the inner writer and the final checksum value.
+///
+/// # Examples
+///
+/// ```rust
+/// use tokio::io::AsyncWriteExt;
+/// use crate::check8::{AsyncChecksumWriter, Check8, Check8Xor, Checksum};
+/// # #[tokio::main(flavor = "current_thread")]
+/// async fn main() -> std::io::Result<()> {
+///     let mut writer = AsyncChecksumWriter::new(Vec::new(), Check8Xor::new(0));
+///     writer.write_all(&[0x01, 0x02, 0x04]).await?;
+///     let (mut out, checksum) = writer.into_parts();
+///     out.push(checksum);
+///     assert_eq!(out, [0x01, 0x02, 0x04, 0x07]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct AsyncChecksumWriter<W, C> {
+    inner: W,
+    checksum: C,
+}
+
+impl<W: AsyncWrite + Unpin, C: Check8 + Unpin> AsyncChecksumWriter<W, C> {
+    pub fn new(inner: W, checksum: C) -> Self {
+        AsyncChecksumWriter { inner, checksum }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    pub fn checksum(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    pub fn into_parts(self) -> (W, u8) {
+        let checksum = self.checksum.get_checksum();
+        (self.inner, checksum)
+    }
+}
+
+impl<W: AsyncWrite + Unpin, C: Check8 + Unpin> AsyncWrite for AsyncChecksumWriter<W, C> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let written = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        this.checksum.calculate_from_byte_array(reported_part(buf, written)?);
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Reads an async source to its end, adding every byte to a checksum.
+///
+/// - **Parameter**: reader - The source to read.
+/// - **Parameter**: checksum - The checksum to add the data to, which may already have a value.
+/// - **Returns**: The final checksum value, or the first error other than Interrupted.
+///
+pub async fn calculate_from_async_reader<R, C>(reader: &mut R, checksum: &mut C) -> io::Result<u8>
+where
+    R: AsyncRead + Unpin + ?Sized,
+    C: Check8 + ?Sized,
+{
+    let mut buf = [0u8; crate::READ_BUFFER_SIZE];
+    loop {
+        match reader.read(&mut buf).await {
+            Ok(0) => return Ok(checksum.get_checksum()),
+            Ok(n) => {
+                checksum.calculate_from_byte_array(buf.get(..n).unwrap_or(&buf));
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::{Check8Crc, Check8Sum, Checksum};
+
+    #[tokio::test]
+    async fn reader_accumulates_what_is_read() {
+        let mut reader = AsyncChecksumReader::new(&b"123456789"[..], Check8Crc::new(0x07));
+        let mut first = [0u8; 4];
+        reader.read_exact(&mut first).await.unwrap();
+        assert_eq!(reader.checksum(), Check8Crc::new(0x07).calculate_from_string("1234"));
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+        let (_, checksum) = reader.into_parts();
+        assert_eq!(checksum, 0xF4)
+    }
+
+    #[tokio::test]
+    async fn writer_forwards_and_accumulates() {
+        let mut writer = AsyncChecksumWriter::new(Vec::new(), Check8Sum::new(0));
+        writer.write_all(b"hello").await.unwrap();
+        writer.flush().await.unwrap();
+        writer.shutdown().await.unwrap();
+        assert_eq!(writer.get_ref(), b"hello");
+        assert_eq!(writer.checksum(), Check8Sum::new(0).calculate_from_string("hello"))
+    }
+
+    #[tokio::test]
+    async fn async_reader_matches_byte_array() {
+        let data: Vec<u8> = (0..20000u32).map(|i| (i * 7) as u8).collect();
+        let mut crc = Check8Crc::new(0x07);
+        let result = calculate_from_async_reader(&mut &data[..], &mut crc).await.unwrap();
+        assert_eq!(result, Check8Crc::new(0x07).calculate_from_byte_array(&data))
+    }
+
+    #[tokio::test]
+    async fn duplex_stream() {
+        let (client, mut server) = tokio::io::duplex(16);
+        let mut writer = AsyncChecksumWriter::new(client, Check8Crc::new(0x07));
+        let send = async move {
+            writer.write_all(b"123456789").await.unwrap();
+            writer.into_parts().1
+        };
+        let receive = async {
+            let mut received = [0u8; 9];
+            server.read_exact(&mut received).await.unwrap();
+            Check8Crc::new(0x07).calculate_from_byte_array(&received)
+        };
+        let (sent, received) = tokio::join!(send, receive);
+        assert_eq!(sent, received)
+    }
+}
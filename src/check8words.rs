@@ -0,0 +1,143 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Word-at-a-time folds
+//!
+//! The byte sum and byte XOR of a buffer, taken 8 bytes at a time through u64 loads with a
+//! scalar tail, used by Check8Sum and Check8Xor for calculate_from_byte_array.
+//!
+//! With the simd feature (nightly only) the buffer is taken 32 bytes at a time as std::simd
+//! vectors instead (core::simd), which lowers to whatever vector instructions the target has and to the
+//! scalar equivalent where it has none. Both folds are lane-wise, so there is no carry to
+//! manage: wrapping u8 lanes sum modulo 256 just as the checksum does. The CRCs are not
+//! vectorised; without carry-less multiplication a vector CRC is no faster than the table.
+
+#[cfg(feature = "simd")]
+use core::simd::{num::SimdUint, u8x32};
+
+// the sum of the even and the odd bytes of a word is at most 510 per 16-bit lane, so the
+// lanes can take this many words before they must be folded into the total
+#[cfg(all(feature = "sum", not(feature = "simd")))]
+const WORDS_PER_FOLD: usize = 128;
+
+#[cfg(all(feature = "sum", not(feature = "simd")))]
+const EVEN_BYTES: u64 = 0x00FF_00FF_00FF_00FF;
+
+/// Returns the wrapping sum of the bytes of a buffer.
+#[cfg(all(feature = "sum", feature = "simd"))]
+pub(crate) fn sum_bytes(bytes: &[u8]) -> u8 {
+    let (vectors, tail) = bytes.as_chunks::<32>();
+    let lanes = vectors.iter().fold(u8x32::splat(0), |lanes, v| lanes + u8x32::from_array(*v));
+    tail.iter().fold(lanes.reduce_sum(), |total, &b| total.wrapping_add(b))
+}
+
+/// Returns the wrapping sum of the bytes of a buffer.
+#[cfg(all(feature = "sum", not(feature = "simd")))]
+pub(crate) fn sum_bytes(bytes: &[u8]) -> u8 {
+    let (words, tail) = bytes.as_chunks::<8>();
+    let mut total = 0u8;
+    let mut lanes = 0u64;
+    let mut count = 0;
+    for &word in words {
+        let word = u64::from_le_bytes(word);
+        lanes += (word & EVEN_BYTES) + ((word >> 8) & EVEN_BYTES);
+        count += 1;
+        if count == WORDS_PER_FOLD {
+            total = total.wrapping_add(fold_lanes(lanes));
+            lanes = 0;
+            count = 0;
+        }
+    }
+    total = total.wrapping_add(fold_lanes(lanes));
+    tail.iter().fold(total, |total, &b| total.wrapping_add(b))
+}
+
+// adds the four 16-bit lanes of a word together, keeping the low byte
+#[cfg(all(feature = "sum", not(feature = "simd")))]
+fn fold_lanes(lanes: u64) -> u8 {
+    (0..4).fold(0u8, |total, lane| total.wrapping_add((lanes >> (16 * lane)) as u8))
+}
+
+/// Returns the XOR of the bytes of a buffer.
+#[cfg(all(feature = "xor", feature = "simd"))]
+pub(crate) fn xor_bytes(bytes: &[u8]) -> u8 {
+    let (vectors, tail) = bytes.as_chunks::<32>();
+    let lanes = vectors.iter().fold(u8x32::splat(0), |lanes, v| lanes ^ u8x32::from_array(*v));
+    tail.iter().fold(lanes.reduce_xor(), |acc, &b| acc ^ b)
+}
+
+/// Returns the XOR of the bytes of a buffer.
+#[cfg(all(feature = "xor", not(feature = "simd")))]
+pub(crate) fn xor_bytes(bytes: &[u8]) -> u8 {
+    let (words, tail) = bytes.as_chunks::<8>();
+    let mut acc = 0u64;
+    for &word in words {
+        acc ^= u64::from_le_bytes(word);
+    }
+    acc ^= acc >> 32;
+    acc ^= acc >> 16;
+    acc ^= acc >> 8;
+    tail.iter().fold(acc as u8, |acc, &b| acc ^ b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every length up to a few folds, at every alignment of the start of the buffer
+    fn for_each_case(mut f: impl FnMut(&[u8])) {
+        let data: [u8; 2100] = core::array::from_fn(|i| (i * 151 + 7) as u8);
+        for start in 0..8 {
+            for len in (0..40).chain([1023, 1024, 1025, 2048, 2092]) {
+                f(&data[start..start + len]);
+            }
+        }
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn sum_matches_byte_loop() {
+        for_each_case(|bytes| {
+            let expected = bytes.iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+            assert_eq!(sum_bytes(bytes), expected, "length {}", bytes.len());
+        })
+    }
+
+    #[cfg(feature = "sum")]
+    #[test]
+    fn sum_of_maximal_bytes_does_not_overflow_lanes() {
+        let bytes = [0xFFu8; 4096];
+        assert_eq!(sum_bytes(&bytes), (4096u32 * 0xFF) as u8)
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn xor_matches_byte_loop() {
+        for_each_case(|bytes| {
+            let expected = bytes.iter().fold(0u8, |acc, &b| acc ^ b);
+            assert_eq!(xor_bytes(bytes), expected, "length {}", bytes.len());
+        })
+    }
+}
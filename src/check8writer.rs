@@ -0,0 +1,167 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ChecksumWriter
+//!
+//! A pass-through writer that forwards everything to an inner writer while adding the bytes
+//! the inner writer accepted to a checksum, so a trailer can be appended to a stream without
+//! buffering it. Available with the std feature, for std::io::Write, or the embedded-io
+//! feature, for embedded_io::Write.
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+#[cfg(feature = "std")]
+use crate::check8error::reported_part;
+use crate::Check8;          // for the Check8 trait
+
+/// # Provided Methods
+///
+/// - new: Wraps a writer and a checksum, which may already have a value.
+/// - get_ref, get_mut: Retrieve the inner writer.
+/// - checksum: Retrieves the checksum so far.
+/// - into_parts: Returns the inner writer and the final checksum value.
+///
+/// Only the bytes the inner writer reports as written are added, so a short write does not
+/// put the checksum out of step with the stream.
+///
+/// # Examples
+///
+/// With the std feature:
+///
+#[cfg_attr(feature = "std", doc = "```rust")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use std::io::Write;
+/// use crate::check8::{Check8, Check8Parity, Checksum, ChecksumWriter};
+/// fn main() -> std::io::Result<()> {
+///     // stream a record out, then append its even parity (the XOR) as a trailer byte
+///     let mut writer = ChecksumWriter::new(Vec::new(), Check8Parity::new(0));
+///     writer.write_all(&[0x01, 0x02])?;
+///     writer.write_all(&[0x04, 0x08])?;
+///     let (mut out, checksum) = writer.into_parts();
+///     out.push(checksum);
+///     assert_eq!(out, [0x01, 0x02, 0x04, 0x08, 0x0F]);
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub struct ChecksumWriter<W, C> {
+    inner: W,
+    checksum: C,
+}
+
+impl<W, C: Check8> ChecksumWriter<W, C> {
+    pub fn new(inner: W, checksum: C) -> Self {
+        ChecksumWriter { inner, checksum }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    pub fn checksum(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    pub fn into_parts(self) -> (W, u8) {
+        let checksum = self.checksum.get_checksum();
+        (self.inner, checksum)
+    }
+
+    // adds the bytes the inner writer reported as written
+    pub(crate) fn add_written(&mut self, bytes: &[u8]) {
+        self.checksum.calculate_from_byte_array(bytes);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write, C: Check8> Write for ChecksumWriter<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.add_written(reported_part(buf, written)?);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{Check8Lrc, Checksum};
+
+    // a writer that accepts at most 3 bytes at a time
+    struct Trickle(Vec<u8>);
+
+    impl Write for Trickle {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = buf.len().min(3);
+            self.0.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn forwards_and_accumulates() {
+        let mut writer = ChecksumWriter::new(Vec::new(), Check8Lrc::new(0));
+        writer.write_all(b"1234").unwrap();
+        write!(writer, "{}", 56789).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.get_ref(), b"123456789");
+        assert_eq!(writer.checksum(), 0x23);
+        let (out, checksum) = writer.into_parts();
+        assert_eq!(out, b"123456789");
+        assert_eq!(checksum, 0x23)
+    }
+
+    #[test]
+    fn short_writes_are_counted_once() {
+        let mut writer = ChecksumWriter::new(Trickle(Vec::new()), Check8Lrc::new(0));
+        assert_eq!(writer.write(b"abcdef").unwrap(), 3);
+        writer.write_all(b"def").unwrap();
+        let (out, checksum) = writer.into_parts();
+        assert_eq!(out.0, b"abcdef");
+        assert_eq!(checksum, Check8Lrc::new(0).calculate_from_string("abcdef"))
+    }
+
+    #[test]
+    fn copy_through_writer() {
+        let mut writer = ChecksumWriter::new(Vec::new(), Check8Lrc::new(0));
+        io::copy(&mut &b"hello"[..], &mut writer).unwrap();
+        assert_eq!(writer.checksum(), Check8Lrc::new(0).calculate_from_string("hello"))
+    }
+}
@@ -29,42 +29,65 @@ SOFTWARE.
 //! An 8-bit XOR sum checksum type with tests, implements Check8 trait
 
 use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Finalise;        // for with_finalise
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Check8Xor
 {
     accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    finalise: Finalise,
 }
 
 /// # Provided Methods
 ///
 /// - new: Creates a new instance of the type, initialises the accumulator.
+/// - with_finalise: Creates a new instance of the type, initialises the accumulator and selects how the checksum is finalised.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - get_checksum: Retrieves the checksum, the accumulator after the selected finalisation.
 /// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
 /// - add: Adds a given value to the accumulator using the appropriate algorithm and returns the updated value.
+/// - remove: Removes a previously added value from the accumulator and returns the updated value.
 ///
 /// # Examples
 //s
 /// ```rust
-/// use crate::check8::{Check8, Check8Xor};
+/// use crate::check8::{Check8, Check8Xor, Checksum, Finalise};
 /// fn main() {
 ///     let mut sum = Check8Xor::new(0x00);
 ///     sum.init(0x01);
 ///     let result = sum.add(0x07);
 ///     assert_eq!(result, 0x06);
 ///     assert_eq!(sum.get_accum(), 0x06);
+///
+///     // "XOR all bytes then invert"
+///     let mut sum = Check8Xor::with_finalise(0x00, Finalise::XorOut(0xFF));
+///     assert_eq!(sum.calculate_from_byte_array(&[0x01, 0x07]), 0xF9);
 /// }
 /// ```
 ///
-
 impl Check8 for Check8Xor
 {
-    fn new(initial: u8) -> impl Check8 {
-        Check8Xor { accum: initial }
+    fn new(initial: u8) -> Self {
+        Self::with_finalise(initial, Finalise::None)
     }
+}
 
+impl Checksum<u8> for Check8Xor
+{
     fn get_accum(&self) -> u8 {
         self.accum
     }
 
+    fn get_checksum(&self) -> u8 {
+        self.finalise.apply(self.accum)
+    }
+
     fn init(&mut self, val: u8) -> u8 {
         self.accum = val;
         self.accum
@@ -75,11 +98,10 @@ impl Check8 for Check8Xor
         self.accum
     }
 
+    // 8 bytes at a time, see check8words
     fn calculate_from_byte_array(&mut self, array: &[u8]) -> u8 {
-        for val in array {
-            self.add(*val);
-        }
-        self.accum
+        self.accum ^= crate::check8words::xor_bytes(array);
+        self.get_checksum()
     }
 
     fn calculate_from_string(&mut self, string: &str) -> u8 {
@@ -87,6 +109,37 @@ impl Check8 for Check8Xor
     }
 }
 
+impl Check8Xor {
+    /// Creates a new instance with the accumulator seeded from `initial` and the given finalisation
+    /// applied by get_checksum, e.g. `Finalise::XorOut(0xFF)` to invert or `Finalise::Negate`.
+    pub fn with_finalise(initial: u8, finalise: Finalise) -> Self {
+        Check8Xor { accum: initial, initial, finalise }
+    }
+
+    /// Removes a value that was previously added, using XOR, which is its own inverse, and returns the updated value.
+    ///
+    /// The checksum does not depend on the order of the bytes, so when one byte of a record
+    /// changes the checksum can be updated by removing the old value and adding the new one,
+    /// rather than recalculating over the whole record.
+    pub fn remove(&mut self, val: u8) -> u8 {
+        self.accum ^= val;
+        self.accum
+    }
+
+    /// Returns the accumulator to the value it was created with, keeping the finalisation, and
+    /// returns it. init does not change that value.
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+// the same as new(0)
+impl Default for Check8Xor {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +201,60 @@ mod tests {
         let result = sum.calculate_from_string("hello");
         assert_eq!(result, expected)
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_accum() {
+        let mut sum = Check8Xor::new(0);
+        sum.calculate_from_string("hel");
+
+        let json = serde_json::to_string(&sum).unwrap();
+        let mut resumed: Check8Xor = serde_json::from_str(&json).unwrap();
+        assert_eq!(resumed.get_accum(), sum.get_accum());
+
+        let mut whole = Check8Xor::new(0);
+        assert_eq!(resumed.calculate_from_string("lo"), whole.calculate_from_string("hello"))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_loads_state_saved_without_finalise() {
+        let resumed: Check8Xor = serde_json::from_str(r#"{"accum":5}"#).unwrap();
+        assert_eq!(resumed.get_accum(), 5);
+        assert_eq!(resumed.get_checksum(), 5)
+    }
+
+    #[test]
+    fn remove_then_add_matches_recalculation() {
+        let mut record: [u8; 4] = [0x10, 0x20, 0x30, 0x40];
+        let mut sum = Check8Xor::new(0);
+        sum.calculate_from_byte_array(&record);
+
+        sum.remove(record[2]);
+        record[2] = 0xFE;
+        let result = sum.add(record[2]);
+
+        let mut expected = Check8Xor::new(0);
+        assert_eq!(result, expected.calculate_from_byte_array(&record))
+    }
+
+    #[test]
+    fn remove_undoes_add() {
+        let mut sum = Check8Xor::new(0x5A);
+        sum.add(0xC3);
+        assert_eq!(sum.remove(0xC3), 0x5A)
+    }
+
+    #[test]
+    fn finalise_applies_to_checksum_not_accumulator() {
+        let mut plain = Check8Xor::new(0xA5);
+        let expected = plain.calculate_from_string("hello");
+
+        let mut inverted = Check8Xor::with_finalise(0xA5, Finalise::XorOut(0xFF));
+        assert_eq!(inverted.calculate_from_string("hello"), !expected);
+        assert_eq!(inverted.get_accum(), expected);
+
+        let mut negated = Check8Xor::with_finalise(0xA5, Finalise::Negate);
+        assert_eq!(negated.calculate_from_string("hello"), expected.wrapping_neg())
+    }
+}
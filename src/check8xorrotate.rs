@@ -0,0 +1,165 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check8XorRotate
+//!
+//! An 8-bit rotate-and-XOR checksum type with tests, implements Check8 trait.
+//! The accumulator is rotated left by a configurable number of bits before each byte is
+//! XORed in, so unlike plain XOR the checksum depends on the order of the bytes.
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Check8XorRotate
+{
+    accum: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    initial: u8,
+    rotation: u32,
+}
+
+/// # Provided Methods
+///
+/// - new: Creates a new instance of the type that rotates left by one bit, initialises the accumulator.
+/// - with_rotation: Creates a new instance of the type that rotates left by the given number of bits.
+///   A rotation right by n bits is a rotation left by 8 - n.
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - get_rotation: Retrieves the number of bits the accumulator is rotated left by.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - reset: Returns the accumulator to the value it was created with and returns it.
+/// - add: Rotates the accumulator, XORs a given value into it and returns the updated value.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Check8XorRotate, Checksum};
+/// fn main() {
+///     let mut sum = Check8XorRotate::new(0x00);
+///     sum.init(0x81);
+///     // 0x81 rotated left is 0x03, XOR 0x01
+///     let result = sum.add(0x01);
+///     assert_eq!(result, 0x02);
+///
+///     // rotate right by one bit
+///     let mut sum = Check8XorRotate::with_rotation(0x81, 7);
+///     assert_eq!(sum.add(0x01), 0xC1);
+/// }
+/// ```
+///
+impl Check8XorRotate {
+    pub fn with_rotation(initial: u8, rotation: u32) -> Self {
+        Check8XorRotate { accum: initial, initial, rotation: rotation % 8 }
+    }
+
+    pub fn get_rotation(&self) -> u32 {
+        self.rotation
+    }
+
+    pub fn reset(&mut self) -> u8 {
+        self.init(self.initial)
+    }
+}
+
+impl Check8 for Check8XorRotate
+{
+    fn new(initial: u8) -> Self {
+        Self::with_rotation(initial, 1)
+    }
+}
+
+impl Checksum<u8> for Check8XorRotate
+{
+    fn get_accum(&self) -> u8 {
+        self.accum
+    }
+
+    fn init(&mut self, val: u8) -> u8 {
+        self.accum = val;
+        self.accum
+    }
+
+    fn add(&mut self, val: u8) -> u8 {
+        self.accum = self.accum.rotate_left(self.rotation) ^ val;
+        self.accum
+    }
+}
+
+// the same as new(0)
+impl Default for Check8XorRotate {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    #[test]
+    fn new_sets_initial() {
+        let sum = Check8XorRotate::new(10);
+        assert_eq!(sum.get_accum(), 10)
+    }
+
+    #[cfg(feature = "xor")]
+    #[test]
+    fn zero_rotation_matches_xor() {
+        let mut sum = Check8XorRotate::with_rotation(0, 0);
+        let mut xor = Check8Xor::new(0);
+        assert_eq!(sum.calculate_from_string("hello"), xor.calculate_from_string("hello"))
+    }
+
+    #[test]
+    fn rotation_is_taken_modulo_8() {
+        assert_eq!(Check8XorRotate::with_rotation(0, 9).get_rotation(), 1)
+    }
+
+    #[test]
+    fn calculate_from_string_returns_correct_checksum() {
+        let test_string = "hello";
+
+        // calculate the rotate-left-by-3 XOR of the test string, with the rotation done in 16 bits
+        let mut expected: u8 = 0;
+        for val in test_string.as_bytes() {
+            let doubled = ((expected as u16) << 8) | expected as u16;
+            expected = ((doubled >> 5) as u8) ^ *val;
+        }
+
+        let mut sum = Check8XorRotate::with_rotation(0, 3);
+        assert_eq!(sum.calculate_from_string(test_string), expected)
+    }
+
+    #[test]
+    fn detects_transposed_bytes() {
+        let mut a = Check8XorRotate::new(0);
+        let mut b = Check8XorRotate::new(0);
+        assert_ne!(a.calculate_from_byte_array(&[0x01, 0x02]), b.calculate_from_byte_array(&[0x02, 0x01]))
+    }
+}
@@ -0,0 +1,150 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Damm
+//!
+//! The Damm check digit, based on a totally anti-symmetric quasigroup of order 10. It detects
+//! all single-digit errors and all adjacent transpositions, and the digits followed by their
+//! check digit always reduce to zero.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::append_digit;
+use super::{digit_value, CheckDigitError};
+
+/// The weakly totally anti-symmetric quasigroup table from Damm's thesis, with a zero diagonal.
+pub const DAMM_TABLE: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+// the interim digit after processing all of the digits
+fn reduce(digits: &[u8]) -> Result<u8, CheckDigitError> {
+    let mut interim = 0u8;
+    for (position, &digit) in digits.iter().enumerate() {
+        interim = DAMM_TABLE[interim as usize][digit_value(digit, position)? as usize];
+    }
+    Ok(interim)
+}
+
+/// Calculates the Damm check digit for a sequence of digits.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The check digit as a numeric value (0 to 9), or a CheckDigitError.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checkdigit::damm;
+/// fn main() {
+///     assert_eq!(damm::compute(b"572"), Ok(4));
+///     assert!(damm::verify(b"5724"));
+///     assert_eq!(damm::append(b"572").unwrap(), b"5724".to_vec());
+/// }
+/// ```
+///
+pub fn compute(digits: &[u8]) -> Result<u8, CheckDigitError> {
+    reduce(digits)
+}
+
+/// Verifies a sequence of digits whose last digit is a Damm check digit.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, including the check digit.
+/// - **Returns**: true if the check digit is correct, false if it is not or a digit is invalid.
+pub fn verify(digits: &[u8]) -> bool {
+    !digits.is_empty() && reduce(digits) == Ok(0)
+}
+
+/// Copies a sequence of digits and appends its Damm check digit, in the same form as the input.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The digits followed by the check digit, or a CheckDigitError.
+#[cfg(feature = "alloc")]
+pub fn append(digits: &[u8]) -> Result<Vec<u8>, CheckDigitError> {
+    let check = compute(digits)?;
+    Ok(append_digit(digits, check))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wikipedia_example() {
+        assert_eq!(compute(b"572"), Ok(4));
+        assert!(verify(b"5724"));
+        assert!(!verify(b"5723"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn numeric_digits() {
+        assert_eq!(compute(&[5, 7, 2]), Ok(4));
+        assert_eq!(append(&[5, 7, 2]).unwrap(), vec![5, 7, 2, 4])
+    }
+
+    #[test]
+    fn invalid_digit_is_reported() {
+        assert_eq!(compute(b"5x2"), Err(CheckDigitError::InvalidDigit(1)));
+        assert!(!verify(b"5x24"));
+        assert!(!verify(b""))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn detects_all_single_digit_errors_and_adjacent_transpositions() {
+        // every three-digit payload with its check digit, with one digit changed or two adjacent digits swapped
+        for payload in 0..1000u32 {
+            let digits = [(payload / 100) as u8, (payload / 10 % 10) as u8, (payload % 10) as u8];
+            let valid = append(&digits).unwrap();
+            assert!(verify(&valid));
+            for position in 0..valid.len() {
+                for digit in 0..10u8 {
+                    if digit != valid[position] {
+                        let mut changed = valid.clone();
+                        changed[position] = digit;
+                        assert!(!verify(&changed))
+                    }
+                }
+                if position + 1 < valid.len() && valid[position] != valid[position + 1] {
+                    let mut swapped = valid.clone();
+                    swapped.swap(position, position + 1);
+                    assert!(!verify(&swapped))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,275 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ISO/IEC 7064
+//!
+//! The check character systems of ISO/IEC 7064, for numeric and alphanumeric identifiers.
+//!
+//! - Pure systems: MOD 11-2 (one check character, 0 to 9 or X), MOD 37-2 (one check character,
+//!   0 to 9, A to Z or *) and MOD 97-10 (two check digits, as used by IBANs once the letters
+//!   have been converted to digits and the country code and check digits moved to the end).
+//! - Hybrid systems: MOD 11,10 (one check digit) and MOD 37,36 (one check character, 0 to 9 or A to Z).
+//!
+//! Unlike the other check digit algorithms the check characters may be letters, so they are
+//! returned as ASCII. Letters are accepted in either case.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::CheckDigitError;
+
+/// The characters of the alphanumeric systems in order of their values, with * as 36.
+const ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ*";
+
+/// # Variants
+///
+/// - Mod11_2: The pure MOD 11-2 system for numeric data, e.g. ISNI and ORCID identifiers.
+/// - Mod37_2: The pure MOD 37-2 system for alphanumeric data.
+/// - Mod97_10: The pure MOD 97-10 system for numeric data, e.g. IBANs.
+/// - Mod11_10: The hybrid MOD 11,10 system for numeric data.
+/// - Mod37_36: The hybrid MOD 37,36 system for alphanumeric data.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Iso7064 {
+    Mod11_2,
+    Mod37_2,
+    Mod97_10,
+    Mod11_10,
+    Mod37_36,
+}
+
+impl Iso7064 {
+    /// The number of check characters the system appends.
+    pub const fn check_len(self) -> usize {
+        match self {
+            Iso7064::Mod97_10 => 2,
+            _ => 1,
+        }
+    }
+
+    // the modulus M of a pure system, or the M of a hybrid M, M+1 system
+    const fn modulus(self) -> u32 {
+        match self {
+            Iso7064::Mod11_2 => 11,
+            Iso7064::Mod37_2 => 37,
+            Iso7064::Mod97_10 => 97,
+            Iso7064::Mod11_10 => 10,
+            Iso7064::Mod37_36 => 36,
+        }
+    }
+
+    const fn alphanumeric(self) -> bool {
+        matches!(self, Iso7064::Mod37_2 | Iso7064::Mod37_36)
+    }
+
+    // the value of a data character, or of a check character if check is true
+    fn value(self, c: u8, position: usize, check: bool) -> Result<u32, CheckDigitError> {
+        let alphanumeric = self.alphanumeric();
+        match c {
+            0..=9 => Ok(c as u32),
+            b'0'..=b'9' => Ok((c - b'0') as u32),
+            b'A'..=b'Z' if alphanumeric => Ok((c - b'A') as u32 + 10),
+            b'a'..=b'z' if alphanumeric => Ok((c - b'a') as u32 + 10),
+            b'X' | b'x' if check && self == Iso7064::Mod11_2 => Ok(10),
+            b'*' if check && self == Iso7064::Mod37_2 => Ok(36),
+            _ => Err(CheckDigitError::InvalidCharacter(position)),
+        }
+    }
+
+    // the numeric check value of the data
+    fn check_value(self, data: &[u8]) -> Result<u32, CheckDigitError> {
+        let m = self.modulus();
+        let mut p;
+        match self {
+            Iso7064::Mod11_2 | Iso7064::Mod37_2 | Iso7064::Mod97_10 => {
+                let radix = if self == Iso7064::Mod97_10 { 10 } else { 2 };
+                p = 0;
+                for (position, &c) in data.iter().enumerate() {
+                    p = (p + self.value(c, position, false)?) * radix % m;
+                }
+                // one further step for each check character after the first
+                for _ in 1..self.check_len() {
+                    p = p * radix % m;
+                }
+            }
+            Iso7064::Mod11_10 | Iso7064::Mod37_36 => {
+                p = m;
+                for (position, &c) in data.iter().enumerate() {
+                    let mut s = (p + self.value(c, position, false)?) % m;
+                    if s == 0 {
+                        s = m;
+                    }
+                    p = s * 2 % (m + 1);
+                }
+            }
+        }
+        Ok((m + 1 - p) % m)
+    }
+}
+
+/// Calculates the ISO/IEC 7064 check characters for a sequence of characters.
+///
+/// - **Parameter**: system - The check character system.
+/// - **Parameter**: data - The characters, ASCII (or numeric digits), without check characters.
+/// - **Returns**: The check characters in ASCII and their number, which is the system's
+///   check_len, or a CheckDigitError. Only the first check_len bytes of the array are used.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checkdigit::iso7064::{self, Iso7064};
+/// fn main() {
+///     let (chars, len) = iso7064::compute(Iso7064::Mod11_2, b"079").unwrap();
+///     assert_eq!(&chars[..len], b"X");
+///     let (chars, len) = iso7064::compute(Iso7064::Mod97_10, b"794").unwrap();
+///     assert_eq!(&chars[..len], b"44");
+///     assert!(iso7064::verify(Iso7064::Mod37_2, b"G123498654321H"));
+/// }
+/// ```
+///
+pub fn compute(system: Iso7064, data: &[u8]) -> Result<([u8; 2], usize), CheckDigitError> {
+    let check = system.check_value(data)?;
+    let chars = match system {
+        Iso7064::Mod11_2 if check == 10 => [b'X', 0],
+        Iso7064::Mod97_10 => [b'0' + (check / 10) as u8, b'0' + (check % 10) as u8],
+        _ => [ALPHABET[check as usize], 0],
+    };
+    Ok((chars, system.check_len()))
+}
+
+/// Verifies a sequence of characters ending with its ISO/IEC 7064 check characters.
+///
+/// - **Parameter**: system - The check character system.
+/// - **Parameter**: data - The characters, ASCII (or numeric digits), including the check characters.
+/// - **Returns**: true if the check characters are correct, false if they are not or a character is invalid.
+pub fn verify(system: Iso7064, data: &[u8]) -> bool {
+    if data.len() <= system.check_len() {
+        return false;
+    }
+    let (payload, check) = data.split_at(data.len() - system.check_len());
+    let mut found = 0;
+    for (i, &c) in check.iter().enumerate() {
+        match system.value(c, payload.len() + i, true) {
+            Ok(value) => found = found * 10 + value,
+            Err(_) => return false,
+        }
+    }
+    system.check_value(payload) == Ok(found)
+}
+
+/// Copies a sequence of characters and appends its ISO/IEC 7064 check characters in ASCII.
+///
+/// - **Parameter**: system - The check character system.
+/// - **Parameter**: data - The characters, ASCII (or numeric digits), without check characters.
+/// - **Returns**: The characters followed by the check characters, or a CheckDigitError.
+#[cfg(feature = "alloc")]
+pub fn append(system: Iso7064, data: &[u8]) -> Result<Vec<u8>, CheckDigitError> {
+    let (chars, len) = compute(system, data)?;
+    let mut result = data.to_vec();
+    result.extend_from_slice(&chars[..len]);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the check characters of compute as a slice
+    fn check_chars(system: Iso7064, data: &[u8]) -> Result<std::vec::Vec<u8>, CheckDigitError> {
+        compute(system, data).map(|(chars, len)| chars[..len].to_vec())
+    }
+
+    #[test]
+    fn standard_examples() {
+        assert_eq!(check_chars(Iso7064::Mod11_2, b"079").unwrap(), b"X");
+        assert_eq!(check_chars(Iso7064::Mod37_2, b"G123498654321").unwrap(), b"H");
+        assert_eq!(check_chars(Iso7064::Mod97_10, b"794").unwrap(), b"44");
+        assert_eq!(check_chars(Iso7064::Mod11_10, b"0794").unwrap(), b"5");
+        assert_eq!(check_chars(Iso7064::Mod37_36, b"A12425GABC1234002").unwrap(), b"M")
+    }
+
+    #[test]
+    fn verify_standard_examples() {
+        assert!(verify(Iso7064::Mod11_2, b"079X"));
+        assert!(verify(Iso7064::Mod11_2, b"079x"));
+        assert!(verify(Iso7064::Mod37_2, b"G123498654321H"));
+        assert!(verify(Iso7064::Mod97_10, b"79444"));
+        assert!(verify(Iso7064::Mod11_10, b"07945"));
+        assert!(verify(Iso7064::Mod37_36, b"a12425gabc1234002m"));
+        assert!(!verify(Iso7064::Mod97_10, b"79445"));
+        assert!(!verify(Iso7064::Mod11_2, b"X"))
+    }
+
+    #[test]
+    fn orcid() {
+        // ORCID iDs use MOD 11-2 over the first 15 digits
+        assert!(verify(Iso7064::Mod11_2, b"0000000218250097"));
+        assert!(!verify(Iso7064::Mod11_2, b"000000021825009X"))
+    }
+
+    #[test]
+    fn iban() {
+        // GB82 WEST 1234 5698 7654 32 rearranged, with W=32, E=14, S=28, T=29, G=16, B=11
+        let digits = b"3214282912345698765432161182";
+        assert_eq!(digits[digits.len() - 2..], *b"82");
+        assert!(verify(Iso7064::Mod97_10, digits))
+    }
+
+    #[test]
+    fn invalid_characters_are_reported() {
+        assert_eq!(compute(Iso7064::Mod11_2, b"07A"), Err(CheckDigitError::InvalidCharacter(2)));
+        assert_eq!(compute(Iso7064::Mod37_2, b"AB*"), Err(CheckDigitError::InvalidCharacter(2)));
+        assert!(!verify(Iso7064::Mod11_10, b"0794X"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn append_adds_check_characters() {
+        assert_eq!(append(Iso7064::Mod97_10, b"794").unwrap(), b"79444".to_vec());
+        for system in [Iso7064::Mod11_2, Iso7064::Mod37_2, Iso7064::Mod97_10, Iso7064::Mod11_10, Iso7064::Mod37_36] {
+            assert!(verify(system, &append(system, b"2024103117").unwrap()))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn detects_single_character_errors() {
+        for system in [Iso7064::Mod11_2, Iso7064::Mod37_2, Iso7064::Mod97_10, Iso7064::Mod11_10, Iso7064::Mod37_36] {
+            let valid = append(system, b"31415926").unwrap();
+            for position in 0..valid.len() - system.check_len() {
+                for digit in b'0'..=b'9' {
+                    if digit != valid[position] {
+                        let mut changed = valid.clone();
+                        changed[position] = digit;
+                        assert!(!verify(system, &changed))
+                    }
+                }
+            }
+        }
+    }
+}
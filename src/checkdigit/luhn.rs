@@ -0,0 +1,156 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Luhn
+//!
+//! The Luhn (mod 10) check digit, used by payment card numbers and IMEIs. It detects all
+//! single-digit errors and most adjacent transpositions, but not 09 <-> 90.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::append_digit;
+use super::{digit_value, CheckDigitError};
+
+/// Calculates the Luhn check digit for a sequence of digits.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The check digit as a numeric value (0 to 9), or a CheckDigitError.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checkdigit::luhn;
+/// fn main() {
+///     assert_eq!(luhn::compute(b"7992739871"), Ok(3));
+///     assert!(luhn::verify(b"79927398713"));
+///     assert_eq!(luhn::append(b"7992739871").unwrap(), b"79927398713".to_vec());
+/// }
+/// ```
+///
+pub fn compute(digits: &[u8]) -> Result<u8, CheckDigitError> {
+    let mut sum = 0u32;
+    // the digit to the left of the check digit is doubled, and every second digit from there
+    for (i, &digit) in digits.iter().rev().enumerate() {
+        let mut value = digit_value(digit, digits.len() - 1 - i)? as u32;
+        if i % 2 == 0 {
+            value *= 2;
+            if value > 9 {
+                value -= 9;
+            }
+        }
+        sum += value;
+    }
+    Ok(((10 - sum % 10) % 10) as u8)
+}
+
+/// Verifies a sequence of digits whose last digit is a Luhn check digit.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, including the check digit.
+/// - **Returns**: true if the check digit is correct, false if it is not or a digit is invalid.
+pub fn verify(digits: &[u8]) -> bool {
+    match digits.split_last() {
+        Some((&check, payload)) => {
+            match (compute(payload), digit_value(check, payload.len())) {
+                (Ok(expected), Ok(actual)) => expected == actual,
+                _ => false,
+            }
+        }
+        None => false,
+    }
+}
+
+/// Copies a sequence of digits and appends its Luhn check digit, in the same form as the input.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The digits followed by the check digit, or a CheckDigitError.
+#[cfg(feature = "alloc")]
+pub fn append(digits: &[u8]) -> Result<Vec<u8>, CheckDigitError> {
+    let check = compute(digits)?;
+    Ok(append_digit(digits, check))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wikipedia_example() {
+        assert_eq!(compute(b"7992739871"), Ok(3));
+        assert!(verify(b"79927398713"));
+        assert!(!verify(b"79927398710"))
+    }
+
+    #[test]
+    fn card_and_imei() {
+        // Visa test card number and an example IMEI
+        assert!(verify(b"4111111111111111"));
+        assert_eq!(compute(b"49015420323751"), Ok(8));
+        assert!(verify(b"490154203237518"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn numeric_digits() {
+        assert_eq!(compute(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1]), Ok(3));
+        assert_eq!(append(&[1, 2, 3]).unwrap(), vec![1, 2, 3, 0])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn append_ascii() {
+        assert_eq!(append(b"123").unwrap(), b"1230".to_vec())
+    }
+
+    #[test]
+    fn invalid_digit_is_reported() {
+        assert_eq!(compute(b"12a4"), Err(CheckDigitError::InvalidDigit(2)));
+        assert!(!verify(b"12a4"));
+        assert!(!verify(b""))
+    }
+
+    #[test]
+    fn all_invalid_digits_do_not_verify() {
+        assert!(!verify(b"aa"));
+        assert!(!verify(b"1x?"));
+        assert!(!verify(b"x"))
+    }
+
+    #[test]
+    fn detects_single_digit_errors() {
+        let valid = b"79927398713";
+        for position in 0..valid.len() {
+            for digit in b'0'..=b'9' {
+                if digit != valid[position] {
+                    let mut changed = *valid;
+                    changed[position] = digit;
+                    assert!(!verify(&changed))
+                }
+            }
+        }
+    }
+}
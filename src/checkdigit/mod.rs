@@ -0,0 +1,108 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Check Digits
+//!
+//! Decimal check digit algorithms, for identifiers such as card numbers, IMEIs and labels that
+//! carry a single check digit rather than a checksum byte.
+//!
+//! Digits may be given either as ASCII characters (b'0' to b'9') or as numeric values (0 to 9).
+//! Check digits are returned as numeric values, and `append` adds the check digit in the same
+//! form as the first digit of its input. The ISO/IEC 7064 systems, whose check characters may
+//! be letters, return and append them as ASCII.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::fmt;
+
+pub mod luhn;  // the Luhn (mod 10) algorithm
+pub mod damm;  // the Damm quasigroup algorithm
+pub mod verhoeff;  // the Verhoeff dihedral group algorithm
+pub mod iso7064;  // the ISO/IEC 7064 pure and hybrid systems
+
+/// # Variants
+///
+/// - InvalidDigit: The byte at the given position is neither an ASCII nor a numeric decimal digit.
+/// - InvalidCharacter: The byte at the given position is not in the character set of the check character system.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CheckDigitError {
+    InvalidDigit(usize),
+    InvalidCharacter(usize),
+}
+
+impl fmt::Display for CheckDigitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckDigitError::InvalidDigit(position) => write!(f, "invalid decimal digit at position {}", position),
+            CheckDigitError::InvalidCharacter(position) => write!(f, "invalid character at position {}", position),
+        }
+    }
+}
+
+impl core::error::Error for CheckDigitError {}
+
+// the numeric value of an ASCII or numeric digit
+fn digit_value(digit: u8, position: usize) -> Result<u8, CheckDigitError> {
+    match digit {
+        0..=9 => Ok(digit),
+        b'0'..=b'9' => Ok(digit - b'0'),
+        _ => Err(CheckDigitError::InvalidDigit(position)),
+    }
+}
+
+// copies the digits and adds the check digit, as ASCII if the digits are ASCII
+#[cfg(feature = "alloc")]
+fn append_digit(digits: &[u8], check: u8) -> Vec<u8> {
+    let ascii = digits.first().is_some_and(|d| d.is_ascii_digit());
+    let mut result = Vec::with_capacity(digits.len() + 1);
+    result.extend_from_slice(digits);
+    result.push(if ascii { b'0' + check } else { check });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digit_value_accepts_ascii_and_numeric() {
+        assert_eq!(digit_value(b'7', 0), Ok(7));
+        assert_eq!(digit_value(7, 0), Ok(7));
+        assert_eq!(digit_value(b'A', 3), Err(CheckDigitError::InvalidDigit(3)));
+        assert_eq!(digit_value(10, 1), Err(CheckDigitError::InvalidDigit(1)))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn append_digit_matches_input_form() {
+        assert_eq!(append_digit(b"12", 3), b"123".to_vec());
+        assert_eq!(append_digit(&[1, 2], 3), vec![1, 2, 3]);
+        assert_eq!(append_digit(&[], 0), vec![0])
+    }
+}
@@ -0,0 +1,186 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Verhoeff
+//!
+//! The Verhoeff check digit, based on the dihedral group D5. It detects all single-digit errors
+//! and all adjacent transpositions, and is mandated by some national identifiers such as the
+//! Indian Aadhaar number.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use super::append_digit;
+use super::{digit_value, CheckDigitError};
+
+/// The multiplication table of the dihedral group D5.
+pub const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// The permutation applied to a digit according to its position from the right, modulo 8.
+pub const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// The inverse of each element of D5.
+pub const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+// the group product of the digits, with the rightmost digit at position offset from the right
+fn reduce(digits: &[u8], offset: usize) -> Result<u8, CheckDigitError> {
+    let mut c = 0u8;
+    for (i, &digit) in digits.iter().rev().enumerate() {
+        let value = digit_value(digit, digits.len() - 1 - i)?;
+        c = VERHOEFF_D[c as usize][VERHOEFF_P[(i + offset) % 8][value as usize] as usize];
+    }
+    Ok(c)
+}
+
+/// Calculates the Verhoeff check digit for a sequence of digits.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The check digit as a numeric value (0 to 9), or a CheckDigitError.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checkdigit::verhoeff;
+/// fn main() {
+///     assert_eq!(verhoeff::compute(b"236"), Ok(3));
+///     assert!(verhoeff::verify(b"2363"));
+///     assert_eq!(verhoeff::append(b"236").unwrap(), b"2363".to_vec());
+/// }
+/// ```
+///
+pub fn compute(digits: &[u8]) -> Result<u8, CheckDigitError> {
+    // the check digit will occupy position 0, so the payload starts at position 1
+    Ok(VERHOEFF_INV[reduce(digits, 1)? as usize])
+}
+
+/// Verifies a sequence of digits whose last digit is a Verhoeff check digit.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, including the check digit.
+/// - **Returns**: true if the check digit is correct, false if it is not or a digit is invalid.
+pub fn verify(digits: &[u8]) -> bool {
+    !digits.is_empty() && reduce(digits, 0) == Ok(0)
+}
+
+/// Copies a sequence of digits and appends its Verhoeff check digit, in the same form as the input.
+///
+/// - **Parameter**: digits - The digits, ASCII or numeric, without a check digit.
+/// - **Returns**: The digits followed by the check digit, or a CheckDigitError.
+#[cfg(feature = "alloc")]
+pub fn append(digits: &[u8]) -> Result<Vec<u8>, CheckDigitError> {
+    let check = compute(digits)?;
+    Ok(append_digit(digits, check))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wikipedia_examples() {
+        assert_eq!(compute(b"236"), Ok(3));
+        assert!(verify(b"2363"));
+        assert_eq!(compute(b"12345"), Ok(1));
+        assert!(verify(b"123451"));
+        assert!(!verify(b"123456"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn numeric_digits() {
+        assert_eq!(compute(&[2, 3, 6]), Ok(3));
+        assert_eq!(append(&[2, 3, 6]).unwrap(), vec![2, 3, 6, 3])
+    }
+
+    #[test]
+    fn invalid_digit_is_reported() {
+        assert_eq!(compute(b"2-6"), Err(CheckDigitError::InvalidDigit(1)));
+        assert!(!verify(b"2-63"));
+        assert!(!verify(b""))
+    }
+
+    #[test]
+    fn tables_are_consistent() {
+        for a in 0..10 {
+            // each element times its inverse is the identity
+            assert_eq!(VERHOEFF_D[a][VERHOEFF_INV[a] as usize], 0);
+            // each row of the permutation table is a permutation
+            for row in VERHOEFF_P.iter() {
+                assert!(row.contains(&(a as u8)))
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn detects_all_single_digit_errors_and_adjacent_transpositions() {
+        // every four-digit payload with its check digit, with one digit changed or two adjacent digits swapped
+        for payload in 0..10000u32 {
+            let digits = [
+                (payload / 1000) as u8,
+                (payload / 100 % 10) as u8,
+                (payload / 10 % 10) as u8,
+                (payload % 10) as u8,
+            ];
+            let valid = append(&digits).unwrap();
+            assert!(verify(&valid));
+            for position in 0..valid.len() {
+                for digit in 0..10u8 {
+                    if digit != valid[position] {
+                        let mut changed = valid.clone();
+                        changed[position] = digit;
+                        assert!(!verify(&changed))
+                    }
+                }
+                if position + 1 < valid.len() && valid[position] != valid[position + 1] {
+                    let mut swapped = valid.clone();
+                    swapped.swap(position, position + 1);
+                    assert!(!verify(&swapped))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,391 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Checksum
+//!
+//! The Checksum trait holds the accumulate, initialise and finalise methods for every width of
+//! checksum, so that the 8, 16 and 32-bit algorithms share them and generic code can be
+//! written once for every width. Check8 is its 8-bit subtrait.
+
+use core::fmt;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+}
+
+/// The width of a checksum, one of u8, u16 and u32.
+///
+/// # Provided Constants
+///
+/// - BITS: The number of bits in the checksum.
+///
+/// # Required Methods
+///
+/// - from_u64: Converts from a u64, discarding the bits above the width.
+/// - to_u64: Converts to a u64.
+///
+pub trait Width: sealed::Sealed + Copy + Default + Eq + fmt::Debug + fmt::LowerHex + fmt::UpperHex + 'static {
+    const BITS: u32;
+
+    fn from_u64(val: u64) -> Self;
+    fn to_u64(self) -> u64;
+}
+
+macro_rules! impl_width {
+    ($($t:ty),*) => {
+        $(
+            impl Width for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                fn from_u64(val: u64) -> Self {
+                    val as $t
+                }
+
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_width!(u8, u16, u32);
+
+///
+/// # Required Methods
+///
+/// - get_accum: Retrieves the current value of the accumulator.
+/// - init: Initialises the accumulator with a given value and returns the initialised value.
+/// - add: Adds a byte to the accumulator using the appropriate algorithm and returns the updated value.
+///
+/// # Provided Methods
+///
+/// - get_checksum:
+///   Retrieves the checksum, which is the accumulator after any finalisation the algorithm applies
+///   (e.g. the output reflection and final XOR of a CRC). By default it is the accumulator itself.
+///   - **Returns**: The checksum as a W.
+///
+/// - calculate_from_byte_array:
+///   Processes a slice of bytes by adding each byte's value to the accumulator using the add method.
+///   Finally, it retrieves the checksum using get_checksum.
+///   - **Parameter**: array - A byte slice to process.
+///   - **Returns**: The final checksum value as a W.
+///
+/// - calculate_from_string:
+///   Converts a string to its byte representation and processes it using calculate_from_byte_array.
+///   - **Parameter**: string - A string whose byte representation is processed.
+///   - **Returns**: The final checksum value as a W.
+///
+/// - calculate_from_slices:
+///   Processes several byte slices in order, as if they had been concatenated, without copying them.
+///   - **Parameter**: parts - The byte slices to process, e.g. a header, payload and footer.
+///   - **Returns**: The final checksum value as a W.
+///
+/// - calculate_from_buf:
+///   Adds the remaining bytes of a bytes::Buf chunk by chunk, without copying them into a contiguous
+///   slice, and advances the Buf past them. Requires the bytes feature.
+///   - **Parameter**: buf - The Buf to consume, e.g. a Bytes or a chain of buffers.
+///   - **Returns**: The final checksum value as a W.
+///
+/// - calculate_from_reader:
+///   Reads a source to its end through an internal buffer, adding every byte, so sockets, pipes and
+///   decompressors can be checksummed without loading them into memory. Requires the std feature.
+///   - **Parameter**: reader - The source to read.
+///   - **Returns**: The final checksum value as a W, or the first error other than Interrupted.
+///
+/// - calculate_from_file:
+///   Opens a file and adds its contents. With the mmap feature, files of 1 MiB or more are
+///   memory-mapped rather than read through a buffer. Requires the std feature.
+///   - **Parameter**: path - The path of the file.
+///   - **Returns**: The final checksum value as a W, or an error opening or reading the file.
+///
+/// - calculate_from_reader_with_progress, calculate_from_file_with_progress:
+///   As calculate_from_reader and calculate_from_file, reporting progress and checking a cancellation
+///   flag between chunks, for inputs large enough to need feedback or an abort. Requires the std feature.
+///   - **Parameter**: progress - Called after each chunk with the number of bytes processed so far.
+///   - **Parameter**: cancel - Checked before each chunk; once set, the calculation stops with an
+///     io::Error wrapping Check8Error::Cancelled, and the checksum holds the chunks added before it.
+///   - **Returns**: The final checksum value as a W, or an error.
+///
+/// - add_u16_le, add_u16_be, add_u32_le, add_u32_be, add_u64_le, add_u64_be:
+///   Adds the bytes of a multi-byte integer in little-endian (le) or big-endian (be) order.
+///   - **Parameter**: val - The integer to add.
+///   - **Returns**: The updated checksum value as a W.
+///
+/// Constructors are left to each type, as the meaning of their parameters (an initial value, a
+/// polynomial) differs; the 8-bit types construct through Check8::new.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::checksum::{Checksum, Width};
+/// use crate::check8::check16::Check16Fletcher;
+/// use crate::check8::Check8Lrc;
+/// fn checksum_of<W: Width>(checksum: &mut impl Checksum<W>, data: &[u8]) -> u64 {
+///     checksum.calculate_from_byte_array(data).to_u64()
+/// }
+///
+/// fn main() {
+///     use crate::check8::Check8;
+///     assert_eq!(checksum_of(&mut Check8Lrc::new(0), b"123456789"), 0x23);
+///     assert_eq!(checksum_of(&mut Check16Fletcher::new(0), b"123456789"), 0x1EDE);
+/// }
+/// ```
+///
+pub trait Checksum<W: Width> {
+    fn get_accum(&self) -> W;
+    fn init(&mut self, val: W) -> W;
+    fn add(&mut self, val: u8) -> W;
+
+    fn get_checksum(&self) -> W {
+        self.get_accum()
+    }
+
+    fn calculate_from_byte_array(&mut self, array: &[u8]) -> W {
+        for val in array {
+            self.add(*val);
+        }
+        self.get_checksum()
+    }
+
+    fn calculate_from_string(&mut self, string: &str) -> W {
+        self.calculate_from_byte_array(string.as_bytes())
+    }
+
+    fn calculate_from_slices(&mut self, parts: &[&[u8]]) -> W {
+        for part in parts {
+            self.calculate_from_byte_array(part);
+        }
+        self.get_checksum()
+    }
+
+    #[cfg(feature = "bytes")]
+    fn calculate_from_buf(&mut self, buf: &mut impl bytes::Buf) -> W where Self: Sized {
+        while buf.has_remaining() {
+            let chunk = buf.chunk();
+            let len = chunk.len();
+            self.calculate_from_byte_array(chunk);
+            buf.advance(len);
+        }
+        self.get_checksum()
+    }
+
+    #[cfg(feature = "std")]
+    fn calculate_from_reader<R: std::io::Read>(&mut self, reader: R) -> std::io::Result<W> where Self: Sized {
+        crate::check8io::read_chunks(reader, |chunk| {
+            self.calculate_from_byte_array(chunk);
+            Ok(())
+        })?;
+        Ok(self.get_checksum())
+    }
+
+    #[cfg(feature = "std")]
+    fn calculate_from_reader_with_progress<R: std::io::Read>(
+        &mut self,
+        reader: R,
+        progress: impl FnMut(u64),
+        cancel: &core::sync::atomic::AtomicBool,
+    ) -> std::io::Result<W> where Self: Sized {
+        let add = |chunk: &[u8]| {
+            self.calculate_from_byte_array(chunk);
+        };
+        crate::check8io::read_chunks(reader, crate::check8io::with_progress(add, progress, cancel))?;
+        Ok(self.get_checksum())
+    }
+
+    #[cfg(feature = "std")]
+    fn calculate_from_file<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<W> where Self: Sized {
+        crate::check8file::for_each_chunk(path, |chunk| {
+            self.calculate_from_byte_array(chunk);
+            Ok(())
+        })?;
+        Ok(self.get_checksum())
+    }
+
+    #[cfg(feature = "std")]
+    fn calculate_from_file_with_progress<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+        progress: impl FnMut(u64),
+        cancel: &core::sync::atomic::AtomicBool,
+    ) -> std::io::Result<W> where Self: Sized {
+        let add = |chunk: &[u8]| {
+            self.calculate_from_byte_array(chunk);
+        };
+        crate::check8file::for_each_chunk(path, crate::check8io::with_progress(add, progress, cancel))?;
+        Ok(self.get_checksum())
+    }
+
+    fn add_u16_le(&mut self, val: u16) -> W {
+        self.calculate_from_byte_array(&val.to_le_bytes())
+    }
+
+    fn add_u16_be(&mut self, val: u16) -> W {
+        self.calculate_from_byte_array(&val.to_be_bytes())
+    }
+
+    fn add_u32_le(&mut self, val: u32) -> W {
+        self.calculate_from_byte_array(&val.to_le_bytes())
+    }
+
+    fn add_u32_be(&mut self, val: u32) -> W {
+        self.calculate_from_byte_array(&val.to_be_bytes())
+    }
+
+    fn add_u64_le(&mut self, val: u64) -> W {
+        self.calculate_from_byte_array(&val.to_le_bytes())
+    }
+
+    fn add_u64_be(&mut self, val: u64) -> W {
+        self.calculate_from_byte_array(&val.to_be_bytes())
+    }
+}
+
+// Invokes a macro once for each checksum type, so the modules implementing a foreign trait
+// for all of them share one list. Each invocation is given the generic parameters of the
+// impl in brackets, the type and the width of its checksum, e.g.
+// `impl_foo!([const POLY: u8] crate::Check8CrcConst<POLY>, u8);`.
+macro_rules! for_each_checksum_type {
+    ($m:ident) => {
+        #[cfg(feature = "sum")]
+        $m!([] crate::Check8Sum, u8);
+        #[cfg(feature = "xor")]
+        $m!([] crate::Check8Xor, u8);
+        $m!([] crate::Check8XorRotate, u8);
+        #[cfg(feature = "crc")]
+        $m!([] crate::Check8Crc, u8);
+        #[cfg(feature = "crc")]
+        $m!([] crate::Check8CrcNibble, u8);
+        #[cfg(feature = "crc")]
+        $m!([] crate::Check8CrcBitwise, u8);
+        #[cfg(feature = "crc")]
+        $m!([] crate::Check8CrcSlice8, u8);
+        #[cfg(feature = "crc")]
+        $m!([const POLY: u8] crate::Check8CrcConst<POLY>, u8);
+        $m!([] crate::Check8Lrc, u8);
+        $m!([] crate::Check8Bsd, u8);
+        $m!([] crate::Check8Parity, u8);
+        $m!([] crate::Check8OnesComplement, u8);
+        $m!([] crate::Check8Fletcher, u8);
+        $m!([] crate::Check8Pearson, u8);
+        $m!([] crate::Check7Roland, u8);
+        #[cfg(feature = "crc")]
+        $m!([] crate::check16::Check16Crc, u16);
+        $m!([] crate::check16::Check16Fletcher, u16);
+        #[cfg(feature = "crc")]
+        $m!([] crate::check32::Check32Crc, u32);
+    };
+}
+
+pub(crate) use for_each_checksum_type;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8, Check8Lrc};
+    #[cfg(feature = "crc")]
+    use crate::Check8Crc;
+    #[cfg(feature = "sum")]
+    use crate::Check8Sum;
+    #[cfg(feature = "xor")]
+    use crate::Check8Xor;
+
+    // a 16-bit arithmetic sum, implementing Checksum directly
+    struct Sum16 {
+        accum: u16,
+    }
+
+    impl Checksum<u16> for Sum16 {
+        fn get_accum(&self) -> u16 {
+            self.accum
+        }
+
+        fn init(&mut self, val: u16) -> u16 {
+            self.accum = val;
+            self.accum
+        }
+
+        fn add(&mut self, val: u8) -> u16 {
+            self.accum = self.accum.wrapping_add(val as u16);
+            self.accum
+        }
+    }
+
+    fn checksum_of<W: Width>(checksum: &mut impl Checksum<W>, data: &[u8]) -> W {
+        checksum.calculate_from_slices(&[&data[..3], &data[3..]])
+    }
+
+    #[test]
+    fn check8_types_are_8_bit_checksums() {
+        let data = b"123456789";
+        #[cfg(feature = "sum")]
+        assert_eq!(checksum_of(&mut Check8Sum::new(0), data), 0xDD);
+        #[cfg(feature = "xor")]
+        assert_eq!(checksum_of(&mut Check8Xor::new(0), data), 0x31);
+        #[cfg(feature = "crc")]
+        assert_eq!(checksum_of(&mut Check8Crc::new(0x07), data), 0xF4);
+        // Check8Lrc finalises in get_checksum, the two's complement of the sum
+        assert_eq!(checksum_of(&mut Check8Lrc::new(0), data), 0x23)
+    }
+
+    #[test]
+    fn wider_checksum_shares_the_provided_methods() {
+        let mut sum = Sum16 { accum: 0 };
+        assert_eq!(checksum_of(&mut sum, &[0xFF; 9]), 9 * 0xFF);
+        assert_eq!(sum.init(0), 0);
+        assert_eq!(sum.add_u16_be(0x0102), 0x0003);
+        assert_eq!(sum.calculate_from_string("A"), 0x0044)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calculate_from_reader_at_any_width() {
+        let mut sum = Sum16 { accum: 0 };
+        assert_eq!(sum.calculate_from_reader(&[0xFFu8; 10000][..]).unwrap(), (10000u32 * 0xFF) as u16);
+        assert_eq!(Checksum::calculate_from_reader(&mut Check8Lrc::new(0), &b"123456789"[..]).unwrap(), 0x23)
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn calculate_from_buf_at_any_width() {
+        use bytes::Buf;
+        let mut sum = Sum16 { accum: 0 };
+        let mut chain = (&[0xFFu8; 3][..]).chain(&[0x01u8][..]);
+        assert_eq!(sum.calculate_from_buf(&mut chain), 3 * 0xFF + 1)
+    }
+
+    #[test]
+    fn width_conversions() {
+        assert_eq!(<u8 as Width>::BITS, 8);
+        assert_eq!(<u16 as Width>::BITS, 16);
+        assert_eq!(<u32 as Width>::BITS, 32);
+        assert_eq!(u16::from_u64(0x12345), 0x2345);
+        assert_eq!(0xABu8.to_u64(), 0xAB)
+    }
+}
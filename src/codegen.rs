@@ -0,0 +1,198 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Codegen
+//!
+//! Emits the lookup table for a set of CRC parameters as source code, a Rust constant or a C
+//! array, so that firmware in C and host tools in Rust can share one source of truth for the
+//! table. The functions are plain library code, so they can be called from a build script
+//! with check8 as a build dependency, writing the table into OUT_DIR for include!.
+//!
+//! The emitted table is the one Check8Crc::generate_table_with_params gives, in the engine's
+//! form: indexed by the register XOR the input byte, with the register left-aligned for
+//! unreflected CRCs narrower than 8 bits. The parameters are written in a comment above it.
+//!
+//! # Examples
+//!
+//! A build script generating a table for the crate being built, with the alloc feature:
+//!
+#![cfg_attr(feature = "alloc", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+//! use check8::Check8CrcParams;
+//! use check8::codegen::rust_table;
+//! fn main() {
+//!     let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+//!     let out_dir = std::env::var("OUT_DIR").unwrap();
+//!     let source = rust_table("CRC8_MAXIM_TABLE", params);
+//!     std::fs::write(std::path::Path::new(&out_dir).join("crc_table.rs"), source).unwrap();
+//! }
+//! ```
+//!
+//! and in the crate, `include!(concat!(env!("OUT_DIR"), "/crc_table.rs"));` followed by
+//! `Check8Crc::with_table(&CRC8_MAXIM_TABLE, params)`.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{Check8Crc, Check8CrcParams};
+
+// the number of table entries on each line of the emitted source
+const ENTRIES_PER_LINE: usize = 16;
+
+/// Writes the lookup table for a set of CRC parameters as a Rust constant,
+/// `pub const NAME: [u8; 256] = [...];`.
+///
+/// - **Parameter**: out - Where to write the source.
+/// - **Parameter**: name - The name of the constant.
+/// - **Parameter**: params - The CRC parameters to generate the table for.
+/// - **Returns**: Any error from the destination.
+pub fn write_rust_table(out: &mut impl fmt::Write, name: &str, params: Check8CrcParams) -> fmt::Result {
+    write_params_comment(out, "//", "", params)?;
+    writeln!(out, "pub const {}: [u8; 256] = [", name)?;
+    write_entries(out, params)?;
+    writeln!(out, "];")
+}
+
+/// Writes the lookup table for a set of CRC parameters as a C array,
+/// `static const uint8_t name[256] = {...};`, which needs stdint.h.
+///
+/// - **Parameter**: out - Where to write the source.
+/// - **Parameter**: name - The name of the array.
+/// - **Parameter**: params - The CRC parameters to generate the table for.
+/// - **Returns**: Any error from the destination.
+pub fn write_c_table(out: &mut impl fmt::Write, name: &str, params: Check8CrcParams) -> fmt::Result {
+    write_params_comment(out, "/*", " */", params)?;
+    writeln!(out, "static const uint8_t {}[256] = {{", name)?;
+    write_entries(out, params)?;
+    writeln!(out, "}};")
+}
+
+/// Returns the lookup table for a set of CRC parameters as a Rust constant, see write_rust_table.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::Check8CrcParams;
+/// use crate::check8::codegen::rust_table;
+/// fn main() {
+///     let source = rust_table("SMBUS_TABLE", Check8CrcParams::new(0x07));
+///     assert!(source.contains("pub const SMBUS_TABLE: [u8; 256] = ["));
+///     assert!(source.contains("    0x00, 0x07, 0x0E, 0x09,"));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn rust_table(name: &str, params: Check8CrcParams) -> String {
+    let mut out = String::new();
+    // writing to a String cannot fail
+    let _ = write_rust_table(&mut out, name, params);
+    out
+}
+
+/// Returns the lookup table for a set of CRC parameters as a C array, see write_c_table.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::Check8CrcParams;
+/// use crate::check8::codegen::c_table;
+/// fn main() {
+///     let source = c_table("smbus_table", Check8CrcParams::new(0x07));
+///     assert!(source.contains("static const uint8_t smbus_table[256] = {"));
+///     assert!(source.ends_with("};\n"));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn c_table(name: &str, params: Check8CrcParams) -> String {
+    let mut out = String::new();
+    // writing to a String cannot fail
+    let _ = write_c_table(&mut out, name, params);
+    out
+}
+
+// the parameters, as a comment in the emitted language
+fn write_params_comment(out: &mut impl fmt::Write, open: &str, close: &str, params: Check8CrcParams) -> fmt::Result {
+    writeln!(
+        out,
+        "{} CRC lookup table: width {}, poly 0x{:02X}, init 0x{:02X}, refin {}, refout {}, xorout 0x{:02X}{}",
+        open, params.width, params.poly, params.init, params.refin, params.refout, params.xorout, close
+    )
+}
+
+// the 256 entries, indented, as hexadecimal literals that read the same in Rust and C
+fn write_entries(out: &mut impl fmt::Write, params: Check8CrcParams) -> fmt::Result {
+    let table = Check8Crc::generate_table_with_params(params);
+    for line in table.chunks(ENTRIES_PER_LINE) {
+        write!(out, "   ")?;
+        for entry in line {
+            write!(out, " 0x{:02X},", entry)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    // the entries of the emitted source, in order
+    fn parse_entries(source: &str) -> Vec<u8> {
+        source
+            .lines()
+            .filter(|line| line.starts_with("    0x"))
+            .flat_map(|line| line.split(',').filter_map(|entry| entry.trim().strip_prefix("0x")))
+            .map(|hex| u8::from_str_radix(hex, 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rust_table_round_trips() {
+        let params = crate::presets::CRC8_MAXIM.params;
+        let source = rust_table("MAXIM", params);
+        assert!(source.starts_with("// CRC lookup table: width 8, poly 0x31, init 0x00, refin true, refout true, xorout 0x00\n"));
+        assert!(source.ends_with("];\n"));
+        assert_eq!(parse_entries(&source), Check8Crc::generate_table_with_params(params))
+    }
+
+    #[test]
+    fn c_table_round_trips() {
+        let params = crate::presets::CRC5_USB.params;
+        let source = c_table("usb", params);
+        assert!(source.starts_with("/* CRC lookup table: width 5, poly 0x05, init 0x1F, refin true, refout true, xorout 0x1F */\n"));
+        assert!(source.contains("static const uint8_t usb[256] = {\n"));
+        assert_eq!(parse_entries(&source), Check8Crc::generate_table_with_params(params))
+    }
+
+    #[test]
+    fn sixteen_entries_per_line() {
+        let source = rust_table("T", Check8CrcParams::new(0x07));
+        assert_eq!(source.lines().count(), 2 + 256 / 16 + 1);
+        assert!(source.lines().filter(|line| line.starts_with("    0x")).all(|line| line.matches("0x").count() == 16))
+    }
+}
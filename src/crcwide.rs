@@ -0,0 +1,352 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # CRC model for wider CRCs
+//!
+//! The Rocksoft model of Check8Crc, held in u64 so that the CRC types of the check16 and
+//! check32 modules share one implementation of the table generation, reflection and
+//! finalisation. As in Check8Crc, the engine register is left-aligned in the type's
+//! register for unreflected algorithms, and right-aligned and reflected otherwise.
+
+/// The parameters of a CRC of up to 32 bits, in a register of `bits` bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CrcModel {
+    pub bits: u32,
+    pub width: u32,
+    pub poly: u64,
+    pub init: u64,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u64,
+}
+
+impl CrcModel {
+    // The number of unused bits below a left-aligned register.
+    const fn shift(&self) -> u32 {
+        assert!(self.width >= 1 && self.width <= self.bits, "CRC width must be 1 bit to the register width");
+        self.bits - self.width
+    }
+
+    const fn mask(&self) -> u64 {
+        mask(self.width)
+    }
+
+    const fn register_mask(&self) -> u64 {
+        mask(self.bits)
+    }
+
+    // The polynomial as the table and bitwise engines use it.
+    pub(crate) const fn engine_poly(&self) -> u64 {
+        if self.refin {
+            reflect(self.poly & self.mask(), self.width)
+        } else {
+            (self.poly & self.mask()) << self.shift()
+        }
+    }
+
+    // Converts a right-aligned register value to the engine's internal form.
+    pub(crate) const fn encode_register(&self, val: u64) -> u64 {
+        if self.refin {
+            val & self.mask()
+        } else {
+            (val & self.mask()) << self.shift()
+        }
+    }
+
+    // Converts the engine's internal register to a right-aligned value.
+    pub(crate) const fn decode_register(&self, reg: u64) -> u64 {
+        if self.refin {
+            reg
+        } else {
+            reg >> self.shift()
+        }
+    }
+
+    // The internal register the engine starts from.
+    pub(crate) const fn init_register(&self) -> u64 {
+        if self.refin {
+            reflect(self.init & self.mask(), self.width)
+        } else {
+            self.encode_register(self.init)
+        }
+    }
+
+    // Applies output reflection and the final XOR to the internal register.
+    pub(crate) const fn finalise(&self, reg: u64) -> u64 {
+        let val = self.decode_register(reg);
+        let val = if self.refin != self.refout { reflect(val, self.width) } else { val };
+        (val ^ self.xorout) & self.mask()
+    }
+
+    // Adds a byte to the internal register one bit at a time, without a table.
+    pub(crate) const fn add_bitwise(&self, reg: u64, val: u8) -> u64 {
+        let poly = self.engine_poly();
+        let top = 1u64 << (self.bits - 1);
+        let mut reg = if self.refin { reg ^ val as u64 } else { reg ^ ((val as u64) << (self.bits - 8)) };
+        let mut bit = 0;
+        while bit < 8 {
+            reg = if self.refin {
+                if (reg & 0x01) != 0 { (reg >> 1) ^ poly } else { reg >> 1 }
+            } else if (reg & top) != 0 {
+                ((reg << 1) ^ poly) & self.register_mask()
+            } else {
+                (reg << 1) & self.register_mask()
+            };
+            bit += 1;
+        }
+        reg
+    }
+
+    // The table entry for an index, the register after adding the index byte to zero.
+    pub(crate) const fn table_entry(&self, index: u8) -> u64 {
+        self.add_bitwise(0, index)
+    }
+}
+
+const fn mask(bits: u32) -> u64 {
+    if bits >= 64 { u64::MAX } else { (1u64 << bits) - 1 }
+}
+
+/// Reverses the order of the low width bits of a value.
+pub(crate) const fn reflect(val: u64, width: u32) -> u64 {
+    val.reverse_bits() >> (64 - width)
+}
+
+// Defines a CRC type of up to $bits bits over the CrcModel, with its parameters type, its
+// serialised form and its Checksum implementation, so the check16 and check32 types share one
+// definition. Doc comments given before the type are put on its impl block, for the examples.
+// The invoking module needs the Checksum trait, CrcModel and Check8Error in scope.
+macro_rules! wide_crc {
+    ($(#[$doc:meta])* $t:ident($params:ident, $w:ty, $bits:literal, $name:literal)) => {
+        #[derive(Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(try_from = "CrcRepr", into = "CrcRepr"))]
+        pub struct $t {
+            params: $params,
+            accum: $w,
+            table: [$w; 256],
+        }
+
+        #[doc = concat!("The parameters that determine how a CRC of up to ", $bits, " bits is calculated, following the")]
+        /// Rocksoft model, as for Check8CrcParams.
+        ///
+        #[doc = concat!("- width: The number of bits in the CRC, 1 to ", $bits, ".")]
+        /// - poly: The polynomial, right-aligned and without the implicit top bit, in normal (unreflected) form.
+        /// - init: The initial register value, in normal (unreflected) form.
+        /// - refin: Whether each input byte is processed least significant bit first.
+        /// - refout: Whether the final register is reflected before the final XOR.
+        /// - xorout: The value XORed with the register to give the checksum.
+        ///
+        #[doc = concat!(stringify!($params), "::new gives the plain CRC-", $bits, " for a polynomial (width ", $bits, ", init 0, no")]
+        /// reflection, no final XOR), the other fields can be set with struct update syntax.
+        ///
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $params {
+            pub width: u8,
+            pub poly: $w,
+            pub init: $w,
+            pub refin: bool,
+            pub refout: bool,
+            pub xorout: $w,
+        }
+
+        // The serialised form of the CRC type: the table is derived from the parameters, so it
+        // is regenerated on deserialisation rather than stored.
+        #[cfg(feature = "serde")]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(rename = $name)]
+        struct CrcRepr {
+            params: $params,
+            accum: $w,
+        }
+
+        #[cfg(feature = "serde")]
+        impl TryFrom<CrcRepr> for $t {
+            type Error = Check8Error;
+
+            fn try_from(repr: CrcRepr) -> Result<Self, Check8Error> {
+                let mut crc = $t::try_with_params(repr.params)?;
+                crc.init(repr.accum);
+                Ok(crc)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<$t> for CrcRepr {
+            fn from(crc: $t) -> Self {
+                CrcRepr { params: crc.params, accum: crc.get_accum() }
+            }
+        }
+
+        impl $params {
+            #[doc = concat!("The plain CRC-", $bits, " for a polynomial: width ", $bits, ", init 0, no reflection and no final XOR.")]
+            pub const fn new(poly: $w) -> Self {
+                $params { width: $bits, poly, init: 0, refin: false, refout: false, xorout: 0 }
+            }
+
+            #[doc = concat!("Checks that the width is 1 to ", $bits, " bits.")]
+            pub const fn validate(&self) -> Result<(), Check8Error> {
+                if self.width >= 1 && self.width <= $bits { Ok(()) } else { Err(Check8Error::InvalidWidth(self.width)) }
+            }
+
+            pub(crate) const fn model(&self) -> CrcModel {
+                CrcModel {
+                    bits: $bits,
+                    width: self.width as u32,
+                    poly: self.poly as u64,
+                    init: self.init as u64,
+                    refin: self.refin,
+                    refout: self.refout,
+                    xorout: self.xorout as u64,
+                }
+            }
+        }
+
+        /// # Provided Methods
+        ///
+        /// - new: Creates a new instance of the type, generates the CRC lookup table from the provided polynomial.
+        #[doc = concat!("- with_params: Creates a new instance for any CRC of up to ", $bits, " bits, described by a ", stringify!($params), ".")]
+        /// - try_with_params: As with_params, but returns an error for an invalid width rather than panicking.
+        #[doc = concat!("- get_accum: Retrieves the current value of the accumulator, right-aligned for widths below ", $bits, ".")]
+        /// - get_checksum: Retrieves the checksum, after output reflection and the final XOR.
+        /// - get_params: Retrieves the parameters the instance was created with.
+        /// - generate_table_with_params: Generates the lookup table for a set of parameters, a const fn so it can be used at compile time.
+        /// - init: Initialises the accumulator with a given value and returns the initialised value.
+        /// - reset: Initialises the accumulator with the initial value from the parameters.
+        /// - add: Adds a given value to the accumulator using the CRC algorithm and returns the updated value.
+        ///
+        /// For reflected algorithms the accumulator holds the register in reflected bit order, as
+        /// for Check8Crc.
+        ///
+        $(#[$doc])*
+        impl $t {
+            #[doc = concat!("Creates a new instance for the plain CRC-", $bits, " of a polynomial.")]
+            pub fn new(poly: $w) -> Self {
+                Self::with_params($params::new(poly))
+            }
+
+            /// Creates a new instance from the full set of CRC parameters, or returns
+            #[doc = concat!("Check8Error::InvalidWidth if the width is not 1 to ", $bits, " bits.")]
+            pub fn try_with_params(params: $params) -> Result<Self, Check8Error> {
+                params.validate()?;
+                Ok(Self::with_params(params))
+            }
+
+            /// Creates a new instance from the full set of CRC parameters.
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the width is not 1 to ", $bits, " bits.")]
+            pub fn with_params(params: $params) -> Self {
+                $t {
+                    params,
+                    accum: params.model().init_register() as $w,
+                    table: Self::generate_table_with_params(params),
+                }
+            }
+
+            pub fn get_params(&self) -> $params {
+                self.params
+            }
+
+            pub fn reset(&mut self) -> $w {
+                self.accum = self.params.model().init_register() as $w;
+                self.get_accum()
+            }
+
+            /// Generates the 256-entry lookup table for a set of CRC parameters, usable at compile time.
+            pub const fn generate_table_with_params(params: $params) -> [$w; 256] {
+                let model = params.model();
+                let mut table = [0; 256];
+                let mut i = 0;
+                while i < 256 {
+                    table[i] = model.table_entry(i as u8) as $w;
+                    i += 1;
+                }
+                table
+            }
+        }
+
+        impl Checksum<$w> for $t {
+            fn get_accum(&self) -> $w {
+                self.params.model().decode_register(self.accum as u64) as $w
+            }
+
+            fn get_checksum(&self) -> $w {
+                self.params.model().finalise(self.accum as u64) as $w
+            }
+
+            fn init(&mut self, val: $w) -> $w {
+                self.accum = self.params.model().encode_register(val as u64) as $w;
+                self.get_accum()
+            }
+
+            fn add(&mut self, val: u8) -> $w {
+                self.accum = if self.params.refin {
+                    (self.accum >> 8) ^ self.table[(self.accum as u8 ^ val) as usize]
+                } else {
+                    // the top byte of the left-aligned register
+                    (self.accum << 8) ^ self.table[((self.accum >> ($bits - 8)) as u8 ^ val) as usize]
+                };
+                self.get_accum()
+            }
+        }
+    };
+}
+
+pub(crate) use wide_crc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc(model: &CrcModel, data: &[u8]) -> u64 {
+        let mut reg = model.init_register();
+        for &byte in data {
+            reg = model.add_bitwise(reg, byte);
+        }
+        model.finalise(reg)
+    }
+
+    #[test]
+    fn catalogue_check_values() {
+        let xmodem = CrcModel { bits: 16, width: 16, poly: 0x1021, init: 0, refin: false, refout: false, xorout: 0 };
+        assert_eq!(crc(&xmodem, b"123456789"), 0x31C3);
+        let modbus = CrcModel { bits: 16, width: 16, poly: 0x8005, init: 0xFFFF, refin: true, refout: true, xorout: 0 };
+        assert_eq!(crc(&modbus, b"123456789"), 0x4B37);
+        let crc12 = CrcModel { bits: 16, width: 12, poly: 0x80F, init: 0, refin: false, refout: true, xorout: 0 };
+        assert_eq!(crc(&crc12, b"123456789"), 0xDAF)
+    }
+
+    #[test]
+    fn reflect_reverses_the_low_bits() {
+        assert_eq!(reflect(0x0001, 16), 0x8000);
+        assert_eq!(reflect(0x1021, 16), 0x8408);
+        assert_eq!(reflect(0x04C11DB7, 32), 0xEDB88320)
+    }
+}
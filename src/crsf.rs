@@ -0,0 +1,190 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # CRSF
+//!
+//! Helpers for the frame check of the Crossfire (CRSF) RC link protocol, also used by
+//! ExpressLRS. A frame is a device address (or sync byte), a length, a frame type, the
+//! payload and a CRC-8/DVB-S2 over the type and payload. The length counts the type,
+//! payload and CRC bytes.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Checksum;        // for the Checksum trait
+use crate::presets::crc8_dvb_s2;
+
+/// The sync byte, which is also the address of the flight controller.
+pub const CRSF_SYNC: u8 = 0xC8;
+
+/// The longest frame, including the address and length bytes.
+pub const CRSF_MAX_FRAME_LEN: usize = 64;
+
+/// # Variants
+///
+/// - TooShort: The frame is shorter than its address, length, type and CRC bytes.
+/// - TooLong: The payload does not fit in a frame.
+/// - LengthMismatch: The length byte does not match the number of bytes in the frame.
+/// - BadCrc: The CRC byte does not match the CRC of the type and payload.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CrsfError {
+    TooShort,
+    TooLong,
+    LengthMismatch,
+    BadCrc,
+}
+
+impl fmt::Display for CrsfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrsfError::TooShort => write!(f, "CRSF frame is too short"),
+            CrsfError::TooLong => write!(f, "CRSF frame is too long"),
+            CrsfError::LengthMismatch => write!(f, "CRSF length byte does not match the frame"),
+            CrsfError::BadCrc => write!(f, "CRSF frame CRC does not match"),
+        }
+    }
+}
+
+impl core::error::Error for CrsfError {}
+
+/// Calculates the CRC of a CRSF frame from its type and payload.
+///
+/// - **Parameter**: frame_type - The frame type, e.g. 0x16 for RC channels.
+/// - **Parameter**: payload - The payload bytes.
+/// - **Returns**: The CRC byte which ends the frame.
+pub fn frame_crc(frame_type: u8, payload: &[u8]) -> u8 {
+    let mut crc = crc8_dvb_s2();
+    crc.add(frame_type);
+    crc.calculate_from_byte_array(payload)
+}
+
+/// Builds a complete CRSF frame.
+///
+/// - **Parameter**: address - The destination address, or CRSF_SYNC.
+/// - **Parameter**: frame_type - The frame type.
+/// - **Parameter**: payload - The payload bytes.
+/// - **Returns**: The frame, or CrsfError::TooLong if the payload does not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::crsf::{build_frame, check_frame, CRSF_SYNC};
+/// fn main() {
+///     // battery sensor: 12.6 V, 1.5 A, 1000 mAh used, 80% remaining
+///     let frame = build_frame(CRSF_SYNC, 0x08, &[0x00, 0x7E, 0x00, 0x0F, 0x00, 0x03, 0xE8, 0x50]).unwrap();
+///     assert_eq!(frame[1], 10);
+///     assert_eq!(check_frame(&frame), Ok(()));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn build_frame(address: u8, frame_type: u8, payload: &[u8]) -> Result<Vec<u8>, CrsfError> {
+    if payload.len() + 4 > CRSF_MAX_FRAME_LEN {
+        return Err(CrsfError::TooLong);
+    }
+    let mut frame = Vec::with_capacity(payload.len() + 4);
+    frame.push(address);
+    frame.push((payload.len() + 2) as u8);
+    frame.push(frame_type);
+    frame.extend_from_slice(payload);
+    frame.push(frame_crc(frame_type, payload));
+    Ok(frame)
+}
+
+/// Checks the length byte and CRC of a complete CRSF frame.
+///
+/// - **Parameter**: frame - The frame, from the address byte to the CRC byte.
+/// - **Returns**: Ok if the frame is intact, or a CrsfError describing the fault.
+pub fn check_frame(frame: &[u8]) -> Result<(), CrsfError> {
+    let [_, length, frame_type, payload @ .., crc] = frame else {
+        return Err(CrsfError::TooShort);
+    };
+    if frame.len() > CRSF_MAX_FRAME_LEN {
+        return Err(CrsfError::TooLong);
+    }
+    if *length as usize != frame.len() - 2 {
+        return Err(CrsfError::LengthMismatch);
+    }
+    if frame_crc(*frame_type, payload) != *crc {
+        return Err(CrsfError::BadCrc);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_crc_covers_type_and_payload() {
+        let mut crc = crc8_dvb_s2();
+        assert_eq!(frame_crc(0x14, &[0x01, 0x02, 0x03]), crc.calculate_from_byte_array(&[0x14, 0x01, 0x02, 0x03]))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn built_frames_check() {
+        let frame = build_frame(CRSF_SYNC, 0x16, &[0u8; 22]).unwrap();
+        assert_eq!(frame.len(), 26);
+        assert_eq!(frame[..3], [0xC8, 24, 0x16]);
+        assert_eq!(check_frame(&frame), Ok(()))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn corrupted_frames_are_rejected() {
+        let frame = build_frame(CRSF_SYNC, 0x08, &[0x00, 0x7E, 0x00, 0x0F]).unwrap();
+
+        let mut corrupted = frame.clone();
+        corrupted[4] ^= 0x10;
+        assert_eq!(check_frame(&corrupted), Err(CrsfError::BadCrc));
+
+        let mut corrupted = frame.clone();
+        corrupted[1] += 1;
+        assert_eq!(check_frame(&corrupted), Err(CrsfError::LengthMismatch));
+
+        assert_eq!(check_frame(&frame[..3]), Err(CrsfError::TooShort))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn address_is_not_covered() {
+        let mut frame = build_frame(CRSF_SYNC, 0x08, &[0x01]).unwrap();
+        frame[0] = 0xEA;
+        assert_eq!(check_frame(&frame), Ok(()))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn payload_limit() {
+        assert!(build_frame(CRSF_SYNC, 0x7F, &[0u8; 60]).is_ok());
+        assert_eq!(build_frame(CRSF_SYNC, 0x7F, &[0u8; 61]), Err(CrsfError::TooLong))
+    }
+}
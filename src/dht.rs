@@ -0,0 +1,155 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # DHT
+//!
+//! Helpers for the checksum of the 5-byte payload of DHT11 and DHT22 (AM2302) humidity and
+//! temperature sensors: two bytes of humidity, two bytes of temperature, and a checksum, the
+//! low 8 bits of the sum of the first four bytes, as computed by Check8Sum.
+
+use core::fmt;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Sum;
+
+/// # Variants
+///
+/// - BadChecksum: The checksum byte does not match the first four bytes.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DhtError {
+    BadChecksum,
+}
+
+impl fmt::Display for DhtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DhtError::BadChecksum => write!(f, "DHT payload checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for DhtError {}
+
+/// The raw fields of a valid payload, each the first byte of the pair in the upper 8 bits.
+///
+/// The interpretation depends on the sensor: the DHT11 sends integral and decimal parts, and
+/// the DHT22 sends tenths, with the top bit of the temperature as its sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DhtReading {
+    pub humidity: u16,
+    pub temperature: u16,
+}
+
+impl DhtReading {
+    /// The DHT22 relative humidity, in tenths of a percent.
+    pub fn dht22_humidity(&self) -> u16 {
+        self.humidity
+    }
+
+    /// The DHT22 temperature, in tenths of a degree Celsius.
+    pub fn dht22_temperature(&self) -> i16 {
+        let magnitude = (self.temperature & 0x7FFF) as i16;
+        if self.temperature & 0x8000 != 0 { -magnitude } else { magnitude }
+    }
+
+    /// The DHT11 relative humidity, in whole percent.
+    pub fn dht11_humidity(&self) -> u8 {
+        (self.humidity >> 8) as u8
+    }
+
+    /// The DHT11 temperature, in whole degrees Celsius.
+    pub fn dht11_temperature(&self) -> u8 {
+        (self.temperature >> 8) as u8
+    }
+}
+
+/// Calculates the checksum byte of a payload from its first four bytes.
+pub fn payload_checksum(payload: &[u8; 5]) -> u8 {
+    Check8Sum::new(0x00).calculate_from_byte_array(&payload[..4])
+}
+
+/// Validates a payload and extracts its raw fields.
+///
+/// - **Parameter**: payload - The 5 bytes read from the sensor.
+/// - **Returns**: The raw fields, or DhtError::BadChecksum.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::dht::parse_payload;
+/// fn main() {
+///     // DHT22: 65.2 %RH, -10.1 C
+///     let reading = parse_payload(&[0x02, 0x8C, 0x80, 0x65, 0x73]).unwrap();
+///     assert_eq!(reading.dht22_humidity(), 652);
+///     assert_eq!(reading.dht22_temperature(), -101);
+/// }
+/// ```
+///
+pub fn parse_payload(payload: &[u8; 5]) -> Result<DhtReading, DhtError> {
+    if payload_checksum(payload) != payload[4] {
+        return Err(DhtError::BadChecksum);
+    }
+    Ok(DhtReading {
+        humidity: u16::from_be_bytes([payload[0], payload[1]]),
+        temperature: u16::from_be_bytes([payload[2], payload[3]]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dht11_payload() {
+        // 40 %RH, 24 C
+        let reading = parse_payload(&[0x28, 0x00, 0x18, 0x00, 0x40]).unwrap();
+        assert_eq!(reading.dht11_humidity(), 40);
+        assert_eq!(reading.dht11_temperature(), 24)
+    }
+
+    #[test]
+    fn dht22_payload() {
+        // 65.2 %RH, 35.1 C, from the AM2302 datasheet
+        let reading = parse_payload(&[0x02, 0x8C, 0x01, 0x5F, 0xEE]).unwrap();
+        assert_eq!(reading.dht22_humidity(), 652);
+        assert_eq!(reading.dht22_temperature(), 351)
+    }
+
+    #[test]
+    fn checksum_wraps() {
+        let payload = [0xFF, 0xFF, 0x01, 0x00, 0xFF];
+        assert_eq!(payload_checksum(&payload), 0xFF);
+        assert!(parse_payload(&payload).is_ok())
+    }
+
+    #[test]
+    fn bad_checksum_is_rejected() {
+        assert_eq!(parse_payload(&[0x02, 0x8C, 0x01, 0x5F, 0xEF]), Err(DhtError::BadChecksum))
+    }
+}
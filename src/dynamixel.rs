@@ -0,0 +1,189 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Dynamixel
+//!
+//! Helpers for the checksum of Dynamixel Protocol 1.0 packets. A packet is the header 0xFF
+//! 0xFF, the servo ID, a length (the number of parameters plus two), the instruction (or the
+//! error byte of a status packet), the parameters and a checksum: the inverse of the low byte
+//! of the sum of the ID, length, instruction and parameters, as computed by Check8Sum with a
+//! final XOR of 0xFF.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Sum, Finalise};
+
+/// The two bytes which start every packet.
+pub const DYNAMIXEL_HEADER: [u8; 2] = [0xFF, 0xFF];
+
+/// # Variants
+///
+/// - TooShort: The packet is shorter than its header, ID, length, instruction and checksum.
+/// - BadHeader: The packet does not start with 0xFF 0xFF.
+/// - LengthMismatch: The length byte does not match the number of bytes in the packet.
+/// - BadChecksum: The checksum does not match the packet.
+/// - TooLong: There are more than 253 parameters, so the length does not fit its byte.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DynamixelError {
+    TooShort,
+    BadHeader,
+    LengthMismatch,
+    BadChecksum,
+    TooLong,
+}
+
+impl fmt::Display for DynamixelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamixelError::TooShort => write!(f, "Dynamixel packet is too short"),
+            DynamixelError::BadHeader => write!(f, "Dynamixel packet does not start with 0xFF 0xFF"),
+            DynamixelError::LengthMismatch => write!(f, "Dynamixel length does not match the packet"),
+            DynamixelError::BadChecksum => write!(f, "Dynamixel packet checksum does not match"),
+            DynamixelError::TooLong => write!(f, "Dynamixel packet has more than 253 parameters"),
+        }
+    }
+}
+
+impl core::error::Error for DynamixelError {}
+
+/// Calculates the checksum of a packet.
+///
+/// - **Parameter**: id - The servo ID, or 0xFE to broadcast.
+/// - **Parameter**: instruction - The instruction, or the error byte of a status packet.
+/// - **Parameter**: params - The parameters, at most 253.
+/// - **Returns**: The checksum byte, or DynamixelError::TooLong.
+pub fn checksum(id: u8, instruction: u8, params: &[u8]) -> Result<u8, DynamixelError> {
+    let length = packet_length(params)?;
+    let mut sum = Check8Sum::with_finalise(0x00, Finalise::XorOut(0xFF));
+    sum.add(id);
+    sum.add(length);
+    sum.add(instruction);
+    Ok(sum.calculate_from_byte_array(params))
+}
+
+// the length byte: the number of parameters plus the instruction and checksum
+fn packet_length(params: &[u8]) -> Result<u8, DynamixelError> {
+    u8::try_from(params.len() + 2).map_err(|_| DynamixelError::TooLong)
+}
+
+/// Builds a complete packet.
+///
+/// - **Parameter**: id - The servo ID, or 0xFE to broadcast.
+/// - **Parameter**: instruction - The instruction, or the error byte of a status packet.
+/// - **Parameter**: params - The parameters, at most 253.
+/// - **Returns**: The packet, from the header to the checksum, or DynamixelError::TooLong.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::dynamixel::{build_packet, verify_packet};
+/// fn main() {
+///     // PING servo 1
+///     let packet = build_packet(0x01, 0x01, &[]).unwrap();
+///     assert_eq!(packet, vec![0xFF, 0xFF, 0x01, 0x02, 0x01, 0xFB]);
+///     assert_eq!(verify_packet(&packet), Ok(()));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn build_packet(id: u8, instruction: u8, params: &[u8]) -> Result<Vec<u8>, DynamixelError> {
+    let length = packet_length(params)?;
+    let mut packet = Vec::with_capacity(params.len() + 6);
+    packet.extend_from_slice(&DYNAMIXEL_HEADER);
+    packet.extend_from_slice(&[id, length, instruction]);
+    packet.extend_from_slice(params);
+    packet.push(checksum(id, instruction, params)?);
+    Ok(packet)
+}
+
+/// Verifies the header, length and checksum of an instruction or status packet.
+///
+/// - **Parameter**: packet - The packet, from the header to the checksum.
+/// - **Returns**: Ok if the packet is intact, or a DynamixelError describing the fault.
+pub fn verify_packet(packet: &[u8]) -> Result<(), DynamixelError> {
+    let [first, second, id, length, instruction, params @ .., received] = packet else {
+        return Err(DynamixelError::TooShort);
+    };
+    if [*first, *second] != DYNAMIXEL_HEADER {
+        return Err(DynamixelError::BadHeader);
+    }
+    if *length as usize != packet.len() - 4 {
+        return Err(DynamixelError::LengthMismatch);
+    }
+    if checksum(*id, *instruction, params)? != *received {
+        return Err(DynamixelError::BadChecksum);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn known_packets() {
+        // READ_DATA of the present position (address 0x24, 2 bytes) from servo 1
+        assert_eq!(build_packet(0x01, 0x02, &[0x24, 0x02]), Ok(vec![0xFF, 0xFF, 0x01, 0x04, 0x02, 0x24, 0x02, 0xD2]));
+        // status packet with no error
+        assert_eq!(verify_packet(&[0xFF, 0xFF, 0x01, 0x02, 0x00, 0xFC]), Ok(()))
+    }
+
+    #[test]
+    fn checksum_is_inverted_sum() {
+        let params = [0x1E, 0x00, 0x02, 0x00, 0x02];
+        let total = params.iter().fold(0xFEu8.wrapping_add(7).wrapping_add(0x03), |acc, val| acc.wrapping_add(*val));
+        assert_eq!(checksum(0xFE, 0x03, &params), Ok(!total))
+    }
+
+    #[test]
+    fn too_many_params_are_rejected() {
+        assert!(checksum(0x01, 0x03, &[0u8; 253]).is_ok());
+        assert_eq!(checksum(0x01, 0x03, &[0u8; 254]), Err(DynamixelError::TooLong));
+        #[cfg(feature = "alloc")]
+        assert_eq!(build_packet(0x01, 0x03, &[0u8; 254]), Err(DynamixelError::TooLong))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_reports_faults() {
+        let packet = build_packet(0x05, 0x03, &[0x1E, 0x00, 0x02]).unwrap();
+        let mut corrupted = packet.clone();
+        corrupted[6] ^= 0x01;
+        assert_eq!(verify_packet(&corrupted), Err(DynamixelError::BadChecksum));
+        let mut corrupted = packet.clone();
+        corrupted[1] = 0x00;
+        assert_eq!(verify_packet(&corrupted), Err(DynamixelError::BadHeader));
+        assert_eq!(verify_packet(&packet[..7]), Err(DynamixelError::LengthMismatch));
+        assert_eq!(verify_packet(&packet[..5]), Err(DynamixelError::TooShort))
+    }
+}
@@ -0,0 +1,330 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # C interface
+//!
+//! extern "C" functions over the checksum types, for C code that needs the same
+//! implementations as the Rust side. Available with the ffi feature, which implies std.
+//!
+//! A running checksum is an opaque Check8Handle, created by one of the create functions and
+//! released with check8_destroy. check8_update adds bytes, check8_finalize retrieves the
+//! checksum without ending the calculation, and check8_reset returns the handle to the state
+//! it was created in. The one-shot functions checksum a buffer without a handle.
+//!
+//! The functions do not panic. A null handle is ignored, with 0 returned where a checksum is
+//! expected, and a null data pointer is taken as an empty buffer. include/check8.h declares
+//! the functions for C.
+//!
+//! The crate builds as an rlib; a shared or static library for C is built with
+//! `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! # Examples
+//!
+//! ```c
+//! #include "check8.h"
+//!
+//! Check8Handle *crc = check8_crc_create(0x07);
+//! check8_update(crc, (const uint8_t *)"1234", 4);
+//! check8_update(crc, (const uint8_t *)"56789", 5);
+//! uint8_t result = check8_finalize(crc);  /* 0xF4 */
+//! check8_destroy(crc);
+//! ```
+
+#![allow(unsafe_code)]
+
+use alloc::boxed::Box;
+use core::ptr;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Crc, Check8CrcParams, Check8Sum, Check8Xor};
+
+/// A running checksum, opaque to C.
+pub struct Check8Handle {
+    checksum: Box<dyn crate::checksum::Checksum<u8> + Send>,
+    initial: u8,
+}
+
+impl Check8Handle {
+    fn create<C: Check8 + Send + 'static>(checksum: C) -> *mut Check8Handle {
+        let initial = checksum.get_accum();
+        Box::into_raw(Box::new(Check8Handle { checksum: Box::new(checksum), initial }))
+    }
+}
+
+// the bytes at data, or none for a null pointer
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        // SAFETY: the caller guarantees data points to len readable bytes
+        unsafe { core::slice::from_raw_parts(data, len) }
+    }
+}
+
+/// Creates a handle for an arithmetic sum, Check8Sum, with the accumulator set to initial.
+#[unsafe(no_mangle)]
+pub extern "C" fn check8_sum_create(initial: u8) -> *mut Check8Handle {
+    Check8Handle::create(Check8Sum::new(initial))
+}
+
+/// Creates a handle for an XOR, Check8Xor, with the accumulator set to initial.
+#[unsafe(no_mangle)]
+pub extern "C" fn check8_xor_create(initial: u8) -> *mut Check8Handle {
+    Check8Handle::create(Check8Xor::new(initial))
+}
+
+/// Creates a handle for the plain CRC-8 of a polynomial.
+#[unsafe(no_mangle)]
+pub extern "C" fn check8_crc_create(poly: u8) -> *mut Check8Handle {
+    Check8Handle::create(Check8Crc::new(poly))
+}
+
+/// Creates a handle for a CRC described by a full set of parameters.
+///
+/// - **Returns**: The handle, or null if the width is not 1 to 8 bits.
+#[unsafe(no_mangle)]
+pub extern "C" fn check8_crc_create_with_params(params: Check8CrcParams) -> *mut Check8Handle {
+    match Check8Crc::try_with_params(params) {
+        Ok(crc) => Check8Handle::create(crc),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Creates a handle for a named CRC from the presets module, e.g. "CRC-8/MAXIM-DOW".
+/// Requires the presets feature.
+///
+/// - **Returns**: The handle, or null if the name is null, not UTF-8 or not a known preset.
+///
+/// # Safety
+///
+/// name must be null or point to a NUL-terminated string.
+#[cfg(feature = "presets")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_crc_create_preset(name: *const core::ffi::c_char) -> *mut Check8Handle {
+    if name.is_null() {
+        return ptr::null_mut();
+    }
+    // SAFETY: the caller guarantees name is a NUL-terminated string
+    let name = unsafe { core::ffi::CStr::from_ptr(name) };
+    match name.to_str().ok().and_then(crate::presets::by_name) {
+        Some(preset) => Check8Handle::create(preset.crc()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Returns a handle to the state it was created in.
+///
+/// - **Returns**: The accumulator after the reset.
+///
+/// # Safety
+///
+/// handle must be null or a live handle from one of the create functions.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_reset(handle: *mut Check8Handle) -> u8 {
+    // SAFETY: the caller guarantees the handle is null or live
+    match unsafe { handle.as_mut() } {
+        Some(handle) => handle.checksum.init(handle.initial),
+        None => 0,
+    }
+}
+
+/// Adds len bytes at data to a handle's checksum.
+///
+/// - **Returns**: The checksum so far.
+///
+/// # Safety
+///
+/// handle must be null or a live handle, and data must be null or point to len readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_update(handle: *mut Check8Handle, data: *const u8, len: usize) -> u8 {
+    // SAFETY: guaranteed by the caller
+    match unsafe { handle.as_mut() } {
+        Some(handle) => handle.checksum.calculate_from_byte_array(unsafe { bytes(data, len) }),
+        None => 0,
+    }
+}
+
+/// Retrieves the checksum of a handle, after any finalisation. The handle can still be updated.
+///
+/// # Safety
+///
+/// handle must be null or a live handle from one of the create functions.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_finalize(handle: *const Check8Handle) -> u8 {
+    // SAFETY: the caller guarantees the handle is null or live
+    match unsafe { handle.as_ref() } {
+        Some(handle) => handle.checksum.get_checksum(),
+        None => 0,
+    }
+}
+
+/// Releases a handle. Null is ignored.
+///
+/// # Safety
+///
+/// handle must be null or a live handle from one of the create functions, and is not live
+/// afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_destroy(handle: *mut Check8Handle) {
+    if !handle.is_null() {
+        // SAFETY: the handle came from Box::into_raw in Check8Handle::create
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+/// Calculates the arithmetic sum of len bytes at data, starting from zero.
+///
+/// # Safety
+///
+/// data must be null or point to len readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_sum(data: *const u8, len: usize) -> u8 {
+    // SAFETY: guaranteed by the caller
+    Check8Sum::new(0).calculate_from_byte_array(unsafe { bytes(data, len) })
+}
+
+/// Calculates the XOR of len bytes at data, starting from zero.
+///
+/// # Safety
+///
+/// data must be null or point to len readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_xor(data: *const u8, len: usize) -> u8 {
+    // SAFETY: guaranteed by the caller
+    Check8Xor::new(0).calculate_from_byte_array(unsafe { bytes(data, len) })
+}
+
+/// Calculates the plain CRC-8 of len bytes at data for a polynomial.
+///
+/// # Safety
+///
+/// data must be null or point to len readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_crc(poly: u8, data: *const u8, len: usize) -> u8 {
+    // SAFETY: guaranteed by the caller
+    Check8Crc::new(poly).calculate_from_byte_array(unsafe { bytes(data, len) })
+}
+
+/// Calculates the CRC of len bytes at data for a full set of parameters, without generating a
+/// lookup table.
+///
+/// - **Returns**: true with the CRC written to out, or false if the width is not 1 to 8 bits
+///   or out is null.
+///
+/// # Safety
+///
+/// data must be null or point to len readable bytes, and out must be null or writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn check8_crc_with_params(
+    params: Check8CrcParams,
+    data: *const u8,
+    len: usize,
+    out: *mut u8,
+) -> bool {
+    if params.validate().is_err() || out.is_null() {
+        return false;
+    }
+    // SAFETY: guaranteed by the caller
+    let crc = crate::Check8CrcBitwise::with_params(params).calculate_from_byte_array(unsafe { bytes(data, len) });
+    // SAFETY: out is not null, and the caller guarantees it is writable
+    unsafe { out.write(crc) };
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_matches_the_rust_types() {
+        let data = b"123456789";
+        unsafe {
+            let crc = check8_crc_create(0x07);
+            check8_update(crc, data.as_ptr(), 4);
+            check8_update(crc, data[4..].as_ptr(), 5);
+            assert_eq!(check8_finalize(crc), 0xF4);
+            check8_destroy(crc);
+
+            let sum = check8_sum_create(0x10);
+            check8_update(sum, data.as_ptr(), data.len());
+            assert_eq!(check8_finalize(sum), Check8Sum::new(0x10).calculate_from_byte_array(data));
+            check8_destroy(sum)
+        }
+    }
+
+    #[test]
+    fn reset_returns_to_the_created_state() {
+        let params = Check8CrcParams { refin: true, refout: true, ..Check8CrcParams::new(0x31) };
+        unsafe {
+            let crc = check8_crc_create_with_params(params);
+            check8_update(crc, b"garbage".as_ptr(), 7);
+            check8_reset(crc);
+            assert_eq!(check8_update(crc, b"123456789".as_ptr(), 9), 0xA1);
+            check8_destroy(crc)
+        }
+    }
+
+    #[test]
+    fn one_shot_functions() {
+        let data = b"123456789";
+        let params = Check8CrcParams { width: 7, ..Check8CrcParams::new(0x09) };
+        let mut out = 0;
+        unsafe {
+            assert_eq!(check8_sum(data.as_ptr(), data.len()), Check8Sum::new(0).calculate_from_byte_array(data));
+            assert_eq!(check8_xor(data.as_ptr(), data.len()), Check8Xor::new(0).calculate_from_byte_array(data));
+            assert_eq!(check8_crc(0x07, data.as_ptr(), data.len()), 0xF4);
+            assert!(check8_crc_with_params(params, data.as_ptr(), data.len(), &mut out));
+        }
+        assert_eq!(out, 0x75)
+    }
+
+    #[test]
+    fn invalid_arguments_do_not_panic() {
+        let params = Check8CrcParams { width: 9, ..Check8CrcParams::new(0x07) };
+        let mut out = 0;
+        unsafe {
+            assert!(check8_crc_create_with_params(params).is_null());
+            assert!(!check8_crc_with_params(params, ptr::null(), 0, &mut out));
+            assert_eq!(check8_update(ptr::null_mut(), b"1".as_ptr(), 1), 0);
+            assert_eq!(check8_finalize(ptr::null()), 0);
+            assert_eq!(check8_reset(ptr::null_mut()), 0);
+            check8_destroy(ptr::null_mut());
+            assert_eq!(check8_crc(0x07, ptr::null(), 5), 0x00)
+        }
+    }
+
+    #[cfg(feature = "presets")]
+    #[test]
+    fn presets_by_name() {
+        unsafe {
+            let crc = check8_crc_create_preset(c"CRC-8/MAXIM-DOW".as_ptr());
+            assert_eq!(check8_update(crc, b"123456789".as_ptr(), 9), 0xA1);
+            check8_destroy(crc);
+            assert!(check8_crc_create_preset(c"CRC-8/UNKNOWN".as_ptr()).is_null());
+            assert!(check8_crc_create_preset(ptr::null()).is_null())
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Game Boy
+//!
+//! Helpers for the header checksum of Game Boy cartridge ROM images. The boot ROM computes
+//! x = x - byte - 1 over the header bytes 0x134 to 0x14C and refuses to start the cartridge
+//! unless the low 8 bits match the byte at 0x14D. Over the 25 bytes this is the two's
+//! complement of their sum plus 25, as computed by Check8Sum with an initial value of 25
+//! and Finalise::Negate.
+
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Sum, Finalise};
+
+/// The header bytes covered by the checksum, the title to the mask ROM version number.
+pub const HEADER_CHECKSUM_RANGE: RangeInclusive<usize> = 0x134..=0x14C;
+
+/// The offset of the header checksum byte.
+pub const HEADER_CHECKSUM_OFFSET: usize = 0x14D;
+
+/// # Variants
+///
+/// - TooShort: The image is too short to contain the cartridge header.
+/// - BadChecksum: The stored header checksum does not match the calculated one.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GameBoyError {
+    TooShort,
+    BadChecksum { calculated: u8, stored: u8 },
+}
+
+impl fmt::Display for GameBoyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameBoyError::TooShort => write!(f, "ROM image is too short to contain a cartridge header"),
+            GameBoyError::BadChecksum { calculated, stored } => write!(
+                f,
+                "header checksum mismatch: calculated 0x{:02X}, stored 0x{:02X}",
+                calculated, stored
+            ),
+        }
+    }
+}
+
+impl core::error::Error for GameBoyError {}
+
+/// Calculates the header checksum of a ROM image.
+///
+/// - **Parameter**: rom - The ROM image, at least up to and including the header checksum byte.
+/// - **Returns**: The header checksum, or GameBoyError::TooShort.
+///
+pub fn header_checksum(rom: &[u8]) -> Result<u8, GameBoyError> {
+    if rom.len() <= HEADER_CHECKSUM_OFFSET {
+        return Err(GameBoyError::TooShort);
+    }
+    let header = &rom[HEADER_CHECKSUM_RANGE];
+    Ok(Check8Sum::with_finalise(header.len() as u8, Finalise::Negate).calculate_from_byte_array(header))
+}
+
+/// Checks the header checksum stored in a ROM image.
+///
+/// - **Parameter**: rom - The ROM image.
+/// - **Returns**: Ok if the stored checksum is correct, or a GameBoyError.
+///
+pub fn verify_header(rom: &[u8]) -> Result<(), GameBoyError> {
+    let calculated = header_checksum(rom)?;
+    let stored = rom[HEADER_CHECKSUM_OFFSET];
+    if calculated != stored {
+        return Err(GameBoyError::BadChecksum { calculated, stored });
+    }
+    Ok(())
+}
+
+/// Writes the correct header checksum into a ROM image, e.g. after editing its title.
+///
+/// - **Parameter**: rom - The ROM image to patch.
+/// - **Returns**: The checksum written, or GameBoyError::TooShort.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::gameboy::{patch_header, verify_header};
+/// fn main() {
+///     let mut rom = vec![0u8; 0x8000];
+///     rom[0x134..0x13A].copy_from_slice(b"TETRIS");
+///     assert!(verify_header(&rom).is_err());
+///     patch_header(&mut rom).unwrap();
+///     assert!(verify_header(&rom).is_ok());
+/// }
+/// ```
+///
+pub fn patch_header(rom: &mut [u8]) -> Result<u8, GameBoyError> {
+    let checksum = header_checksum(rom)?;
+    rom[HEADER_CHECKSUM_OFFSET] = checksum;
+    Ok(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the algorithm as the boot ROM runs it
+    fn boot_rom_checksum(rom: &[u8]) -> u8 {
+        let mut x = 0u8;
+        for &byte in &rom[0x134..=0x14C] {
+            x = x.wrapping_sub(byte).wrapping_sub(1);
+        }
+        x
+    }
+
+    #[test]
+    fn blank_header() {
+        assert_eq!(header_checksum(&[0u8; 0x150]), Ok(0xE7))
+    }
+
+    #[test]
+    fn matches_boot_rom() {
+        let mut rom = [0u8; 0x150];
+        for (i, byte) in rom.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37).wrapping_add(11);
+        }
+        assert_eq!(header_checksum(&rom), Ok(boot_rom_checksum(&rom)))
+    }
+
+    #[test]
+    fn patch_then_verify() {
+        let mut rom = [0u8; 0x150];
+        rom[0x134..0x13A].copy_from_slice(b"TETRIS");
+        let checksum = patch_header(&mut rom).unwrap();
+        assert_eq!(rom[HEADER_CHECKSUM_OFFSET], checksum);
+        assert_eq!(verify_header(&rom), Ok(()));
+        rom[0x134] = b'Z';
+        assert_eq!(
+            verify_header(&rom),
+            Err(GameBoyError::BadChecksum { calculated: boot_rom_checksum(&rom), stored: checksum })
+        )
+    }
+
+    #[test]
+    fn short_image() {
+        assert_eq!(header_checksum(&[0u8; 0x14D]), Err(GameBoyError::TooShort));
+        assert_eq!(patch_header(&mut [0u8; 0x100]), Err(GameBoyError::TooShort))
+    }
+}
@@ -0,0 +1,111 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Hex
+//!
+//! Hexadecimal encoding and decoding for the text-based record formats.
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+// the value of a hexadecimal digit, in either case
+fn digit_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes pairs of hexadecimal digits into the start of a buffer, or None if a digit is
+/// invalid, one is left over, or there are more bytes than fit.
+pub(crate) fn decode_into<'a>(hex: &str, out: &'a mut [u8]) -> Option<&'a [u8]> {
+    let (pairs, rest) = hex.as_bytes().as_chunks::<2>();
+    if !rest.is_empty() || pairs.len() > out.len() {
+        return None;
+    }
+    let (bytes, _) = out.split_at_mut(pairs.len());
+    for (val, pair) in bytes.iter_mut().zip(pairs) {
+        *val = decode_pair(*pair)?;
+    }
+    Some(bytes)
+}
+
+/// Decodes one pair of hexadecimal digits, or None if either is invalid.
+pub(crate) fn decode_pair([high, low]: [u8; 2]) -> Option<u8> {
+    Some((digit_value(high)? << 4) | digit_value(low)?)
+}
+
+/// Decodes pairs of hexadecimal digits into bytes, or None if a digit is invalid or one is left over.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode(hex: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![0; hex.len() / 2];
+    decode_into(hex, &mut bytes)?;
+    Some(bytes)
+}
+
+/// Encodes bytes as upper case hexadecimal digits, appending them to a string.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_into(bytes: &[u8], out: &mut String) {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    for val in bytes {
+        out.push(DIGITS[(val >> 4) as usize] as char);
+        out.push(DIGITS[(val & 0x0F) as usize] as char);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_into_a_buffer() {
+        let mut buf = [0u8; 4];
+        assert_eq!(decode_into("00ff7E", &mut buf), Some(&[0x00, 0xFF, 0x7E][..]));
+        assert_eq!(decode_into("00ff7Ea5", &mut buf), Some(&[0x00, 0xFF, 0x7E, 0xA5][..]));
+        assert_eq!(decode_into("00ff7Ea500", &mut buf), None);
+        assert_eq!(decode_into("0", &mut buf), None);
+        assert_eq!(decode_into("0G", &mut buf), None)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_pairs() {
+        assert_eq!(decode("00ff7Ea5"), Some(vec![0x00, 0xFF, 0x7E, 0xA5]));
+        assert_eq!(decode(""), Some(vec![]));
+        assert_eq!(decode("0"), None);
+        assert_eq!(decode("0G"), None)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_upper_case() {
+        let mut out = String::from(":");
+        encode_into(&[0x00, 0xFF, 0x7E, 0xA5], &mut out);
+        assert_eq!(out, ":00FF7EA5")
+    }
+}
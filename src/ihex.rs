@@ -0,0 +1,250 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Intel HEX
+//!
+//! Helpers for the checksum of Intel HEX records. A record is a line of the form
+//! `:LLAAAATTDD...CC`: a start code, the data byte count, a 16-bit address, the record type,
+//! the data and a checksum, all as hexadecimal digit pairs. The checksum is the two's
+//! complement of the sum of the other bytes, as computed by Check8Lrc, so that all of the
+//! bytes of a valid record sum to zero.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Lrc;
+use crate::hex;
+
+/// # Variants
+///
+/// - MissingStart: The line does not start with ':'.
+/// - InvalidHex: The line contains a character that is not a hexadecimal digit, or an odd number of them.
+/// - TooLong: The data is longer than 255 bytes, the most the byte count can hold.
+/// - LengthMismatch: The byte count does not match the number of data bytes in the line.
+/// - BadChecksum: The checksum does not match the other bytes of the record.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IhexError {
+    MissingStart,
+    InvalidHex,
+    TooLong,
+    LengthMismatch,
+    BadChecksum,
+}
+
+impl fmt::Display for IhexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IhexError::MissingStart => write!(f, "Intel HEX record does not start with ':'"),
+            IhexError::InvalidHex => write!(f, "Intel HEX record contains invalid hexadecimal digits"),
+            IhexError::TooLong => write!(f, "Intel HEX record data is too long"),
+            IhexError::LengthMismatch => write!(f, "Intel HEX byte count does not match the record"),
+            IhexError::BadChecksum => write!(f, "Intel HEX record checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for IhexError {}
+
+// the longest record: the byte count, address, record type, 255 data bytes and the checksum
+const MAX_RECORD_LEN: usize = 255 + 5;
+
+/// The fields of an Intel HEX record.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IhexRecord {
+    pub address: u16,
+    pub record_type: u8,
+    pub data: Vec<u8>,
+}
+
+/// Calculates the checksum of a record from its fields.
+///
+/// - **Parameter**: address - The 16-bit address field.
+/// - **Parameter**: record_type - The record type, e.g. 0x00 for data or 0x01 for end of file.
+/// - **Parameter**: data - The data bytes, at most 255.
+/// - **Returns**: The checksum byte, or IhexError::TooLong.
+pub fn record_checksum(address: u16, record_type: u8, data: &[u8]) -> Result<u8, IhexError> {
+    let count = u8::try_from(data.len()).map_err(|_| IhexError::TooLong)?;
+    let mut lrc = Check8Lrc::new(0x00);
+    lrc.add(count);
+    lrc.add_u16_be(address);
+    lrc.add(record_type);
+    Ok(lrc.calculate_from_byte_array(data))
+}
+
+/// Formats a record as a line, without a line ending.
+///
+/// - **Parameter**: address - The 16-bit address field.
+/// - **Parameter**: record_type - The record type.
+/// - **Parameter**: data - The data bytes, at most 255.
+/// - **Returns**: The record, e.g. ":00000001FF", or IhexError::TooLong.
+#[cfg(feature = "alloc")]
+pub fn format_record(address: u16, record_type: u8, data: &[u8]) -> Result<String, IhexError> {
+    let checksum = record_checksum(address, record_type, data)?;
+    let mut line = String::with_capacity(11 + 2 * data.len());
+    line.push(':');
+    hex::encode_into(&[data.len() as u8], &mut line);
+    hex::encode_into(&address.to_be_bytes(), &mut line);
+    hex::encode_into(&[record_type], &mut line);
+    hex::encode_into(data, &mut line);
+    hex::encode_into(&[checksum], &mut line);
+    Ok(line)
+}
+
+// Decodes a record into a buffer and checks its byte count and checksum, returning the bytes
+// from the byte count to the checksum.
+fn decode_record<'a>(line: &str, buf: &'a mut [u8; MAX_RECORD_LEN]) -> Result<&'a [u8], IhexError> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let digits = line.strip_prefix(':').ok_or(IhexError::MissingStart)?;
+    // a line too long for any byte count cannot match its own
+    if digits.len() > 2 * MAX_RECORD_LEN {
+        return Err(IhexError::LengthMismatch);
+    }
+    let bytes = hex::decode_into(digits, buf).ok_or(IhexError::InvalidHex)?;
+    match bytes.first() {
+        Some(&count) if bytes.len() == count as usize + 5 => {}
+        _ => return Err(IhexError::LengthMismatch),
+    }
+    // all of the bytes of a record, including its checksum, sum to zero
+    let mut lrc = Check8Lrc::new(0x00);
+    lrc.calculate_from_byte_array(bytes);
+    if lrc.get_accum() != 0x00 {
+        return Err(IhexError::BadChecksum);
+    }
+    Ok(bytes)
+}
+
+/// Parses a record, validating its byte count and checksum.
+///
+/// - **Parameter**: line - The record, which may end with "\r\n".
+/// - **Returns**: The fields of the record, or an IhexError describing the fault.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::ihex::{format_record, parse_record, record_checksum};
+/// fn main() {
+///     let record = parse_record(":10010000214601360121470136007EFE09D2190140").unwrap();
+///     assert_eq!(record.address, 0x0100);
+///     assert_eq!(record.data.len(), 16);
+///     assert_eq!(record_checksum(record.address, record.record_type, &record.data), Ok(0x40));
+///     assert_eq!(format_record(0x0000, 0x01, &[]).unwrap(), ":00000001FF");
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn parse_record(line: &str) -> Result<IhexRecord, IhexError> {
+    let mut buf = [0u8; MAX_RECORD_LEN];
+    match decode_record(line, &mut buf)? {
+        [_, address_high, address_low, record_type, data @ .., _] => Ok(IhexRecord {
+            address: u16::from_be_bytes([*address_high, *address_low]),
+            record_type: *record_type,
+            data: data.to_vec(),
+        }),
+        _ => Err(IhexError::LengthMismatch),
+    }
+}
+
+/// Verifies the byte count and checksum of a record.
+///
+/// - **Parameter**: line - The record, which may end with "\r\n".
+/// - **Returns**: Ok if the record is intact, or an IhexError describing the fault.
+///
+/// The record is decoded into a buffer on the stack, so this needs no allocator.
+pub fn verify_record(line: &str) -> Result<(), IhexError> {
+    let mut buf = [0u8; MAX_RECORD_LEN];
+    decode_record(line, &mut buf).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_records() {
+        assert_eq!(verify_record(":10010000214601360121470136007EFE09D2190140"), Ok(()));
+        assert_eq!(verify_record(":100110002146017E17C20001FF5F16002148011928"), Ok(()));
+        assert_eq!(verify_record(":00000001FF\r\n"), Ok(()));
+        assert_eq!(verify_record(":020000040800F2"), Ok(()))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_extracts_fields() {
+        let record = parse_record(":020000040800f2").unwrap();
+        assert_eq!(record, IhexRecord { address: 0x0000, record_type: 0x04, data: vec![0x08, 0x00] })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn format_round_trips() {
+        let data = [0x21, 0x46, 0x01, 0x36, 0x01, 0x21, 0x47, 0x01, 0x36, 0x00, 0x7E, 0xFE, 0x09, 0xD2, 0x19, 0x01];
+        let line = format_record(0x0100, 0x00, &data).unwrap();
+        assert_eq!(line, ":10010000214601360121470136007EFE09D2190140");
+        assert_eq!(parse_record(&line).unwrap().data, data.to_vec())
+    }
+
+    #[test]
+    fn verify_reports_faults() {
+        assert_eq!(verify_record("10010000214601360121470136007EFE09D2190140"), Err(IhexError::MissingStart));
+        assert_eq!(verify_record(":10010000214601360121470136007EFE09D219014"), Err(IhexError::InvalidHex));
+        assert_eq!(verify_record(":1001000021460136012147013600XXFE09D2190140"), Err(IhexError::InvalidHex));
+        assert_eq!(verify_record(":0F010000214601360121470136007EFE09D2190140"), Err(IhexError::LengthMismatch));
+        assert_eq!(verify_record(":10010000214601360121470136007EFE09D2190141"), Err(IhexError::BadChecksum));
+        assert_eq!(verify_record(":"), Err(IhexError::LengthMismatch))
+    }
+
+    #[test]
+    fn data_too_long_for_the_byte_count_is_rejected() {
+        let data = [0u8; 256];
+        assert_eq!(record_checksum(0x0000, 0x00, &data), Err(IhexError::TooLong));
+        #[cfg(feature = "alloc")]
+        assert_eq!(format_record(0x0000, 0x00, &data), Err(IhexError::TooLong));
+        assert!(record_checksum(0x0000, 0x00, &data[..255]).is_ok())
+    }
+
+    #[test]
+    fn longest_records_verify() {
+        // 255 bytes of 0x01: the byte count, address and type sum to 0xFF, the data to 0xFF
+        let mut line = [b'0'; 1 + 2 * MAX_RECORD_LEN];
+        line[0] = b':';
+        line[1..3].copy_from_slice(b"FF");
+        for pair in line[9..9 + 2 * 255].chunks_mut(2) {
+            pair.copy_from_slice(b"01");
+        }
+        line[9 + 2 * 255..].copy_from_slice(b"02");
+        let line = core::str::from_utf8(&line).unwrap();
+        assert_eq!(verify_record(line), Ok(()));
+        let longer = [line, "00"].concat();
+        assert_eq!(verify_record(&longer), Err(IhexError::LengthMismatch))
+    }
+}
@@ -0,0 +1,235 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ISO 7816-3 T=1
+//!
+//! Helpers for the epilogue field of ISO/IEC 7816-3 T=1 blocks. A block is a prologue (NAD,
+//! PCB and LEN), up to 254 bytes of information (INF), and an error detection code (EDC)
+//! over the prologue and information: either a one byte LRC, the XOR of the bytes as computed
+//! by Check8Xor, or a two byte CRC, as selected by the card in its ATR (bit 1 of the first
+//! TCi for T=1).
+//!
+//! The CRC is the 16-bit CCITT polynomial, reflected, starting from 0xFFFF with no final XOR,
+//! sent most significant byte first, as computed by deployed T=1 reader stacks.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Xor;
+
+/// The longest information field of a block.
+pub const T1_MAX_INF: usize = 254;
+
+/// # Variants
+///
+/// - Lrc: A one byte longitudinal redundancy check, the default.
+/// - Crc: A two byte cyclic redundancy check.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum T1Edc {
+    #[default]
+    Lrc,
+    Crc,
+}
+
+impl T1Edc {
+    /// The number of EDC bytes at the end of a block.
+    pub const fn edc_len(self) -> usize {
+        match self {
+            T1Edc::Lrc => 1,
+            T1Edc::Crc => 2,
+        }
+    }
+}
+
+/// # Variants
+///
+/// - TooShort: The block is shorter than its prologue and EDC.
+/// - TooLong: The information field is longer than 254 bytes.
+/// - LengthMismatch: The LEN byte does not match the number of bytes in the block.
+/// - BadEdc: The EDC does not match the prologue and information field.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum T1Error {
+    TooShort,
+    TooLong,
+    LengthMismatch,
+    BadEdc,
+}
+
+impl fmt::Display for T1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            T1Error::TooShort => write!(f, "T=1 block is too short"),
+            T1Error::TooLong => write!(f, "T=1 information field is too long"),
+            T1Error::LengthMismatch => write!(f, "T=1 LEN does not match the block"),
+            T1Error::BadEdc => write!(f, "T=1 EDC does not match"),
+        }
+    }
+}
+
+impl core::error::Error for T1Error {}
+
+/// Calculates the LRC of a prologue and information field.
+pub fn lrc(data: &[u8]) -> u8 {
+    Check8Xor::new(0x00).calculate_from_byte_array(data)
+}
+
+/// Calculates the CRC of a prologue and information field.
+pub fn crc(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for val in data {
+        crc ^= *val as u16;
+        for _ in 0..8 {
+            crc = if crc & 0x0001 != 0 { (crc >> 1) ^ 0x8408 } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+// appends the EDC of the bytes so far
+#[cfg(feature = "alloc")]
+fn push_edc(block: &mut Vec<u8>, edc: T1Edc) {
+    match edc {
+        T1Edc::Lrc => block.push(lrc(block)),
+        T1Edc::Crc => block.extend_from_slice(&crc(block).to_be_bytes()),
+    }
+}
+
+/// Builds a complete block from its NAD, PCB and information field.
+///
+/// - **Parameter**: nad - The node address byte.
+/// - **Parameter**: pcb - The protocol control byte.
+/// - **Parameter**: inf - The information field, at most 254 bytes.
+/// - **Parameter**: edc - The EDC selected in the ATR.
+/// - **Returns**: The block, or T1Error::TooLong.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::iso7816::{encode_block, verify_block, T1Edc};
+/// fn main() {
+///     // S(IFS request) for an IFSD of 254 bytes
+///     let block = encode_block(0x00, 0xC1, &[0xFE], T1Edc::Lrc).unwrap();
+///     assert_eq!(block, vec![0x00, 0xC1, 0x01, 0xFE, 0x3E]);
+///     assert_eq!(verify_block(&block, T1Edc::Lrc), Ok(&[0xFE][..]));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn encode_block(nad: u8, pcb: u8, inf: &[u8], edc: T1Edc) -> Result<Vec<u8>, T1Error> {
+    if inf.len() > T1_MAX_INF {
+        return Err(T1Error::TooLong);
+    }
+    let mut block = Vec::with_capacity(3 + inf.len() + edc.edc_len());
+    block.extend_from_slice(&[nad, pcb, inf.len() as u8]);
+    block.extend_from_slice(inf);
+    push_edc(&mut block, edc);
+    Ok(block)
+}
+
+/// Verifies the LEN and EDC of a received block.
+///
+/// - **Parameter**: block - The block, from the NAD to the end of the EDC.
+/// - **Parameter**: edc - The EDC selected in the ATR.
+/// - **Returns**: The information field, or a T1Error describing the fault.
+pub fn verify_block(block: &[u8], edc: T1Edc) -> Result<&[u8], T1Error> {
+    if block.len() < 3 + edc.edc_len() {
+        return Err(T1Error::TooShort);
+    }
+    let len = block[2] as usize;
+    if len > T1_MAX_INF {
+        return Err(T1Error::TooLong);
+    }
+    if block.len() != 3 + len + edc.edc_len() {
+        return Err(T1Error::LengthMismatch);
+    }
+    let (data, received) = block.split_at(3 + len);
+    let intact = match edc {
+        T1Edc::Lrc => *received == [lrc(data)],
+        T1Edc::Crc => *received == crc(data).to_be_bytes(),
+    };
+    if !intact {
+        return Err(T1Error::BadEdc);
+    }
+    Ok(&data[3..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_check_value() {
+        // the reflected CCITT CRC from 0xFFFF without a final XOR, CRC-16/MCRF4XX in the catalogue
+        assert_eq!(crc(b"123456789"), 0x6F91)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lrc_blocks() {
+        // R-block acknowledging sequence number 1
+        assert_eq!(encode_block(0x00, 0x90, &[], T1Edc::Lrc).unwrap(), vec![0x00, 0x90, 0x00, 0x90]);
+        let block = encode_block(0x00, 0x00, &[0x00, 0xA4, 0x04, 0x00], T1Edc::Lrc).unwrap();
+        // the XOR of 00 00 04 00 A4 04 00
+        assert_eq!(block[7], 0xA4)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn crc_blocks_are_most_significant_byte_first() {
+        let block = encode_block(0x00, 0xC1, &[0xFE], T1Edc::Crc).unwrap();
+        assert_eq!(block, vec![0x00, 0xC1, 0x01, 0xFE, 0x54, 0x4E]);
+        assert_eq!(verify_block(&block, T1Edc::Crc), Ok(&[0xFE][..]))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_reports_faults() {
+        let block = encode_block(0x00, 0x40, &[0x90, 0x00], T1Edc::Crc).unwrap();
+        let mut corrupted = block.clone();
+        corrupted[3] ^= 0x01;
+        assert_eq!(verify_block(&corrupted, T1Edc::Crc), Err(T1Error::BadEdc));
+        // a block checked with the wrong EDC has the wrong length
+        assert_eq!(verify_block(&block, T1Edc::Lrc), Err(T1Error::LengthMismatch));
+        assert_eq!(verify_block(&block[..6], T1Edc::Crc), Err(T1Error::LengthMismatch));
+        assert_eq!(verify_block(&block[..3], T1Edc::Lrc), Err(T1Error::TooShort))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn information_field_limit() {
+        assert!(encode_block(0x00, 0x00, &[0u8; 254], T1Edc::Lrc).is_ok());
+        assert_eq!(encode_block(0x00, 0x00, &[0u8; 255], T1Edc::Lrc), Err(T1Error::TooLong))
+    }
+}
@@ -27,42 +27,307 @@ SOFTWARE.
 //! # Check8
 //!
 //! A library of 8-bit checksum types implementing the Check8 trait
+//!
+//! The Checksum trait holds the accumulate and calculate methods for every width of checksum
+//! (u8, u16 or u32), so generic code can accept checksums of any width. Check8 is the 8-bit
+//! subtrait, adding construction from an initial value, snapshot and restore, and add_bits.
+//!
+//! # Panics
+//!
+//! Once constructed, the checksum types do not panic: adding data and reading the checksum
+//! use no unwraps and no indexing that can go out of bounds, only lookups of a u8 into a
+//! 256-entry table and fixed-size chunks. The CRC constructors panic on a width out of range,
+//! which in a constant is a compile-time error; try_with_params returns Check8Error::InvalidWidth
+//! instead, for parameters only known at run time. The crate has no unsafe code, except for
+//! the memory map of the mmap feature and the C interface of the ffi feature.
+//!
+//! # Features
+//!
+//! Without the std feature the crate is no_std: every checksum type, the presets and the
+//! verification functions of the protocol modules need nothing but core. The text record
+//! formats decode into buffers on the stack to verify; only the functions that return a
+//! Vec or String, and SrecValidator, which returns each record, need the alloc feature.
+//!
+//! The crc, sum and xor features select the algorithm families, so a bootloader can compile
+//! only the one it uses; the other simple checksum types, which have no tables, are always
+//! compiled. The presets, checkdigit and protocols features leave out the catalogue and the
+//! helper modules, so a small build cannot pull them in by accident.
+//!
+//! - std (default): std::io::Write for the checksum types, so they can be the destination of io::copy or write!,
+//!   the ChecksumWriter and ChecksumReader adapters, copy_with_checksum, calculate_from_reader
+//!   and calculate_from_file, and Check8Crc::with_params_cached. Implies alloc.
+//! - crc (default): the CRC types, Check8Crc, Check8CrcNibble, Check8CrcBitwise, Check8CrcSlice8,
+//!   Check8CrcConst, check16::Check16Crc and the check32 module, with const_crc8 and the codegen module.
+//! - sum (default): Check8Sum and const_sum8.
+//! - xor (default): Check8Xor and const_xor8.
+//! - presets (default): the presets module, the named CRC catalogue with its PRESETS table and
+//!   lookup by name, and the CRC names of check8!. Implies crc.
+//! - checkdigit (default): the checkdigit module.
+//! - protocols (default): the protocol modules, from aes3 to zxtap. Implies presets, sum and xor.
+//! - alloc: the functions that return a Vec or String, such as the frame builders, the check digit
+//!   append functions, the record parsers and formatters of the text formats (Intel HEX, S-records,
+//!   Modbus ASCII, NMEA append) and codegen's rust_table and c_table, for no_std targets with an allocator.
+//! - bytes: calculate_from_buf, which checksums a bytes::Buf chunk by chunk without copying.
+//! - digest: the Update, FixedOutput, FixedOutputReset and Reset traits of the RustCrypto digest crate, and
+//!   Digest for the types with a Default.
+//! - defmt: defmt::Format for Check8State, the error types and the CRC parameters and presets, so they
+//!   can be logged efficiently from embedded targets, e.g. over RTT.
+//! - ffi: the ffi module, extern "C" functions over opaque checksum handles and one-shot
+//!   functions, for C tooling. Build with `cargo rustc --features ffi --crate-type cdylib`. Implies std,
+//!   crc, sum and xor.
+//! - wasm: the wasm module, wasm-bindgen wrappers that construct a checksum by algorithm name,
+//!   update it with a Uint8Array and finalize it, for JavaScript in a browser. Implies alloc, presets,
+//!   sum and xor.
+//! - python: a PyO3 extension module, check8, with Checksum constructed by algorithm name,
+//!   the list of names, and the verify helpers of the protocol and check digit modules. Build
+//!   with `cargo rustc --features python --crate-type cdylib`. Implies std, presets, checkdigit and protocols.
+//! - embedded-io: embedded_io::Write for the checksum types, and embedded_io::Read and Write for the
+//!   ChecksumReader and ChecksumWriter adapters, so no_std targets can stream into a checksum. The
+//!   adapters fail with Check8IoError, which wraps the inner error.
+//! - hasher: core::hash::Hasher for the checksum types, with finish returning the checksum widened to u64.
+//! - mmap: calculate_from_file memory-maps large files instead of reading them through a buffer.
+//! - tokio: AsyncChecksumReader and AsyncChecksumWriter, implementing tokio's AsyncRead and AsyncWrite, and
+//!   calculate_from_async_reader.
+//! - futures: calculate_from_stream and the Check8StreamExt combinators for futures Streams of byte chunks.
+//! - rayon: Check8Par::par_calculate, which checksums a large buffer on the rayon thread pool, for
+//!   Check8Sum, Check8Xor and Check8Crc. Implies std, crc, sum and xor.
+//! - serde: Serialize/Deserialize for the checksum types, so a partially computed checksum can be persisted.
+//! - simd: Check8Sum and Check8Xor add byte arrays 32 bytes at a time with std::simd. Requires a nightly
+//!   compiler; without it they use the word-at-a-time path, which needs no nightly features.
+
+// the examples are written out in full, including fn main, as a teaching aid
+#![allow(clippy::needless_doctest_main)]
+// unsafe code is confined to mapping a file with the mmap feature and the C interface of the
+// ffi feature
+#![cfg_attr(not(any(feature = "mmap", feature = "ffi")), forbid(unsafe_code))]
+#![cfg_attr(any(feature = "mmap", feature = "ffi"), deny(unsafe_code))]
+#![cfg_attr(
+    not(test),
+    deny(clippy::unwrap_used, clippy::expect_used, clippy::panic, clippy::todo, clippy::unimplemented, clippy::unreachable)
+)]
+// the unit tests use std freely, whatever the features
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(all(feature = "simd", any(feature = "sum", feature = "xor")), feature(portable_simd))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "sum")]
 mod check8sum;  // implements Check8Sum - a simple arithmetic sum checksum type
+#[cfg(feature = "xor")]
 mod check8xor;  // implements Check8Xor - a simple XOR checksum type
+mod check8xorrotate;  // implements Check8XorRotate - a rotate-and-XOR checksum type
+#[cfg(feature = "crc")]
 mod check8crc;  // implements Check8Crc - an 8-bit CRC checksum type
+mod check8lrc;  // implements Check8Lrc - a two's complement sum (LRC) checksum type
+mod check8bsd;  // implements Check8Bsd - a BSD rotating sum checksum type
+mod check8parity;  // implements Check8Parity - an even or odd longitudinal parity checksum type
+mod check8onescomplement;  // implements Check8OnesComplement - an end-around carry sum checksum type
+mod check8fletcher;  // implements Check8Fletcher - a position-sensitive Fletcher checksum type
+mod check8pearson;  // implements Check8Pearson - an 8-bit Pearson hash type
+mod check7roland;  // implements Check7Roland - the 7-bit Roland MIDI SysEx checksum type
+#[cfg(feature = "crc")]
+mod check8crcnibble;  // implements Check8CrcNibble - an 8-bit CRC checksum type with 16-entry nibble tables
+#[cfg(feature = "crc")]
+mod check8crcbitwise;  // implements Check8CrcBitwise - an 8-bit CRC checksum type without a lookup table
+#[cfg(feature = "crc")]
+mod check8crcslice8;  // implements Check8CrcSlice8 - an 8-bit CRC checksum type taking 8 bytes per step
+#[cfg(feature = "crc")]
+mod check8crcconst;  // implements Check8CrcConst - an 8-bit CRC checksum type with a compile-time table
+#[cfg(any(feature = "crc", feature = "sum", feature = "xor"))]
+mod check8const;  // implements const_sum8, const_xor8 and const_crc8 - compile-time one-shot checksums
+#[doc(hidden)]
+pub mod check8macro;  // implements the check8! macro - compile-time checksums of literals
+mod check8finalise;  // implements Finalise - the selectable finalisation of a checksum
+#[cfg(feature = "digest")]
+mod check8digest;  // implements the RustCrypto digest traits for the checksum types
+#[cfg(feature = "embedded-io")]
+mod check8embeddedio;  // implements the embedded-io traits for the checksum types and adapters
+mod check8extend;  // implements Extend<u8> and Extend<&u8> for the checksum types
+mod check8fmt;  // implements fmt::Write for the checksum types
+#[cfg(feature = "hasher")]
+mod check8hasher;  // implements Hasher for the checksum types
+mod check8ops;  // implements AddAssign, and BitXorAssign for Check8Xor, to add data with operators
+mod check8iter;  // implements Check8IterExt and RunningChecksum - checksums of iterators of bytes
+mod check8state;  // implements Check8State - a snapshot of a checksum's accumulator
+mod check8error;  // implements Check8Error - the error type for fallible operations
+#[cfg(feature = "std")]
+mod check8io;  // implements io::Write for the checksum types, and copy_with_checksum
+#[cfg(feature = "std")]
+mod check8file;  // reads files for calculate_from_file, memory-mapped with the mmap feature
+#[cfg(all(feature = "std", feature = "crc"))]
+mod check8crccache;  // a process-wide cache of CRC tables for Check8Crc::with_params_cached
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+mod check8writer;  // implements ChecksumWriter - a writer adapter that checksums what passes through
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+mod check8reader;  // implements ChecksumReader - a reader adapter that checksums what passes through
+#[cfg(feature = "tokio")]
+mod check8tokio;  // implements the async reader and writer adapters for tokio
+#[cfg(feature = "rayon")]
+mod check8par;  // implements Check8Par - checksums of large buffers on the rayon thread pool
+#[cfg(feature = "futures")]
+mod check8stream;  // implements the adapters between checksums and futures Streams
+#[cfg(feature = "protocols")]
+mod hex;  // hexadecimal encoding for the text record formats
+#[cfg(any(feature = "sum", feature = "xor"))]
+mod check8words;  // word-at-a-time, or with the simd feature vector, byte sum and XOR for Check8Sum and Check8Xor
+#[cfg(feature = "crc")]
+mod crcwide;  // the Rocksoft CRC model in 64 bits, shared by the 16 and 32-bit CRC types
+
+pub mod checksum;  // the Checksum trait, generalised over the width of the checksum
+pub mod check16;  // 16-bit checksums: CRC-16 and Fletcher-16
+#[cfg(feature = "crc")]
+pub mod check32;  // 32-bit checksums: CRC-32 and CRC-32C
+#[cfg(any(feature = "presets", all(test, feature = "crc")))]  // the unit tests check against the catalogue
+pub mod presets;  // named CRC algorithms from the CRC catalogue
+#[cfg(any(feature = "wasm", feature = "python"))]
+mod check8registry;  // the checksum algorithms by name, for the language bindings
+#[cfg(feature = "ffi")]
+pub mod ffi;  // extern "C" functions for calling the checksum types from C
+#[cfg(feature = "wasm")]
+pub mod wasm;  // wasm-bindgen wrappers for calling the checksum types from JavaScript
+#[cfg(feature = "python")]
+mod python;  // the PyO3 extension module
+#[cfg(feature = "crc")]
+pub mod codegen;  // CRC lookup tables as Rust or C source
+#[cfg(feature = "protocols")]
+pub mod sdmmc;  // SD/MMC command frame CRC-7
+#[cfg(feature = "protocols")]
+pub mod smbus;  // SMBus Packet Error Code over whole transactions
+#[cfg(feature = "protocols")]
+pub mod autosar;  // AUTOSAR E2E Profile 1 CRC
+#[cfg(feature = "protocols")]
+pub mod crsf;  // Crossfire (CRSF) RC link frame check
+#[cfg(feature = "protocols")]
+pub mod bluetooth;  // Bluetooth BR/EDR packet header HEC
+#[cfg(feature = "protocols")]
+pub mod aes3;  // AES3 channel status CRC
+#[cfg(feature = "protocols")]
+pub mod atm;  // ATM cell header HEC
+#[cfg(feature = "protocols")]
+pub mod lin;  // LIN bus classic and enhanced checksums
+#[cfg(feature = "protocols")]
+pub mod ubx;  // u-blox UBX frame checksum
+#[cfg(feature = "protocols")]
+pub mod nmea;  // NMEA 0183 sentence checksum
+#[cfg(feature = "protocols")]
+pub mod ihex;  // Intel HEX record checksum
+#[cfg(feature = "protocols")]
+pub mod srec;  // Motorola S-record checksum
+#[cfg(feature = "protocols")]
+pub mod modbus;  // Modbus ASCII frame codec
+#[cfg(feature = "protocols")]
+pub mod sensirion;  // Sensirion sensor word CRC
+#[cfg(feature = "protocols")]
+pub mod onewire;  // 1-Wire ROM code and scratchpad CRC checks
+#[cfg(feature = "protocols")]
+pub mod iso7816;  // ISO 7816-3 T=1 block EDC
+#[cfg(feature = "protocols")]
+pub mod vedirect;  // Victron VE.Direct text block checksum
+#[cfg(feature = "protocols")]
+pub mod dynamixel;  // Dynamixel Protocol 1.0 packet checksum
+#[cfg(feature = "protocols")]
+pub mod msp;  // MultiWii Serial Protocol v1 frame checksum
+#[cfg(feature = "protocols")]
+pub mod tfmini;  // Benewake TFmini LiDAR frame checksum and scanner
+#[cfg(feature = "protocols")]
+pub mod dht;  // DHT11/DHT22 sensor payload checksum
+#[cfg(feature = "protocols")]
+pub mod gameboy;  // Game Boy cartridge header checksum
+#[cfg(feature = "protocols")]
+pub mod zxtap;  // ZX Spectrum TAP block parity
+#[cfg(feature = "protocols")]
+pub mod uart;  // UART parity bits and 9-bit words
+#[cfg(feature = "checkdigit")]
+pub mod checkdigit;  // decimal check digits such as Luhn, Damm and Verhoeff, and ISO/IEC 7064
 
 // re-export to make the provided implementation types available to the user
+#[cfg(feature = "sum")]
 pub use crate::check8sum::Check8Sum;
+#[cfg(feature = "xor")]
 pub use crate::check8xor::Check8Xor;
-pub use crate::check8crc::Check8Crc;
+pub use crate::check8xorrotate::Check8XorRotate;
+#[cfg(feature = "crc")]
+pub use crate::check8crc::{Check8Crc, Check8CrcParams, crc8_combine};
+#[cfg(feature = "crc")]
+pub use crate::check8crcconst::Check8CrcConst;
+#[cfg(feature = "crc")]
+pub use crate::check8crcnibble::Check8CrcNibble;
+#[cfg(feature = "crc")]
+pub use crate::check8crcbitwise::Check8CrcBitwise;
+#[cfg(feature = "crc")]
+pub use crate::check8crcslice8::Check8CrcSlice8;
+pub use crate::check8lrc::Check8Lrc;
+pub use crate::check8bsd::Check8Bsd;
+pub use crate::check8parity::{Check8Parity, Parity};
+pub use crate::check8onescomplement::Check8OnesComplement;
+pub use crate::check8fletcher::Check8Fletcher;
+pub use crate::check8pearson::{Check8Pearson, PEARSON_TABLE};
+pub use crate::check7roland::Check7Roland;
+#[cfg(feature = "crc")]
+pub use crate::check8const::{const_crc8, const_crc8_add, const_crc8_with_params};
+#[cfg(feature = "sum")]
+pub use crate::check8const::const_sum8;
+#[cfg(feature = "xor")]
+pub use crate::check8const::const_xor8;
+pub use crate::check8finalise::Finalise;
+pub use crate::check8iter::{Check8IterExt, RunningChecksum};
+pub use crate::check8state::Check8State;
+pub use crate::check8error::Check8Error;
+pub use crate::checksum::Checksum;
+#[cfg(feature = "std")]
+pub use crate::check8io::copy_with_checksum;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub use crate::check8writer::ChecksumWriter;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub use crate::check8reader::ChecksumReader;
+#[cfg(feature = "embedded-io")]
+pub use crate::check8embeddedio::Check8IoError;
+#[cfg(feature = "tokio")]
+pub use crate::check8tokio::{AsyncChecksumReader, AsyncChecksumWriter, calculate_from_async_reader};
+#[cfg(feature = "rayon")]
+pub use crate::check8par::Check8Par;
+#[cfg(feature = "futures")]
+pub use crate::check8stream::{
+    Check8StreamExt, ChecksummedStream, TryChecksummedStream, calculate_from_stream, try_calculate_from_stream,
+};
+
+// the size of the stack buffer used by calculate_from_reader
+#[cfg(feature = "std")]
+pub(crate) const READ_BUFFER_SIZE: usize = 8192;
 
+///
+/// Check8 is the 8-bit checksum trait: a Checksum<u8> that can also be constructed from an
+/// initial value, checkpointed and fed partial bytes. The accumulate and calculate methods,
+/// from add to calculate_from_file, are those of Checksum.
 ///
 /// # Required Methods
 ///
 /// - new: Creates a new instance of the type.
-/// - get_accum: Retrieves the current value of the accumulator.
-/// - init: Initialises the accumulator with a given value and returns the initialised value.
-/// - add: Adds a given value to the accumulator using the appropriate algorithm and returns the updated value.
 ///
 /// # Provided Methods
 ///
-/// - calculate_from_byte_array:
-///     Processes a slice of bytes by adding each byte's value to the accumulator using the add method.
-///     Finally, it retrieves the accumulated value using get_accum.
-///     - **Parameter**: array - A byte slice to process.
-///     - **Returns**: The final accumulated value as an u8.
+/// - add_bits:
+///   Adds the low nbits bits of a value, most significant first, for messages whose length is not a
+///   multiple of 8 bits. Only bit-oriented algorithms (Check8Crc) support partial bytes; by default
+///   8 bits are added as a whole byte and anything else is an error.
+///   - **Parameter**: value - The bits to add, right-aligned.
+///   - **Parameter**: nbits - The number of bits to add, 0 to 8.
+///   - **Returns**: The updated accumulated value, or a Check8Error.
 ///
-/// - calculate_from_string:
-///     Converts a string to its byte representation and processes it using calculate_from_byte_array.
-///     - **Parameter**: string - A string whose byte representation is processed.
-///     - **Returns**: The final accumulated value as an u8.
+/// - snapshot:
+///   Captures the current accumulator as a Check8State so a long-running calculation can be checkpointed.
+///   - **Returns**: The captured state.
+///
+/// - restore:
+///   Resumes a calculation from a previously captured Check8State by initialising the accumulator with it.
+///   - **Parameter**: state - The state returned by snapshot on an instance of the same algorithm.
 ///
 /// # Examples
 ///
 /// Demonstrates use of the Check8 trait as a parameter to a function.
 /// ```
-///use crate::check8::{Check8, Check8Sum, Check8Xor};
+///use crate::check8::{Check8, Check8Fletcher, Check8Lrc};
 ///
 /// fn calculate_from_string_with_type_as_parameter(string: &str, checksum_type: &mut impl Check8) -> u8 {
 ///     checksum_type.calculate_from_string(string)
@@ -71,33 +336,35 @@ pub use crate::check8crc::Check8Crc;
 /// fn main()  {
 ///     let test_string = "hello";
 ///
-///     let mut sum_add = Check8Sum::new(0);
-///     let result_add = calculate_from_string_with_type_as_parameter(test_string, &mut sum_add);
-///     println!("{}, 8-bit Arithmetic Checksum: {:#04x}", test_string, result_add);
+///     let mut lrc = Check8Lrc::new(0);
+///     let result_lrc = calculate_from_string_with_type_as_parameter(test_string, &mut lrc);
+///     println!("{}, 8-bit LRC: {:#04x}", test_string, result_lrc);
 ///
-///     let mut sum_xor = Check8Xor::new(0);
-///     let result_xor = calculate_from_string_with_type_as_parameter(test_string, &mut sum_xor);
-///     println!("{}, 8-bit XOR Checksum: {:#04x}", test_string, result_xor);
-///     assert!(result_add != result_xor);
+///     let mut fletcher = Check8Fletcher::new(0);
+///     let result_fletcher = calculate_from_string_with_type_as_parameter(test_string, &mut fletcher);
+///     println!("{}, 8-bit Fletcher Checksum: {:#04x}", test_string, result_fletcher);
+///     assert!(result_lrc != result_fletcher);
 /// }
 ///```
 ///
+pub trait Check8: Checksum<u8> {
+    fn new(initial: u8) -> Self where Self: Sized;
 
-pub trait Check8 {
-    fn new(initial: u8) -> impl Check8;
-    fn get_accum(&self) -> u8;
-    fn init(&mut self, val: u8) -> u8;
-    fn add(&mut self, val: u8) -> u8;
-
-    fn calculate_from_byte_array(&mut self, array: &[u8]) -> u8 {
-        for val in array {
-            self.add(*val);
+    fn add_bits(&mut self, value: u8, nbits: u8) -> Result<u8, Check8Error> {
+        match nbits {
+            0 => Ok(self.get_accum()),
+            8 => Ok(self.add(value)),
+            1..8 => Err(Check8Error::Unsupported),
+            _ => Err(Check8Error::InvalidBitCount(nbits)),
         }
-        self.get_accum()
     }
 
-    fn calculate_from_string(&mut self, string: &str) -> u8 {
-        self.calculate_from_byte_array(string.as_bytes())
+    fn snapshot(&self) -> Check8State {
+        Check8State { accum: self.get_accum() }
+    }
+
+    fn restore(&mut self, state: &Check8State) {
+        self.init(state.accum);
     }
 }
 
@@ -139,13 +406,205 @@ mod tests {
         }
         expected_sum %= 256;
 
-        let mut sum_add = Check8Sum::new(0);
-        let result_add = calculate_from_string_with_type_as_parameter(test_string, &mut sum_add);
-        assert_eq!(result_add, expected_sum as u8);
+        // the LRC is the two's complement of the sum, and even parity is the xor
+        let mut lrc = Check8Lrc::new(0);
+        let result_lrc = calculate_from_string_with_type_as_parameter(test_string, &mut lrc);
+        assert_eq!(result_lrc, (expected_sum as u8).wrapping_neg());
+
+        let mut parity = Check8Parity::new(0);
+        let result_parity = calculate_from_string_with_type_as_parameter(test_string, &mut parity);
+        assert_eq!(result_parity, expected_xor);
+
+        #[cfg(feature = "sum")]
+        {
+            let mut sum_add = Check8Sum::new(0);
+            let result_add = calculate_from_string_with_type_as_parameter(test_string, &mut sum_add);
+            assert_eq!(result_add, expected_sum as u8);
+        }
+
+        #[cfg(feature = "xor")]
+        {
+            let mut sum_xor = Check8Xor::new(0);
+            let result_xor = calculate_from_string_with_type_as_parameter(test_string, &mut sum_xor);
+            assert_eq!(result_xor, expected_xor);
+        }
+    }
+
+    fn snapshot_and_restore_round_trip(whole: &mut impl Check8, first: &mut impl Check8, resumed: &mut impl Check8) {
+        let data = b"a multi-part message";
+        let expected = whole.calculate_from_byte_array(data);
+
+        first.calculate_from_byte_array(&data[..7]);
+        let state = first.snapshot();
+
+        resumed.restore(&state);
+        assert_eq!(resumed.snapshot(), state);
+        assert_eq!(resumed.calculate_from_byte_array(&data[7..]), expected);
+    }
+
+    #[test]
+    fn snapshot_and_restore_round_trip_for_all_types() {
+        #[cfg(feature = "sum")]
+        snapshot_and_restore_round_trip(&mut Check8Sum::new(0), &mut Check8Sum::new(0), &mut Check8Sum::new(0));
+        #[cfg(feature = "xor")]
+        snapshot_and_restore_round_trip(&mut Check8Xor::new(0), &mut Check8Xor::new(0), &mut Check8Xor::new(0));
+        #[cfg(feature = "crc")]
+        snapshot_and_restore_round_trip(&mut Check8Crc::new(0x07), &mut Check8Crc::new(0x07), &mut Check8Crc::new(0x07));
+        snapshot_and_restore_round_trip(&mut Check8Fletcher::new(0), &mut Check8Fletcher::new(0), &mut Check8Fletcher::new(0));
+    }
+
+    #[test]
+    fn add_integers_uses_requested_byte_order() {
+        // Fletcher's checksum depends on the order of the bytes
+        let mut crc = Check8Fletcher::new(0);
+        let mut expected = Check8Fletcher::new(0);
+
+        assert_eq!(crc.add_u16_le(0x0102), expected.calculate_from_byte_array(&[0x02, 0x01]));
+        assert_eq!(crc.add_u16_be(0x0102), expected.calculate_from_byte_array(&[0x01, 0x02]));
+        assert_eq!(crc.add_u32_le(0x01020304), expected.calculate_from_byte_array(&[0x04, 0x03, 0x02, 0x01]));
+        assert_eq!(crc.add_u32_be(0x01020304), expected.calculate_from_byte_array(&[0x01, 0x02, 0x03, 0x04]));
+        assert_eq!(
+            crc.add_u64_le(0x0102030405060708),
+            expected.calculate_from_byte_array(&[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01])
+        );
+        assert_eq!(
+            crc.add_u64_be(0x0102030405060708),
+            expected.calculate_from_byte_array(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08])
+        );
+    }
+
+    #[test]
+    fn calculate_from_slices_matches_concatenated_buffer() {
+        let header: &[u8] = &[0xAA, 0x55];
+        let payload: &[u8] = b"payload";
+        let footer: &[u8] = &[];
+        let concatenated = [header, payload, footer].concat();
 
-        let mut sum_xor = Check8Xor::new(0);
-        let result_xor = calculate_from_string_with_type_as_parameter(test_string, &mut sum_xor);
-        assert_eq!(result_xor, expected_xor);
+        let mut fletcher = Check8Fletcher::new(0);
+        let mut expected = Check8Fletcher::new(0);
+        assert_eq!(fletcher.calculate_from_slices(&[header, payload, footer]), expected.calculate_from_byte_array(&concatenated));
+
+        #[cfg(feature = "crc")]
+        {
+            let mut crc = Check8Crc::new(0x07);
+            let mut expected = Check8Crc::new(0x07);
+            assert_eq!(crc.calculate_from_slices(&[header, payload, footer]), expected.calculate_from_byte_array(&concatenated));
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn calculate_from_buf_walks_every_chunk() {
+        use bytes::Buf;
+        let expected = Check8Fletcher::new(0).calculate_from_string("123456789");
+        let mut chain = (&b"1234"[..]).chain(&b"56"[..]).chain(bytes::Bytes::from_static(b"789"));
+        assert_eq!(Check8Fletcher::new(0).calculate_from_buf(&mut chain), expected);
+        assert!(!chain.has_remaining());
+
+        let mut partly_read = bytes::Bytes::from_static(b"xx123456789");
+        partly_read.advance(2);
+        assert_eq!(Check8Fletcher::new(0).calculate_from_buf(&mut partly_read), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calculate_from_reader_matches_byte_array() {
+        let data: Vec<u8> = (0..20000u32).map(|i| (i * 7) as u8).collect();
+        let mut fletcher = Check8Fletcher::new(0);
+        let mut expected = Check8Fletcher::new(0);
+        assert_eq!(fletcher.calculate_from_reader(&data[..]).unwrap(), expected.calculate_from_byte_array(&data));
+        assert_eq!(Check8Lrc::new(0).calculate_from_reader(std::io::empty()).unwrap(), 0x00);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calculate_from_reader_retries_interrupted_reads() {
+        struct Flaky<'a>(&'a [u8], bool);
+
+        impl std::io::Read for Flaky<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.1 = !self.1;
+                if self.1 {
+                    return Err(std::io::ErrorKind::Interrupted.into());
+                }
+                self.0.read(buf)
+            }
+        }
+
+        let mut parity = Check8Parity::new(0);
+        assert_eq!(parity.calculate_from_reader(Flaky(b"hello", false)).unwrap(), Check8Parity::new(0).calculate_from_string("hello"));
+
+        // interrupted on the second call rather than the first
+        let mut lrc = Check8Lrc::new(0);
+        assert_eq!(lrc.calculate_from_reader(Flaky(b"hello", true)).unwrap(), Check8Lrc::new(0).calculate_from_string("hello"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calculate_from_reader_returns_other_errors() {
+        struct Broken;
+
+        impl std::io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+        }
+
+        let result = Check8Lrc::new(0).calculate_from_reader(Broken);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::BrokenPipe);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn calculate_from_reader_with_progress_reports_and_cancels() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let data = [0xA5u8; 3 * READ_BUFFER_SIZE];
+        let cancel = AtomicBool::new(false);
+        let mut reported = Vec::new();
+        let mut fletcher = Check8Fletcher::new(0);
+        let result = fletcher.calculate_from_reader_with_progress(&data[..], |n| reported.push(n), &cancel);
+        assert_eq!(result.unwrap(), Check8Fletcher::new(0).calculate_from_byte_array(&data));
+        assert_eq!(reported, [READ_BUFFER_SIZE as u64, 2 * READ_BUFFER_SIZE as u64, 3 * READ_BUFFER_SIZE as u64]);
+
+        // set during the second chunk, so the third is never added
+        let mut lrc = Check8Lrc::new(0);
+        let result = lrc.calculate_from_reader_with_progress(
+            &data[..],
+            |n| cancel.store(n >= 2 * READ_BUFFER_SIZE as u64, Ordering::Relaxed),
+            &cancel,
+        );
+        let error = result.unwrap_err();
+        assert_eq!(error.get_ref().and_then(|e| e.downcast_ref::<Check8Error>()), Some(&Check8Error::Cancelled));
+        assert_eq!(lrc.get_checksum(), Check8Lrc::new(0).calculate_from_byte_array(&data[..2 * READ_BUFFER_SIZE]))
+    }
+
+    #[test]
+    fn add_bits_default_accepts_whole_bytes_only() {
+        let mut lrc = Check8Lrc::new(0);
+        assert_eq!(lrc.add_bits(0x12, 8), Ok(0x12));
+        assert_eq!(lrc.add_bits(0x34, 0), Ok(0x12));
+        assert_eq!(lrc.add_bits(0x05, 3), Err(Check8Error::Unsupported));
+        assert_eq!(lrc.add_bits(0x05, 9), Err(Check8Error::InvalidBitCount(9)));
+        assert_eq!(lrc.get_accum(), 0x12);
+    }
+
+    // logging needs a defmt global logger, which only an embedded target provides, so this
+    // checks only that the types can be logged
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn types_implement_defmt_format() {
+        fn is_format<T: defmt::Format>() {}
+        is_format::<Check8State>();
+        is_format::<Check8Error>();
+        is_format::<Finalise>();
+        is_format::<Parity>();
+        #[cfg(feature = "crc")]
+        {
+            is_format::<Check8CrcParams>();
+            is_format::<presets::Check8CrcPreset>();
+            is_format::<check16::Check16CrcParams>();
+            is_format::<check32::Check32CrcParams>()
+        }
     }
-    
 }
@@ -0,0 +1,157 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # LIN
+//!
+//! Helpers for the checksums and protected identifiers of LIN bus frames.
+//!
+//! The LIN checksum is the inverted 8-bit sum with carry (each carry out of the top bit is
+//! added back in), as computed by Check8OnesComplement. The classic checksum of LIN 1.x covers
+//! the data bytes only; the enhanced checksum of LIN 2.x also covers the protected identifier,
+//! except for the diagnostic frames 0x3C and 0x3D which always use the classic checksum.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8OnesComplement;
+
+/// Calculates the protected identifier (PID) of a frame identifier: the 6-bit identifier with
+/// parity bit P0 in bit 6 and the inverted parity bit P1 in bit 7.
+///
+/// - **Parameter**: id - The frame identifier, 0 to 63.
+/// - **Returns**: The protected identifier.
+pub fn pid(id: u8) -> u8 {
+    let id = id & 0x3F;
+    let bit = |n: u8| (id >> n) & 0x01;
+    let p0 = bit(0) ^ bit(1) ^ bit(2) ^ bit(4);
+    let p1 = (bit(1) ^ bit(3) ^ bit(4) ^ bit(5)) ^ 0x01;
+    id | (p0 << 6) | (p1 << 7)
+}
+
+/// Checks the parity bits of a protected identifier and extracts the frame identifier.
+///
+/// - **Parameter**: pid - The protected identifier.
+/// - **Returns**: The frame identifier, or None if the parity bits are wrong.
+pub fn id_from_pid(pid: u8) -> Option<u8> {
+    let id = pid & 0x3F;
+    (self::pid(id) == pid).then_some(id)
+}
+
+/// Calculates the classic checksum, over the data bytes only.
+///
+/// - **Parameter**: data - The data bytes of the frame.
+/// - **Returns**: The checksum byte.
+pub fn classic_checksum(data: &[u8]) -> u8 {
+    Check8OnesComplement::with_complement(0x00, true).calculate_from_byte_array(data)
+}
+
+/// Calculates the enhanced checksum, over the protected identifier and the data bytes.
+///
+/// - **Parameter**: pid - The protected identifier.
+/// - **Parameter**: data - The data bytes of the frame.
+/// - **Returns**: The checksum byte.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::lin::{enhanced_checksum, pid};
+/// fn main() {
+///     // the example from the LIN 2.2A specification
+///     assert_eq!(enhanced_checksum(0x4A, &[0x55, 0x93, 0xE5]), 0xE6);
+///     // and for frame identifier 0x10
+///     assert_eq!(pid(0x10), 0x50);
+///     assert_eq!(enhanced_checksum(pid(0x10), &[0x01, 0x02]), 0xAC);
+/// }
+/// ```
+///
+pub fn enhanced_checksum(pid: u8, data: &[u8]) -> u8 {
+    let mut sum = Check8OnesComplement::with_complement(pid, true);
+    sum.calculate_from_byte_array(data)
+}
+
+/// Calculates the LIN 2.x checksum of a frame: classic for the diagnostic frames 0x3C and
+/// 0x3D, enhanced for all others.
+///
+/// - **Parameter**: pid - The protected identifier.
+/// - **Parameter**: data - The data bytes of the frame.
+/// - **Returns**: The checksum byte.
+pub fn frame_checksum(pid: u8, data: &[u8]) -> u8 {
+    match pid & 0x3F {
+        0x3C | 0x3D => classic_checksum(data),
+        _ => enhanced_checksum(pid, data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Check8;
+
+    #[test]
+    fn specification_example() {
+        assert_eq!(enhanced_checksum(0x4A, &[0x55, 0x93, 0xE5]), 0xE6)
+    }
+
+    #[test]
+    fn protected_identifiers() {
+        assert_eq!(pid(0x00), 0x80);
+        assert_eq!(pid(0x10), 0x50);
+        assert_eq!(pid(0x3C), 0x3C);
+        assert_eq!(pid(0x3D), 0x7D);
+        assert_eq!(pid(0x3F), 0xBF)
+    }
+
+    #[test]
+    fn id_from_pid_checks_parity() {
+        for id in 0..64 {
+            assert_eq!(id_from_pid(pid(id)), Some(id));
+            assert_eq!(id_from_pid(pid(id) ^ 0x40), None);
+            assert_eq!(id_from_pid(pid(id) ^ 0x80), None)
+        }
+    }
+
+    #[test]
+    fn classic_checksum_wraps_carries() {
+        // 0xFF + 0x02 = 0x101, the carry is added back to give 0x02, inverted 0xFD
+        assert_eq!(classic_checksum(&[0xFF, 0x02]), 0xFD);
+        assert_eq!(classic_checksum(&[]), 0xFF)
+    }
+
+    #[test]
+    fn frame_and_checksum_sum_to_0xff() {
+        let data = [0x12, 0xF0, 0xAB, 0xCD, 0x00, 0x99, 0x77, 0xFE];
+        let checksum = enhanced_checksum(pid(0x21), &data);
+        let mut sum = Check8OnesComplement::new(pid(0x21));
+        sum.calculate_from_byte_array(&data);
+        assert_eq!(sum.add(checksum), 0xFF)
+    }
+
+    #[test]
+    fn diagnostic_frames_use_classic_checksum() {
+        let data = [0x7F, 0x06, 0xB2, 0x00, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(frame_checksum(pid(0x3C), &data), classic_checksum(&data));
+        assert_eq!(frame_checksum(pid(0x3D), &data), classic_checksum(&data));
+        assert_eq!(frame_checksum(pid(0x21), &data), enhanced_checksum(pid(0x21), &data))
+    }
+}
@@ -0,0 +1,219 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Modbus ASCII
+//!
+//! A codec for Modbus ASCII frames. A frame is a ':', the address, function code and data
+//! followed by their LRC, all as pairs of hexadecimal digits, and a CR LF. The LRC is
+//! calculated over the raw bytes, not over the hexadecimal characters, as computed by Check8Lrc.
+//!
+//! verify_ascii_frame checks a frame without decoding it into a buffer, so it needs no
+//! allocator; the encoder and decoder need the alloc feature.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Lrc;
+use crate::hex;
+
+/// # Variants
+///
+/// - MissingStart: The frame does not start with ':'.
+/// - MissingEnd: The frame does not end with CR LF.
+/// - InvalidHex: The frame contains a character that is not a hexadecimal digit, or an odd number of them.
+/// - TooShort: The frame has no address and function code before the LRC.
+/// - LrcMismatch: The LRC in the frame does not match the LRC calculated from its bytes.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ModbusAsciiError {
+    MissingStart,
+    MissingEnd,
+    InvalidHex,
+    TooShort,
+    LrcMismatch { calculated: u8, received: u8 },
+}
+
+impl fmt::Display for ModbusAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModbusAsciiError::MissingStart => write!(f, "Modbus ASCII frame does not start with ':'"),
+            ModbusAsciiError::MissingEnd => write!(f, "Modbus ASCII frame does not end with CR LF"),
+            ModbusAsciiError::InvalidHex => write!(f, "Modbus ASCII frame contains invalid hexadecimal digits"),
+            ModbusAsciiError::TooShort => write!(f, "Modbus ASCII frame is too short"),
+            ModbusAsciiError::LrcMismatch { calculated, received } => {
+                write!(f, "Modbus ASCII LRC mismatch: calculated {:02X}, received {:02X}", calculated, received)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ModbusAsciiError {}
+
+/// Encodes a message as a Modbus ASCII frame.
+///
+/// - **Parameter**: message - The address, function code and data bytes.
+/// - **Returns**: The frame, from the ':' to the CR LF.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::modbus::{decode_ascii_frame, encode_ascii_frame};
+/// fn main() {
+///     // read 10 holding registers from 0x1389 of slave 0xF7
+///     let frame = encode_ascii_frame(&[0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A]);
+///     assert_eq!(frame, ":F7031389000A60\r\n");
+///     assert_eq!(decode_ascii_frame(&frame).unwrap(), vec![0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A]);
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn encode_ascii_frame(message: &[u8]) -> String {
+    let mut frame = String::with_capacity(2 * message.len() + 5);
+    frame.push(':');
+    hex::encode_into(message, &mut frame);
+    hex::encode_into(&[Check8Lrc::new(0x00).calculate_from_byte_array(message)], &mut frame);
+    frame.push_str("\r\n");
+    frame
+}
+
+/// Decodes and validates a Modbus ASCII frame.
+///
+/// - **Parameter**: frame - The frame, from the ':' to the CR LF. Lower case hexadecimal digits are accepted.
+/// - **Returns**: The address, function code and data bytes, without the LRC, or a ModbusAsciiError.
+#[cfg(feature = "alloc")]
+pub fn decode_ascii_frame(frame: &str) -> Result<Vec<u8>, ModbusAsciiError> {
+    let mut bytes = hex::decode(frame_digits(frame)?).ok_or(ModbusAsciiError::InvalidHex)?;
+    let received = match bytes.pop() {
+        Some(received) if bytes.len() >= 2 => received,
+        _ => return Err(ModbusAsciiError::TooShort),
+    };
+    let calculated = Check8Lrc::new(0x00).calculate_from_byte_array(&bytes);
+    if calculated != received {
+        return Err(ModbusAsciiError::LrcMismatch { calculated, received });
+    }
+    Ok(bytes)
+}
+
+/// Validates a Modbus ASCII frame, decoding its digits one pair at a time.
+///
+/// - **Parameter**: frame - The frame, from the ':' to the CR LF. Lower case hexadecimal digits are accepted.
+/// - **Returns**: Ok if the LRC matches, or the ModbusAsciiError decode_ascii_frame would return.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::modbus::{verify_ascii_frame, ModbusAsciiError};
+/// fn main() {
+///     assert_eq!(verify_ascii_frame(":F7031389000A60\r\n"), Ok(()));
+///     assert_eq!(verify_ascii_frame(":F7031389000A61\r\n"),
+///                Err(ModbusAsciiError::LrcMismatch { calculated: 0x60, received: 0x61 }));
+/// }
+/// ```
+///
+pub fn verify_ascii_frame(frame: &str) -> Result<(), ModbusAsciiError> {
+    let (pairs, rest) = frame_digits(frame)?.as_bytes().as_chunks::<2>();
+    if !rest.is_empty() {
+        return Err(ModbusAsciiError::InvalidHex);
+    }
+    // every pair is added to the LRC once the next one shows it was not the last
+    let mut lrc = Check8Lrc::new(0x00);
+    let mut last = None;
+    for pair in pairs {
+        let byte = hex::decode_pair(*pair).ok_or(ModbusAsciiError::InvalidHex)?;
+        if let Some(previous) = last.replace(byte) {
+            lrc.add(previous);
+        }
+    }
+    let received = match last {
+        Some(received) if pairs.len() >= 3 => received,
+        _ => return Err(ModbusAsciiError::TooShort),
+    };
+    let calculated = lrc.get_checksum();
+    if calculated != received {
+        return Err(ModbusAsciiError::LrcMismatch { calculated, received });
+    }
+    Ok(())
+}
+
+// strips the ':' and CR LF from a frame, leaving its hexadecimal digits
+fn frame_digits(frame: &str) -> Result<&str, ModbusAsciiError> {
+    let frame = frame.strip_prefix(':').ok_or(ModbusAsciiError::MissingStart)?;
+    frame.strip_suffix("\r\n").ok_or(ModbusAsciiError::MissingEnd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn known_frames() {
+        assert_eq!(encode_ascii_frame(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x01]), ":010300000001FB\r\n");
+        assert_eq!(decode_ascii_frame(":010300000001FB\r\n").unwrap(), vec![0x01, 0x03, 0x00, 0x00, 0x00, 0x01])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lrc_is_over_bytes_not_characters() {
+        // the LRC of the characters "F7031389000A" would be quite different
+        let frame = encode_ascii_frame(&[0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A]);
+        assert!(frame.ends_with("60\r\n"))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn lower_case_accepted() {
+        assert_eq!(decode_ascii_frame(":f7031389000a60\r\n").unwrap(), vec![0xF7, 0x03, 0x13, 0x89, 0x00, 0x0A])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_reports_faults() {
+        assert_eq!(decode_ascii_frame("010300000001FB\r\n"), Err(ModbusAsciiError::MissingStart));
+        assert_eq!(decode_ascii_frame(":010300000001FB"), Err(ModbusAsciiError::MissingEnd));
+        assert_eq!(decode_ascii_frame(":010300000001F\r\n"), Err(ModbusAsciiError::InvalidHex));
+        assert_eq!(decode_ascii_frame(":01FF\r\n"), Err(ModbusAsciiError::TooShort));
+        assert_eq!(decode_ascii_frame(":010300000001FC\r\n"), Err(ModbusAsciiError::LrcMismatch { calculated: 0xFB, received: 0xFC }))
+    }
+
+    #[test]
+    fn verify_reports_the_same_faults_as_decode() {
+        assert_eq!(verify_ascii_frame(":010300000001FB\r\n"), Ok(()));
+        assert_eq!(verify_ascii_frame(":f7031389000a60\r\n"), Ok(()));
+        assert_eq!(verify_ascii_frame("010300000001FB\r\n"), Err(ModbusAsciiError::MissingStart));
+        assert_eq!(verify_ascii_frame(":010300000001FB"), Err(ModbusAsciiError::MissingEnd));
+        assert_eq!(verify_ascii_frame(":010300000001F\r\n"), Err(ModbusAsciiError::InvalidHex));
+        assert_eq!(verify_ascii_frame(":0G\r\n"), Err(ModbusAsciiError::InvalidHex));
+        assert_eq!(verify_ascii_frame(":01FF\r\n"), Err(ModbusAsciiError::TooShort));
+        assert_eq!(verify_ascii_frame(":\r\n"), Err(ModbusAsciiError::TooShort));
+        assert_eq!(verify_ascii_frame(":010300000001FC\r\n"), Err(ModbusAsciiError::LrcMismatch { calculated: 0xFB, received: 0xFC }))
+    }
+}
@@ -0,0 +1,223 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # MSP
+//!
+//! Helpers for the checksum of MultiWii Serial Protocol (MSP) version 1 frames, as used by
+//! MultiWii, Cleanflight, Betaflight and INAV. A frame is the preamble "$M", a direction
+//! character, the payload size, the command, the payload and a checksum: the XOR of the
+//! size, command and payload bytes, as computed by Check8Xor.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Xor;
+
+/// # Variants
+///
+/// - ToFc: A request to the flight controller, '<'.
+/// - FromFc: A response from the flight controller, '>'.
+/// - Error: An error response from the flight controller, '!'.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MspDirection {
+    ToFc,
+    FromFc,
+    Error,
+}
+
+impl MspDirection {
+    /// The character of the direction in the frame preamble.
+    pub const fn as_byte(self) -> u8 {
+        match self {
+            MspDirection::ToFc => b'<',
+            MspDirection::FromFc => b'>',
+            MspDirection::Error => b'!',
+        }
+    }
+
+    /// The direction of a preamble character, or None if it is not one.
+    pub const fn from_byte(val: u8) -> Option<Self> {
+        match val {
+            b'<' => Some(MspDirection::ToFc),
+            b'>' => Some(MspDirection::FromFc),
+            b'!' => Some(MspDirection::Error),
+            _ => None,
+        }
+    }
+}
+
+/// # Variants
+///
+/// - TooShort: The frame is shorter than its preamble, size, command and checksum.
+/// - TooLong: The payload is longer than 255 bytes.
+/// - BadPreamble: The frame does not start with "$M" and a direction character.
+/// - LengthMismatch: The size byte does not match the number of bytes in the frame.
+/// - BadChecksum: The checksum does not match the frame.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MspError {
+    TooShort,
+    TooLong,
+    BadPreamble,
+    LengthMismatch,
+    BadChecksum,
+}
+
+impl fmt::Display for MspError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MspError::TooShort => write!(f, "MSP frame is too short"),
+            MspError::TooLong => write!(f, "MSP payload is too long"),
+            MspError::BadPreamble => write!(f, "MSP frame does not start with a preamble"),
+            MspError::LengthMismatch => write!(f, "MSP size does not match the frame"),
+            MspError::BadChecksum => write!(f, "MSP frame checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for MspError {}
+
+/// The fields of a validated frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MspFrame<'a> {
+    pub direction: MspDirection,
+    pub command: u8,
+    pub payload: &'a [u8],
+}
+
+/// Calculates the checksum of a frame from its command and payload.
+///
+/// - **Parameter**: command - The command, e.g. 101 for MSP_STATUS.
+/// - **Parameter**: payload - The payload, at most 255 bytes.
+/// - **Returns**: The checksum byte.
+pub fn checksum(command: u8, payload: &[u8]) -> u8 {
+    let mut xor = Check8Xor::new(payload.len() as u8);
+    xor.add(command);
+    xor.calculate_from_byte_array(payload)
+}
+
+/// Builds a complete frame.
+///
+/// - **Parameter**: direction - The direction of the frame.
+/// - **Parameter**: command - The command.
+/// - **Parameter**: payload - The payload, at most 255 bytes.
+/// - **Returns**: The frame, or MspError::TooLong.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::msp::{build_frame, parse_frame, MspDirection};
+/// fn main() {
+///     // MSP_STATUS request
+///     let frame = build_frame(MspDirection::ToFc, 101, &[]).unwrap();
+///     assert_eq!(frame, b"$M<\x00\x65\x65".to_vec());
+///     assert_eq!(parse_frame(&frame).unwrap().command, 101);
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn build_frame(direction: MspDirection, command: u8, payload: &[u8]) -> Result<Vec<u8>, MspError> {
+    if payload.len() > 255 {
+        return Err(MspError::TooLong);
+    }
+    let mut frame = Vec::with_capacity(payload.len() + 6);
+    frame.extend_from_slice(&[b'$', b'M', direction.as_byte(), payload.len() as u8, command]);
+    frame.extend_from_slice(payload);
+    frame.push(checksum(command, payload));
+    Ok(frame)
+}
+
+/// Validates a complete frame and extracts its fields.
+///
+/// - **Parameter**: frame - The frame, from the '$' to the checksum.
+/// - **Returns**: The fields of the frame, or an MspError describing the fault.
+pub fn parse_frame(frame: &[u8]) -> Result<MspFrame<'_>, MspError> {
+    if frame.len() < 6 {
+        return Err(MspError::TooShort);
+    }
+    let direction = match frame[..2] {
+        [b'$', b'M'] => MspDirection::from_byte(frame[2]).ok_or(MspError::BadPreamble)?,
+        _ => return Err(MspError::BadPreamble),
+    };
+    let size = frame[3] as usize;
+    if frame.len() != size + 6 {
+        return Err(MspError::LengthMismatch);
+    }
+    let command = frame[4];
+    let payload = &frame[5..5 + size];
+    if checksum(command, payload) != frame[5 + size] {
+        return Err(MspError::BadChecksum);
+    }
+    Ok(MspFrame { direction, command, payload })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn known_requests() {
+        assert_eq!(build_frame(MspDirection::ToFc, 1, &[]).unwrap(), vec![0x24, 0x4D, 0x3C, 0x00, 0x01, 0x01]);  // MSP_API_VERSION
+        assert_eq!(build_frame(MspDirection::ToFc, 108, &[]).unwrap(), vec![0x24, 0x4D, 0x3C, 0x00, 0x6C, 0x6C])  // MSP_ATTITUDE
+    }
+
+    #[test]
+    fn checksum_covers_size_command_and_payload() {
+        assert_eq!(checksum(200, &[0xDC, 0x05, 0xDC, 0x05]), 4 ^ 200 ^ 0xDC ^ 0x05 ^ 0xDC ^ 0x05)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_round_trips() {
+        let payload = [0x00, 0x01, 0x2A];
+        let frame = build_frame(MspDirection::FromFc, 1, &payload).unwrap();
+        assert_eq!(parse_frame(&frame), Ok(MspFrame { direction: MspDirection::FromFc, command: 1, payload: &payload }))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parse_reports_faults() {
+        let frame = build_frame(MspDirection::FromFc, 105, &[0xE8, 0x03, 0xDC, 0x05]).unwrap();
+        let mut corrupted = frame.clone();
+        corrupted[6] ^= 0x01;
+        assert_eq!(parse_frame(&corrupted), Err(MspError::BadChecksum));
+        let mut corrupted = frame.clone();
+        corrupted[2] = b'?';
+        assert_eq!(parse_frame(&corrupted), Err(MspError::BadPreamble));
+        assert_eq!(parse_frame(&frame[..frame.len() - 1]), Err(MspError::LengthMismatch));
+        assert_eq!(parse_frame(b"$M<\x00\x01"), Err(MspError::TooShort));
+        assert_eq!(build_frame(MspDirection::ToFc, 1, &[0u8; 256]), Err(MspError::TooLong))
+    }
+}
@@ -0,0 +1,179 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # NMEA
+//!
+//! Helpers for the checksum of NMEA 0183 sentences. The checksum is the XOR of every character
+//! between the start delimiter ('$', or '!' for encapsulated sentences such as AIS) and the '*',
+//! written after the '*' as two hexadecimal digits.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Xor;
+
+/// # Variants
+///
+/// - MissingStart: The sentence does not start with '$' or '!'.
+/// - MissingChecksum: The sentence has no '*' followed by two characters.
+/// - InvalidHex: The characters after the '*' are not two hexadecimal digits.
+/// - BadChecksum: The checksum in the sentence does not match the checksum of its body.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum NmeaError {
+    MissingStart,
+    MissingChecksum,
+    InvalidHex,
+    BadChecksum,
+}
+
+impl fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NmeaError::MissingStart => write!(f, "NMEA sentence does not start with '$' or '!'"),
+            NmeaError::MissingChecksum => write!(f, "NMEA sentence has no checksum"),
+            NmeaError::InvalidHex => write!(f, "NMEA checksum is not two hexadecimal digits"),
+            NmeaError::BadChecksum => write!(f, "NMEA checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for NmeaError {}
+
+// the part of a sentence covered by the checksum: without the start delimiter, and up to any '*'
+fn body_of(sentence: &str) -> &str {
+    let body = sentence.strip_prefix(['$', '!']).unwrap_or(sentence);
+    match body.find('*') {
+        Some(end) => &body[..end],
+        None => body,
+    }
+}
+
+/// Calculates the checksum of a sentence body.
+///
+/// - **Parameter**: body - The sentence body, e.g. "GPGGA,...". A leading '$' or '!' and anything
+///   from a '*' onwards are ignored, so a whole sentence can also be given.
+/// - **Returns**: The checksum.
+pub fn compute(body: &str) -> u8 {
+    Check8Xor::new(0x00).calculate_from_string(body_of(body))
+}
+
+/// Verifies the checksum of a complete sentence, which may end with "\r\n".
+///
+/// - **Parameter**: sentence - The sentence, e.g. "$GPGGA,...*47".
+/// - **Returns**: Ok if the checksum matches, or an NmeaError describing the fault.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::nmea;
+/// fn main() {
+///     let sentence = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+///     assert_eq!(nmea::verify(sentence), Ok(()));
+///     assert_eq!(nmea::compute(sentence), 0x47);
+///     assert_eq!(nmea::append("GPGLL,4916.45,N,12311.12,W,225444,A"), "$GPGLL,4916.45,N,12311.12,W,225444,A*31");
+/// }
+/// ```
+///
+pub fn verify(sentence: &str) -> Result<(), NmeaError> {
+    let sentence = sentence.trim_end_matches(['\r', '\n']);
+    let body = sentence.strip_prefix(['$', '!']).ok_or(NmeaError::MissingStart)?;
+    let (body, hex) = body.rsplit_once('*').ok_or(NmeaError::MissingChecksum)?;
+    if hex.len() != 2 {
+        return Err(NmeaError::MissingChecksum);
+    }
+    if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(NmeaError::InvalidHex);
+    }
+    let received = u8::from_str_radix(hex, 16).map_err(|_| NmeaError::InvalidHex)?;
+    if compute(body) != received {
+        return Err(NmeaError::BadChecksum);
+    }
+    Ok(())
+}
+
+/// Formats a sentence from its body, adding the start delimiter if it is missing and the checksum.
+///
+/// - **Parameter**: body - The sentence body, e.g. "GPGLL,...", or "!AIVDM,..." with its delimiter.
+/// - **Returns**: The sentence with "*hh" appended in upper case, without a line ending.
+#[cfg(feature = "alloc")]
+pub fn append(body: &str) -> String {
+    let delimiter = if body.starts_with('!') { '!' } else { '$' };
+    let body = body_of(body);
+    format!("{}{}*{:02X}", delimiter, body, compute(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_sentences() {
+        assert_eq!(verify("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47"), Ok(()));
+        assert_eq!(verify("$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A"), Ok(()));
+        assert_eq!(verify("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C"), Ok(()))
+    }
+
+    #[test]
+    fn lower_case_hex_and_line_endings() {
+        assert_eq!(verify("$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6a\r\n"), Ok(()))
+    }
+
+    #[test]
+    fn compute_ignores_delimiters() {
+        assert_eq!(compute("GPGLL,4916.45,N,12311.12,W,225444,A"), 0x31);
+        assert_eq!(compute("$GPGLL,4916.45,N,12311.12,W,225444,A"), 0x31);
+        assert_eq!(compute("$GPGLL,4916.45,N,12311.12,W,225444,A*31"), 0x31)
+    }
+
+    #[test]
+    fn verify_reports_faults() {
+        assert_eq!(verify("GPGLL,4916.45,N,12311.12,W,225444,A*31"), Err(NmeaError::MissingStart));
+        assert_eq!(verify("$GPGLL,4916.45,N,12311.12,W,225444,A"), Err(NmeaError::MissingChecksum));
+        assert_eq!(verify("$GPGLL,4916.45,N,12311.12,W,225444,A*3"), Err(NmeaError::MissingChecksum));
+        assert_eq!(verify("$GPGLL,4916.45,N,12311.12,W,225444,A*G1"), Err(NmeaError::InvalidHex));
+        assert_eq!(verify("$GPGLL,4916.45,N,12311.12,W,225444,A*32"), Err(NmeaError::BadChecksum))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn append_round_trips() {
+        let sentence = append("GPGLL,4916.45,N,12311.12,W,225444,A");
+        assert_eq!(sentence, "$GPGLL,4916.45,N,12311.12,W,225444,A*31");
+        assert_eq!(verify(&sentence), Ok(()));
+        // checksums below 0x10 keep their leading zero
+        let sentence = append("A");
+        assert_eq!(sentence, "$A*41");
+        assert_eq!(append("AA"), "$AA*00");
+        // an encapsulation delimiter is kept
+        assert_eq!(append("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0"), "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C")
+    }
+}
@@ -0,0 +1,153 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # 1-Wire
+//!
+//! Helpers for verifying the CRC-8/MAXIM-DOW of Dallas/Maxim 1-Wire devices: the 8-byte ROM
+//! code (family code, 48-bit serial number, CRC) and the 9-byte scratchpad of DS18B20-class
+//! temperature sensors (8 bytes and a CRC). Running the CRC over the whole ROM code or
+//! scratchpad, including its CRC byte, leaves zero.
+
+use core::fmt;
+
+use crate::Checksum;        // for the Checksum trait
+use crate::presets::crc8_maxim;
+
+/// # Variants
+///
+/// - RomCrc: The CRC of the ROM code does not match.
+/// - ScratchpadCrc: The CRC of the scratchpad does not match.
+/// - NoDevice: Every byte read was zero, which passes the CRC but usually means the bus is held low.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OneWireError {
+    RomCrc,
+    ScratchpadCrc,
+    NoDevice,
+}
+
+impl fmt::Display for OneWireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OneWireError::RomCrc => write!(f, "1-Wire ROM code CRC mismatch"),
+            OneWireError::ScratchpadCrc => write!(f, "1-Wire scratchpad CRC mismatch"),
+            OneWireError::NoDevice => write!(f, "1-Wire read returned all zeros"),
+        }
+    }
+}
+
+impl core::error::Error for OneWireError {}
+
+/// Calculates the CRC byte of a ROM code from its family code and serial number.
+pub fn rom_crc(rom: &[u8; 7]) -> u8 {
+    crc8_maxim().calculate_from_byte_array(rom)
+}
+
+/// Verifies the CRC of a ROM code, as read by Read ROM or found by Search ROM.
+///
+/// - **Parameter**: rom - The ROM code, family code first and CRC last.
+/// - **Returns**: Ok if the ROM code is intact, or a OneWireError.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::onewire::{verify_rom, OneWireError};
+/// fn main() {
+///     // the ROM code from Maxim application note 27
+///     assert_eq!(verify_rom(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2]), Ok(()));
+///     assert_eq!(verify_rom(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA3]), Err(OneWireError::RomCrc));
+/// }
+/// ```
+///
+pub fn verify_rom(rom: &[u8; 8]) -> Result<(), OneWireError> {
+    if rom.iter().all(|val| *val == 0x00) {
+        return Err(OneWireError::NoDevice);
+    }
+    if crc8_maxim().calculate_from_byte_array(rom) != 0x00 {
+        return Err(OneWireError::RomCrc);
+    }
+    Ok(())
+}
+
+/// Verifies the CRC of a scratchpad, as read by Read Scratchpad from a DS18B20-class device.
+///
+/// - **Parameter**: scratchpad - The nine bytes read, CRC last.
+/// - **Returns**: Ok if the scratchpad is intact, or a OneWireError.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::onewire::verify_scratchpad;
+/// fn main() {
+///     // a DS18B20 at its power-on value of 85 degrees C
+///     assert_eq!(verify_scratchpad(&[0x50, 0x05, 0x4B, 0x46, 0x7F, 0xFF, 0x0C, 0x10, 0x1C]), Ok(()));
+/// }
+/// ```
+///
+pub fn verify_scratchpad(scratchpad: &[u8; 9]) -> Result<(), OneWireError> {
+    if scratchpad.iter().all(|val| *val == 0x00) {
+        return Err(OneWireError::NoDevice);
+    }
+    if crc8_maxim().calculate_from_byte_array(scratchpad) != 0x00 {
+        return Err(OneWireError::ScratchpadCrc);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rom_crc_completes_a_rom_code() {
+        let serial = [0x28, 0xFF, 0x4A, 0x1B, 0x60, 0x17, 0x04];
+        let crc = rom_crc(&serial);
+        let mut rom = [0u8; 8];
+        rom[..7].copy_from_slice(&serial);
+        rom[7] = crc;
+        assert_eq!(verify_rom(&rom), Ok(()))
+    }
+
+    #[test]
+    fn corrupted_reads_are_distinguished() {
+        let mut rom = [0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2];
+        rom[3] ^= 0x20;
+        assert_eq!(verify_rom(&rom), Err(OneWireError::RomCrc));
+
+        let mut scratchpad = [0x50, 0x05, 0x4B, 0x46, 0x7F, 0xFF, 0x0C, 0x10, 0x1C];
+        scratchpad[0] ^= 0x01;
+        assert_eq!(verify_scratchpad(&scratchpad), Err(OneWireError::ScratchpadCrc))
+    }
+
+    #[test]
+    fn all_zero_reads_are_rejected() {
+        assert_eq!(verify_rom(&[0u8; 8]), Err(OneWireError::NoDevice));
+        assert_eq!(verify_scratchpad(&[0u8; 9]), Err(OneWireError::NoDevice));
+        // an idle bus reads all ones, which fails the CRC
+        assert_eq!(verify_rom(&[0xFF; 8]), Err(OneWireError::RomCrc))
+    }
+}
@@ -0,0 +1,788 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Presets
+//!
+//! Named CRC algorithms from the catalogue of parametrised CRC algorithms, each with the
+//! parameters for Check8Crc and its check value: the CRC of the ASCII string "123456789".
+//! Each preset is available as a constant, and as a function returning a ready-made Check8Crc.
+//! The functions copy a Check8Crc built at compile time, so they do not generate the lookup
+//! table at runtime and are cheap enough to call for every packet.
+//! All of the presets are listed in PRESETS, and can be looked up by name with by_name.
+//!
+//! Presets also record their error detection strength as Hamming distance (HD) ranges: a CRC
+//! with HD d detects every error of fewer than d bits. The Koopman presets are the 8-bit
+//! polynomials with the best HD at common message lengths, from Koopman and Chakravarty,
+//! "Cyclic Redundancy Code (CRC) Polynomial Selection for Embedded Networks" (DSN 2004).
+
+use crate::{Check8Crc, Check8CrcParams};
+
+/// The longest data word, in bits (excluding the CRC), for which a CRC has Hamming distance hd.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Check8HdRange {
+    pub hd: u8,
+    pub max_data_bits: u32,
+}
+
+/// A named set of CRC parameters, the check value it must produce, and its Hamming distance
+/// ranges in decreasing order of HD. Ranges below HD 3 are not listed.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::Checksum;
+/// use crate::check8::presets::CRC7_MMC;
+/// fn main() {
+///     let mut crc = CRC7_MMC.crc();
+///     assert_eq!(crc.calculate_from_string("123456789"), CRC7_MMC.check);
+/// }
+/// ```
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Check8CrcPreset {
+    pub name: &'static str,
+    pub params: Check8CrcParams,
+    pub check: u8,
+    pub hamming: &'static [Check8HdRange],
+}
+
+impl Check8CrcPreset {
+    /// Creates a Check8Crc for the preset. This is a const fn, so the lookup table can be
+    /// generated at compile time by using it in a constant, as the preset functions do.
+    pub const fn crc(&self) -> Check8Crc {
+        Check8Crc::with_params(self.params)
+    }
+
+    /// Retrieves the Hamming distance the preset guarantees for a data word of the given
+    /// length in bits, or None if the length is beyond the listed ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::check8::presets::KOOPMAN_97;
+    /// fn main() {
+    ///     // a 12-byte message is protected with HD 4: all 1, 2 and 3-bit errors are detected
+    ///     assert_eq!(KOOPMAN_97.hd_for_data_bits(12 * 8), Some(4));
+    ///     assert_eq!(KOOPMAN_97.hd_for_data_bits(16 * 8), None);
+    /// }
+    /// ```
+    ///
+    pub fn hd_for_data_bits(&self, bits: u32) -> Option<u8> {
+        self.hamming.iter().find(|range| bits <= range.max_data_bits).map(|range| range.hd)
+    }
+}
+
+/// CRC-7/MMC, the command and data CRC of SD and MMC cards.
+pub const CRC7_MMC: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-7/MMC",
+    params: Check8CrcParams { width: 7, poly: 0x09, init: 0x00, refin: false, refout: false, xorout: 0x00 },
+    check: 0x75,
+    hamming: &[Check8HdRange { hd: 3, max_data_bits: 120 }],
+};
+
+/// Creates a Check8Crc for CRC-7/MMC.
+pub fn crc7_mmc() -> Check8Crc {
+    const CRC: Check8Crc = CRC7_MMC.crc();
+    CRC
+}
+
+/// CRC-5/USB, the CRC of USB token packets.
+///
+/// The result is in the reflected form that occupies the top five bits of the little-endian
+/// token field. The 11-bit address and endpoint field of a token can be added with add_bits.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::{Check8, Checksum};
+/// use crate::check8::presets::crc5_usb;
+/// fn main() {
+///     // SETUP token, address 0x15, endpoint 0xE
+///     let field: u16 = 0x15 | (0xE << 7);
+///     let mut crc = crc5_usb();
+///     crc.add_bits(field as u8, 8).unwrap();
+///     crc.add_bits((field >> 8) as u8, 3).unwrap();
+///     assert_eq!(crc.get_checksum(), 0x1D);
+/// }
+/// ```
+///
+pub const CRC5_USB: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-5/USB",
+    params: Check8CrcParams { width: 5, poly: 0x05, init: 0x1F, refin: true, refout: true, xorout: 0x1F },
+    check: 0x19,
+    hamming: &[Check8HdRange { hd: 3, max_data_bits: 26 }],
+};
+
+/// Creates a Check8Crc for CRC-5/USB.
+pub fn crc5_usb() -> Check8Crc {
+    const CRC: Check8Crc = CRC5_USB.crc();
+    CRC
+}
+
+/// CRC-8/SMBUS, the Packet Error Code (PEC) of SMBus and PMBus.
+///
+/// This is the plain polynomial 0x07 CRC that Check8Crc::new(0x07) also computes.
+pub const CRC8_SMBUS: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/SMBUS",
+    params: Check8CrcParams::new(0x07),
+    check: 0xF4,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 119 }],
+};
+
+/// Creates a Check8Crc for CRC-8/SMBUS.
+pub fn crc8_smbus() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_SMBUS.crc();
+    CRC
+}
+
+/// CRC-8/MAXIM-DOW, the CRC of Dallas/Maxim 1-Wire ROM codes and scratchpads.
+///
+/// Running the CRC over a 1-Wire ROM code, including its CRC byte, leaves zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::Checksum;
+/// use crate::check8::presets::crc8_maxim;
+/// fn main() {
+///     // family code 0x02, serial number 0x0000000001B81C
+///     let mut crc = crc8_maxim();
+///     assert_eq!(crc.calculate_from_byte_array(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00]), 0xA2);
+/// }
+/// ```
+///
+pub const CRC8_MAXIM: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/MAXIM-DOW",
+    params: Check8CrcParams { width: 8, poly: 0x31, init: 0x00, refin: true, refout: true, xorout: 0x00 },
+    check: 0xA1,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 119 }],
+};
+
+/// Creates a Check8Crc for CRC-8/MAXIM-DOW.
+pub fn crc8_maxim() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_MAXIM.crc();
+    CRC
+}
+
+/// CRC-8/AUTOSAR, the CRC8H2F of the AUTOSAR CRC library, used by E2E Profile 2.
+pub const CRC8_AUTOSAR: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/AUTOSAR",
+    params: Check8CrcParams { width: 8, poly: 0x2F, init: 0xFF, refin: false, refout: false, xorout: 0xFF },
+    check: 0xDF,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 3 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/AUTOSAR.
+pub fn crc8_autosar() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_AUTOSAR.crc();
+    CRC
+}
+
+/// CRC-8/SAE-J1850, the CRC of SAE J1850 (Class 2, legacy OBD) frames and the Crc_CalculateCRC8
+/// routine of the AUTOSAR CRC library.
+pub const CRC8_SAE_J1850: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/SAE-J1850",
+    params: Check8CrcParams { width: 8, poly: 0x1D, init: 0xFF, refin: false, refout: false, xorout: 0xFF },
+    check: 0x4B,
+    hamming: &[
+        Check8HdRange { hd: 5, max_data_bits: 6 },
+        Check8HdRange { hd: 4, max_data_bits: 13 },
+        Check8HdRange { hd: 3, max_data_bits: 247 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/SAE-J1850.
+pub fn crc8_sae_j1850() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_SAE_J1850.crc();
+    CRC
+}
+
+/// CRC-8/DVB-S2, the CRC of DVB-S2 baseband headers and of Crossfire/ExpressLRS (CRSF) frames.
+pub const CRC8_DVB_S2: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/DVB-S2",
+    params: Check8CrcParams::new(0xD5),
+    check: 0xBC,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 2 },
+        Check8HdRange { hd: 4, max_data_bits: 85 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/DVB-S2.
+pub fn crc8_dvb_s2() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_DVB_S2.crc();
+    CRC
+}
+
+/// CRC-8/CDMA2000, the frame quality indicator of some CDMA2000 traffic channel frames.
+pub const CRC8_CDMA2000: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/CDMA2000",
+    params: Check8CrcParams { width: 8, poly: 0x9B, init: 0xFF, refin: false, refout: false, xorout: 0x00 },
+    check: 0xDA,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 2 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/CDMA2000.
+pub fn crc8_cdma2000() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_CDMA2000.crc();
+    CRC
+}
+
+/// CRC-8/DARC, the CRC of Data Radio Channel (DARC) FM subcarrier blocks.
+pub const CRC8_DARC: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/DARC",
+    params: Check8CrcParams { width: 8, poly: 0x39, init: 0x00, refin: true, refout: true, xorout: 0x00 },
+    check: 0x15,
+    hamming: &[Check8HdRange { hd: 5, max_data_bits: 9 }],
+};
+
+/// Creates a Check8Crc for CRC-8/DARC.
+pub fn crc8_darc() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_DARC.crc();
+    CRC
+}
+
+/// CRC-8/GSM-B, the parity check of some GSM control and traffic channel blocks.
+pub const CRC8_GSM_B: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/GSM-B",
+    params: Check8CrcParams { width: 8, poly: 0x49, init: 0x00, refin: false, refout: false, xorout: 0xFF },
+    check: 0x94,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 97 }],
+};
+
+/// Creates a Check8Crc for CRC-8/GSM-B.
+pub fn crc8_gsm_b() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_GSM_B.crc();
+    CRC
+}
+
+/// CRC-8/WCDMA, the 8-bit transport block CRC of UMTS (3GPP TS 25.212).
+///
+/// The polynomial is shared with CRC-8/CDMA2000, which is not reflected and starts from 0xFF.
+pub const CRC8_WCDMA: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/WCDMA",
+    params: Check8CrcParams { width: 8, poly: 0x9B, init: 0x00, refin: true, refout: true, xorout: 0x00 },
+    check: 0x25,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 2 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/WCDMA.
+pub fn crc8_wcdma() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_WCDMA.crc();
+    CRC
+}
+
+/// CRC-8/BLUETOOTH, the header error check (HEC) of Bluetooth BR/EDR packet headers.
+///
+/// The HEC itself starts from the device's UAP rather than zero; see the bluetooth module.
+pub const CRC8_BLUETOOTH: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/BLUETOOTH",
+    params: Check8CrcParams { width: 8, poly: 0xA7, init: 0x00, refin: true, refout: true, xorout: 0x00 },
+    check: 0x26,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 3 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/BLUETOOTH.
+pub fn crc8_bluetooth() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_BLUETOOTH.crc();
+    CRC
+}
+
+/// CRC-8/ROHC, the 8-bit CRC of RObust Header Compression (RFC 3095) IR and IR-DYN packets.
+pub const CRC8_ROHC: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/ROHC",
+    params: Check8CrcParams { width: 8, poly: 0x07, init: 0xFF, refin: true, refout: true, xorout: 0x00 },
+    check: 0xD0,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 119 }],
+};
+
+/// Creates a Check8Crc for CRC-8/ROHC.
+pub fn crc8_rohc() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_ROHC.crc();
+    CRC
+}
+
+/// CRC-8/NRSC-5, the CRC of HD Radio (NRSC-5) program service data frames.
+pub const CRC8_NRSC_5: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/NRSC-5",
+    params: Check8CrcParams { width: 8, poly: 0x31, init: 0xFF, refin: false, refout: false, xorout: 0x00 },
+    check: 0xF7,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 119 }],
+};
+
+/// Creates a Check8Crc for CRC-8/NRSC-5.
+pub fn crc8_nrsc_5() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_NRSC_5.crc();
+    CRC
+}
+
+/// CRC-8/OPENSAFETY, the CRC of short openSAFETY sub-frames.
+///
+/// The parameters are those of Koopman 0x97 under its catalogue name.
+pub const CRC8_OPENSAFETY: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/OPENSAFETY",
+    params: Check8CrcParams::new(0x2F),
+    check: 0x3E,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 3 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/OPENSAFETY.
+pub fn crc8_opensafety() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_OPENSAFETY.crc();
+    CRC
+}
+
+/// CRC-8/MIFARE-MAD, the CRC of the MIFARE Application Directory (NXP AN10787), stored in the
+/// first byte of the MAD and calculated over the info byte and the application identifiers.
+pub const CRC8_MIFARE_MAD: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/MIFARE-MAD",
+    params: Check8CrcParams { width: 8, poly: 0x1D, init: 0xC7, refin: false, refout: false, xorout: 0x00 },
+    check: 0x99,
+    hamming: &[
+        Check8HdRange { hd: 5, max_data_bits: 6 },
+        Check8HdRange { hd: 4, max_data_bits: 13 },
+        Check8HdRange { hd: 3, max_data_bits: 247 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/MIFARE-MAD.
+pub fn crc8_mifare_mad() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_MIFARE_MAD.crc();
+    CRC
+}
+
+/// CRC-8/TECH-3250, the channel status CRC (CRCC) of AES3 (AES/EBU) digital audio, from EBU Tech 3250.
+pub const CRC8_TECH_3250: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/TECH-3250",
+    params: Check8CrcParams { width: 8, poly: 0x1D, init: 0xFF, refin: true, refout: true, xorout: 0x00 },
+    check: 0x97,
+    hamming: &[
+        Check8HdRange { hd: 5, max_data_bits: 6 },
+        Check8HdRange { hd: 4, max_data_bits: 13 },
+        Check8HdRange { hd: 3, max_data_bits: 247 },
+    ],
+};
+
+/// Creates a Check8Crc for CRC-8/TECH-3250.
+pub fn crc8_tech_3250() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_TECH_3250.crc();
+    CRC
+}
+
+/// CRC-8/I-432-1, the header error control (HEC) of ATM cells from ITU-T I.432.1, including
+/// the 0x55 coset as its final XOR. Also known as CRC-8/ITU and CRC-8/ATM.
+pub const CRC8_I_432_1: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/I-432-1",
+    params: Check8CrcParams { width: 8, poly: 0x07, init: 0x00, refin: false, refout: false, xorout: 0x55 },
+    check: 0xA1,
+    hamming: &[Check8HdRange { hd: 4, max_data_bits: 119 }],
+};
+
+/// Creates a Check8Crc for CRC-8/I-432-1.
+pub fn crc8_i_432_1() -> Check8Crc {
+    const CRC: Check8Crc = CRC8_I_432_1.crc();
+    CRC
+}
+
+/// Koopman 0xA6 (0x4D in normal form), the 8-bit polynomial with HD 3 for the longest data words.
+pub const KOOPMAN_A6: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/KOOPMAN-A6",
+    params: Check8CrcParams::new(0x4D),
+    check: 0xC3,
+    hamming: &[
+        Check8HdRange { hd: 5, max_data_bits: 6 },
+        Check8HdRange { hd: 4, max_data_bits: 15 },
+        Check8HdRange { hd: 3, max_data_bits: 247 },
+    ],
+};
+
+/// Koopman 0x97 (0x2F in normal form), HD 4 for data words of up to 119 bits.
+///
+/// The polynomial is shared with CRC-8/AUTOSAR and CRC-8/OPENSAFETY.
+pub const KOOPMAN_97: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/KOOPMAN-97",
+    params: Check8CrcParams::new(0x2F),
+    check: 0x3E,
+    hamming: &[
+        Check8HdRange { hd: 6, max_data_bits: 3 },
+        Check8HdRange { hd: 4, max_data_bits: 119 },
+    ],
+};
+
+/// Koopman 0x9C (0x39 in normal form), HD 5 for data words of up to 9 bits.
+pub const KOOPMAN_9C: Check8CrcPreset = Check8CrcPreset {
+    name: "CRC-8/KOOPMAN-9C",
+    params: Check8CrcParams::new(0x39),
+    check: 0x8F,
+    hamming: &[Check8HdRange { hd: 5, max_data_bits: 9 }],
+};
+
+/// Creates a Check8Crc for Koopman 0xA6.
+pub fn crc8_koopman_a6() -> Check8Crc {
+    const CRC: Check8Crc = KOOPMAN_A6.crc();
+    CRC
+}
+
+/// Creates a Check8Crc for Koopman 0x97.
+pub fn crc8_koopman_97() -> Check8Crc {
+    const CRC: Check8Crc = KOOPMAN_97.crc();
+    CRC
+}
+
+/// Creates a Check8Crc for Koopman 0x9C.
+pub fn crc8_koopman_9c() -> Check8Crc {
+    const CRC: Check8Crc = KOOPMAN_9C.crc();
+    CRC
+}
+
+/// Every preset in this module.
+pub const PRESETS: &[Check8CrcPreset] = &[
+    CRC5_USB,
+    CRC7_MMC,
+    CRC8_SMBUS,
+    CRC8_MAXIM,
+    CRC8_AUTOSAR,
+    CRC8_SAE_J1850,
+    CRC8_DVB_S2,
+    CRC8_CDMA2000,
+    CRC8_DARC,
+    CRC8_GSM_B,
+    CRC8_WCDMA,
+    CRC8_BLUETOOTH,
+    CRC8_ROHC,
+    CRC8_NRSC_5,
+    CRC8_OPENSAFETY,
+    CRC8_MIFARE_MAD,
+    CRC8_TECH_3250,
+    CRC8_I_432_1,
+    KOOPMAN_A6,
+    KOOPMAN_97,
+    KOOPMAN_9C,
+];
+
+/// Looks up a preset by its catalogue name, ignoring case.
+///
+/// - **Parameter**: name - The name of the algorithm, e.g. "CRC-8/SMBUS".
+/// - **Returns**: The preset, or None if there is no preset of that name.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::Checksum;
+/// use crate::check8::presets;
+/// fn main() {
+///     let preset = presets::by_name("crc-8/smbus").unwrap();
+///     let mut crc = preset.crc();
+///     assert_eq!(crc.calculate_from_string("123456789"), 0xF4);
+///     assert!(presets::by_name("CRC-8/UNKNOWN").is_none());
+/// }
+/// ```
+///
+pub fn by_name(name: &str) -> Option<&'static Check8CrcPreset> {
+    PRESETS.iter().find(|preset| preset.name.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Check8, Checksum};
+
+    fn assert_check_value(preset: &Check8CrcPreset, mut crc: Check8Crc) {
+        assert_eq!(crc.calculate_from_string("123456789"), preset.check, "{}", preset.name);
+    }
+
+    #[test]
+    fn crc7_mmc_check_value() {
+        assert_check_value(&CRC7_MMC, crc7_mmc());
+    }
+
+    #[test]
+    fn crc8_smbus_check_value() {
+        assert_check_value(&CRC8_SMBUS, crc8_smbus());
+        assert_check_value(&CRC8_SMBUS, Check8Crc::new(0x07));
+    }
+
+    #[test]
+    fn preset_functions_use_compile_time_tables() {
+        const MAXIM: Check8Crc = CRC8_MAXIM.crc();
+        let mut from_const = MAXIM;
+        let mut from_fn = crc8_maxim();
+        let mut at_runtime = Check8Crc::with_params(CRC8_MAXIM.params);
+        for val in 0..=255u8 {
+            assert_eq!(from_const.add(val), at_runtime.add(val));
+            assert_eq!(from_fn.add(val), at_runtime.get_accum());
+        }
+    }
+
+    #[test]
+    fn all_presets_have_their_check_values() {
+        for preset in PRESETS {
+            assert_check_value(preset, preset.crc());
+        }
+    }
+
+    #[test]
+    fn by_name_finds_each_preset() {
+        for preset in PRESETS {
+            assert_eq!(by_name(preset.name), Some(preset));
+            assert_eq!(by_name(&preset.name.to_lowercase()), Some(preset));
+        }
+        assert_eq!(by_name("CRC-8"), None);
+    }
+
+    #[test]
+    fn crc8_maxim_check_value() {
+        assert_check_value(&CRC8_MAXIM, crc8_maxim());
+    }
+
+    #[test]
+    fn crc8_maxim_one_wire_rom_code() {
+        // the example ROM code from Maxim application note 27
+        let rom = [0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2];
+        assert_eq!(crc8_maxim().calculate_from_byte_array(&rom[..7]), 0xA2);
+        assert_eq!(crc8_maxim().calculate_from_byte_array(&rom), 0x00);
+    }
+
+    #[test]
+    fn crc8_autosar_crc_library_examples() {
+        // the CRC8H2F examples from the AUTOSAR Specification of CRC Routines
+        let examples: [(&[u8], u8); 7] = [
+            (&[0x00, 0x00, 0x00, 0x00], 0x12),
+            (&[0xF2, 0x01, 0x83], 0xC2),
+            (&[0x0F, 0xAA, 0x00, 0x55], 0xC6),
+            (&[0x00, 0xFF, 0x55, 0x11], 0x77),
+            (&[0x33, 0x22, 0x55, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF], 0x11),
+            (&[0x92, 0x6B, 0x55], 0x33),
+            (&[0xFF, 0xFF, 0xFF, 0xFF], 0x6C),
+        ];
+        assert_check_value(&CRC8_AUTOSAR, crc8_autosar());
+        for (data, expected) in examples {
+            assert_eq!(crc8_autosar().calculate_from_byte_array(data), expected);
+        }
+    }
+
+    #[test]
+    fn crc8_sae_j1850_crc_library_examples() {
+        // the Crc_CalculateCRC8 examples from the AUTOSAR Specification of CRC Routines
+        let examples: [(&[u8], u8); 7] = [
+            (&[0x00, 0x00, 0x00, 0x00], 0x59),
+            (&[0xF2, 0x01, 0x83], 0x37),
+            (&[0x0F, 0xAA, 0x00, 0x55], 0x79),
+            (&[0x00, 0xFF, 0x55, 0x11], 0xB8),
+            (&[0x33, 0x22, 0x55, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF], 0xCB),
+            (&[0x92, 0x6B, 0x55], 0x8C),
+            (&[0xFF, 0xFF, 0xFF, 0xFF], 0x74),
+        ];
+        assert_check_value(&CRC8_SAE_J1850, crc8_sae_j1850());
+        for (data, expected) in examples {
+            assert_eq!(crc8_sae_j1850().calculate_from_byte_array(data), expected);
+        }
+    }
+
+    #[test]
+    fn crc8_dvb_s2_check_value() {
+        assert_check_value(&CRC8_DVB_S2, crc8_dvb_s2());
+    }
+
+    #[test]
+    fn crc8_cdma2000_check_value() {
+        assert_check_value(&CRC8_CDMA2000, crc8_cdma2000());
+        assert_eq!(by_name("CRC-8/CDMA2000"), Some(&CRC8_CDMA2000));
+    }
+
+    #[test]
+    fn crc8_darc_check_value() {
+        assert_check_value(&CRC8_DARC, crc8_darc());
+        // the reflected table gives the same result as adding the bits least significant first
+        let mut crc = crc8_darc();
+        for val in b"123456789" {
+            crc.add_bits(*val, 8).unwrap();
+        }
+        assert_eq!(crc.get_checksum(), CRC8_DARC.check);
+    }
+
+    #[test]
+    fn crc8_gsm_b_check_value() {
+        assert_check_value(&CRC8_GSM_B, crc8_gsm_b());
+        // the final XOR inverts the register
+        let mut crc = crc8_gsm_b();
+        crc.calculate_from_string("123456789");
+        assert_eq!(crc.get_accum(), !CRC8_GSM_B.check);
+    }
+
+    #[test]
+    fn crc8_wcdma_check_value() {
+        assert_check_value(&CRC8_WCDMA, crc8_wcdma());
+        assert_eq!(by_name("crc-8/wcdma"), Some(&CRC8_WCDMA));
+    }
+
+    #[test]
+    fn crc8_bluetooth_check_value() {
+        assert_check_value(&CRC8_BLUETOOTH, crc8_bluetooth());
+    }
+
+    #[test]
+    fn crc8_rohc_check_value() {
+        assert_check_value(&CRC8_ROHC, crc8_rohc());
+        assert_eq!(by_name("CRC-8/ROHC"), Some(&CRC8_ROHC));
+    }
+
+    #[test]
+    fn crc8_nrsc_5_check_value() {
+        assert_check_value(&CRC8_NRSC_5, crc8_nrsc_5());
+        assert_eq!(by_name("CRC-8/NRSC-5"), Some(&CRC8_NRSC_5));
+    }
+
+    #[test]
+    fn crc8_opensafety_check_value() {
+        assert_check_value(&CRC8_OPENSAFETY, crc8_opensafety());
+        assert_eq!(CRC8_OPENSAFETY.params, KOOPMAN_97.params);
+    }
+
+    #[test]
+    fn crc8_mifare_mad_check_value() {
+        assert_check_value(&CRC8_MIFARE_MAD, crc8_mifare_mad());
+        assert_eq!(by_name("CRC-8/MIFARE-MAD"), Some(&CRC8_MIFARE_MAD));
+    }
+
+    #[test]
+    fn crc8_tech_3250_check_value() {
+        assert_check_value(&CRC8_TECH_3250, crc8_tech_3250());
+    }
+
+    #[test]
+    fn crc8_i_432_1_check_value() {
+        assert_check_value(&CRC8_I_432_1, crc8_i_432_1());
+    }
+
+    #[test]
+    fn koopman_check_values() {
+        assert_check_value(&KOOPMAN_A6, crc8_koopman_a6());
+        assert_check_value(&KOOPMAN_97, crc8_koopman_97());
+        assert_check_value(&KOOPMAN_9C, crc8_koopman_9c());
+    }
+
+    // finds the lowest weight error pattern of up to max_weight bits in a codeword of the
+    // given length that the CRC does not detect, by brute force over the error positions
+    fn undetected_error_weight(preset: &Check8CrcPreset, data_bits: u32, max_weight: usize) -> Option<usize> {
+        let params = Check8CrcParams { init: 0, xorout: 0, refin: false, refout: false, ..preset.params };
+        let width = params.width as u32;
+        // the remainder of x^i for each bit position of the codeword
+        let mut residues = Vec::new();
+        let mut reg: u32 = 1;
+        for _ in 0..data_bits + width {
+            residues.push(reg);
+            reg <<= 1;
+            if reg & (1 << width) != 0 {
+                reg ^= (1 << width) | params.poly as u32;
+            }
+        }
+        fn search(residues: &[u32], start: usize, remaining: usize, acc: u32) -> bool {
+            if remaining == 0 {
+                return acc == 0;
+            }
+            (start..residues.len()).any(|i| search(residues, i + 1, remaining - 1, acc ^ residues[i]))
+        }
+        (1..=max_weight).find(|weight| search(&residues, 0, *weight, 0))
+    }
+
+    #[test]
+    fn hamming_ranges_hold_at_their_limits() {
+        for preset in PRESETS {
+            for range in preset.hamming {
+                // no error of fewer than hd bits goes undetected at the longest listed length
+                let hd = range.hd as usize;
+                assert_eq!(undetected_error_weight(preset, range.max_data_bits, hd - 1), None, "{}", preset.name);
+            }
+        }
+        // and one bit longer the HD 4 range of Koopman 0x97 fails for some 2 or 3-bit error
+        assert!(undetected_error_weight(&KOOPMAN_97, 120, 3).is_some());
+    }
+
+    #[test]
+    fn hd_for_data_bits_picks_the_strongest_range() {
+        assert_eq!(KOOPMAN_A6.hd_for_data_bits(1), Some(5));
+        assert_eq!(KOOPMAN_A6.hd_for_data_bits(15), Some(4));
+        assert_eq!(KOOPMAN_A6.hd_for_data_bits(16), Some(3));
+        assert_eq!(KOOPMAN_A6.hd_for_data_bits(248), None);
+    }
+
+    #[test]
+    fn crc5_usb_check_value() {
+        assert_check_value(&CRC5_USB, crc5_usb());
+    }
+
+    // the CRC-5 of a token's 11-bit address and endpoint field, sent least significant bit first
+    fn usb_token_crc(addr: u8, endp: u8) -> u8 {
+        let field = (addr as u16 & 0x7F) | ((endp as u16 & 0x0F) << 7);
+        let mut crc = crc5_usb();
+        crc.add_bits(field as u8, 8).unwrap();
+        crc.add_bits((field >> 8) as u8, 3).unwrap();
+        crc.get_checksum()
+    }
+
+    #[test]
+    fn crc5_usb_token_packets() {
+        // examples from the USB CRC whitepaper, which gives the CRC most significant bit
+        // first (0x17, 0x1C, 0x0E); the preset returns the reflected form
+        assert_eq!(usb_token_crc(0x15, 0x0E), 0x1D);
+        assert_eq!(usb_token_crc(0x3A, 0x0A), 0x07);
+        assert_eq!(usb_token_crc(0x70, 0x04), 0x0E);
+        assert_eq!(crate::check8crc::reflect(usb_token_crc(0x15, 0x0E), 5), 0x17);
+    }
+
+    #[test]
+    fn crc5_usb_validates_whole_token_field() {
+        // a receiver can check the 16-bit token field by running the CRC over all of it:
+        // a valid field leaves the USB CRC-5 residual 0b01100 in the register
+        let field: u16 = 0x3A | (0x0A << 7) | ((usb_token_crc(0x3A, 0x0A) as u16) << 11);
+        let mut crc = crc5_usb();
+        crc.calculate_from_byte_array(&field.to_le_bytes());
+        assert_eq!(crate::check8crc::reflect(crc.get_accum(), 5), 0b01100);
+
+        let corrupted = field ^ 0x0040;
+        let mut crc = crc5_usb();
+        crc.calculate_from_byte_array(&corrupted.to_le_bytes());
+        assert_ne!(crate::check8crc::reflect(crc.get_accum(), 5), 0b01100);
+    }
+}
@@ -0,0 +1,240 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # Python bindings
+//!
+//! A PyO3 extension module, check8, so that Python test benches check frames with the same
+//! implementations as the firmware tools rather than a reimplementation. Available with the
+//! python feature, which implies std, presets, checkdigit and protocols.
+//!
+//! - Checksum(algorithm): a running checksum, constructed from an algorithm name as for the
+//!   wasm bindings: sum, xor, xor-rotate, lrc, bsd, parity, ones-complement, fletcher, pearson,
+//!   roland, or the name of any CRC preset, e.g. "CRC-8/SMBUS". update(data) adds bytes and
+//!   returns the checksum so far, finalize() retrieves it and reset() starts again.
+//! - algorithms(): the names Checksum accepts.
+//! - checksum(algorithm, data): the checksum of bytes in one call.
+//! - The verify helpers of the protocol and check digit modules, named module_function, e.g.
+//!   nmea_verify or ubx_verify_frame. Those returning a Result in Rust return None or raise
+//!   ValueError with the error's message; those returning a bool return it.
+//!
+//! An unknown algorithm name raises ValueError.
+//!
+//! The module is built with `cargo rustc --release --features python --crate-type cdylib`,
+//! with the library renamed to check8.so (check8.pyd on Windows) to be imported.
+//!
+//! # Examples
+//!
+//! ```python
+//! import check8
+//!
+//! crc = check8.Checksum("CRC-8/SMBUS")
+//! crc.update(b"1234")
+//! assert crc.update(b"56789") == 0xF4
+//!
+//! check8.nmea_verify("$GPGLL,4916.45,N,12311.12,W,225444,A*31")
+//! ```
+
+use core::fmt;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::check8registry::{by_name, names, AnyChecksum};
+use crate::{checkdigit, crsf, dynamixel, gameboy, ihex, nmea, onewire, srec, ubx, vedirect, zxtap};
+
+// a Rust error as a Python ValueError with the same message
+fn value_error(error: impl fmt::Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// A running checksum, constructed from an algorithm name.
+#[pyclass(name = "Checksum")]
+pub struct PyChecksum {
+    checksum: AnyChecksum,
+    initial: u8,
+}
+
+#[pymethods]
+impl PyChecksum {
+    #[new]
+    fn new(algorithm: &str) -> PyResult<Self> {
+        let checksum =
+            by_name(algorithm).ok_or_else(|| value_error(format_args!("unknown checksum algorithm {}", algorithm)))?;
+        let initial = checksum.get_accum();
+        Ok(PyChecksum { checksum, initial })
+    }
+
+    /// Adds bytes and returns the checksum so far.
+    fn update(&mut self, data: &[u8]) -> u8 {
+        self.checksum.calculate_from_byte_array(data)
+    }
+
+    /// Retrieves the checksum, after any finalisation. The checksum can still be updated.
+    fn finalize(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    /// Returns the checksum to the state it was created in.
+    fn reset(&mut self) -> u8 {
+        self.checksum.init(self.initial)
+    }
+}
+
+/// Lists the algorithm names Checksum accepts: the simple checksums, then the CRC presets.
+#[pyfunction]
+fn algorithms() -> Vec<&'static str> {
+    names().collect()
+}
+
+/// Calculates the checksum of bytes in one call.
+#[pyfunction]
+fn checksum(algorithm: &str, data: &[u8]) -> PyResult<u8> {
+    let mut checksum = PyChecksum::new(algorithm)?;
+    Ok(checksum.update(data))
+}
+
+#[pyfunction]
+fn nmea_verify(sentence: &str) -> PyResult<()> {
+    nmea::verify(sentence).map_err(value_error)
+}
+
+#[pyfunction]
+fn ihex_verify_record(line: &str) -> PyResult<()> {
+    ihex::verify_record(line).map_err(value_error)
+}
+
+#[pyfunction]
+fn srec_verify_record(line: &str) -> PyResult<()> {
+    srec::verify_record(line).map_err(value_error)
+}
+
+#[pyfunction]
+fn ubx_verify_frame(frame: &[u8]) -> PyResult<()> {
+    ubx::verify_frame(frame).map_err(value_error)
+}
+
+#[pyfunction]
+fn crsf_check_frame(frame: &[u8]) -> PyResult<()> {
+    crsf::check_frame(frame).map_err(value_error)
+}
+
+#[pyfunction]
+fn dynamixel_verify_packet(packet: &[u8]) -> PyResult<()> {
+    dynamixel::verify_packet(packet).map_err(value_error)
+}
+
+#[pyfunction]
+fn gameboy_verify_header(rom: &[u8]) -> PyResult<()> {
+    gameboy::verify_header(rom).map_err(value_error)
+}
+
+#[pyfunction]
+fn onewire_verify_rom(rom: &[u8]) -> PyResult<()> {
+    let rom = rom.try_into().map_err(|_| value_error("a ROM code is 8 bytes"))?;
+    onewire::verify_rom(rom).map_err(value_error)
+}
+
+#[pyfunction]
+fn onewire_verify_scratchpad(scratchpad: &[u8]) -> PyResult<()> {
+    let scratchpad = scratchpad.try_into().map_err(|_| value_error("a scratchpad is 9 bytes"))?;
+    onewire::verify_scratchpad(scratchpad).map_err(value_error)
+}
+
+#[pyfunction]
+fn vedirect_verify_block(block: &[u8]) -> bool {
+    vedirect::verify_block(block)
+}
+
+#[pyfunction]
+fn zxtap_verify_block(block: &[u8]) -> bool {
+    zxtap::verify_block(block)
+}
+
+#[pyfunction]
+fn luhn_verify(digits: &str) -> bool {
+    checkdigit::luhn::verify(digits.as_bytes())
+}
+
+#[pyfunction]
+fn damm_verify(digits: &str) -> bool {
+    checkdigit::damm::verify(digits.as_bytes())
+}
+
+#[pyfunction]
+fn verhoeff_verify(digits: &str) -> bool {
+    checkdigit::verhoeff::verify(digits.as_bytes())
+}
+
+/// The check8 extension module.
+#[pymodule]
+#[pyo3(name = "check8")]
+fn check8_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyChecksum>()?;
+    m.add_function(wrap_pyfunction!(algorithms, m)?)?;
+    m.add_function(wrap_pyfunction!(checksum, m)?)?;
+    m.add_function(wrap_pyfunction!(nmea_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(ihex_verify_record, m)?)?;
+    m.add_function(wrap_pyfunction!(srec_verify_record, m)?)?;
+    m.add_function(wrap_pyfunction!(ubx_verify_frame, m)?)?;
+    m.add_function(wrap_pyfunction!(crsf_check_frame, m)?)?;
+    m.add_function(wrap_pyfunction!(dynamixel_verify_packet, m)?)?;
+    m.add_function(wrap_pyfunction!(gameboy_verify_header, m)?)?;
+    m.add_function(wrap_pyfunction!(onewire_verify_rom, m)?)?;
+    m.add_function(wrap_pyfunction!(onewire_verify_scratchpad, m)?)?;
+    m.add_function(wrap_pyfunction!(vedirect_verify_block, m)?)?;
+    m.add_function(wrap_pyfunction!(zxtap_verify_block, m)?)?;
+    m.add_function(wrap_pyfunction!(luhn_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(damm_verify, m)?)?;
+    m.add_function(wrap_pyfunction!(verhoeff_verify, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_finalize_and_reset() {
+        let mut crc = PyChecksum::new("crc-8/maxim-dow").unwrap();
+        crc.update(b"1234");
+        assert_eq!(crc.update(b"56789"), 0xA1);
+        crc.reset();
+        crc.update(b"123456789");
+        assert_eq!(crc.finalize(), 0xA1);
+        assert!(PyChecksum::new("crc-8/unknown").is_err());
+        assert_eq!(checksum("xor", &[0x01, 0x02]).unwrap(), 0x03)
+    }
+
+    #[test]
+    fn verify_helpers() {
+        assert!(nmea_verify("$GPGLL,4916.45,N,12311.12,W,225444,A*31").is_ok());
+        assert!(nmea_verify("$GPGLL,4916.45,N,12311.12,W,225444,A*32").is_err());
+        assert!(onewire_verify_rom(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA2]).is_ok());
+        assert!(onewire_verify_rom(&[0x02, 0x1C, 0xB8, 0x01, 0x00, 0x00, 0x00, 0xA3]).is_err());
+        assert!(onewire_verify_rom(&[0x28]).is_err());
+        assert!(luhn_verify("79927398713"));
+        assert!(!luhn_verify("79927398710"))
+    }
+}
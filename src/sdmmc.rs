@@ -0,0 +1,88 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # SD/MMC
+//!
+//! Helpers for the CRC-7 that protects SD and MMC card command frames.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::presets::crc7_mmc;
+
+/// Calculates the final byte of an SD/MMC command frame: the CRC-7/MMC of the first five
+/// bytes (start bits and command index, then the 32-bit argument), shifted up with the
+/// mandatory end bit set.
+///
+/// - **Parameter**: command - The command index byte (including the start and transmission bits) and argument.
+/// - **Returns**: The byte to transmit after the argument.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::sdmmc::command_crc;
+/// fn main() {
+///     // CMD0, GO_IDLE_STATE
+///     assert_eq!(command_crc(&[0x40, 0x00, 0x00, 0x00, 0x00]), 0x95);
+///     // CMD8, SEND_IF_COND with the usual 0x1AA argument
+///     assert_eq!(command_crc(&[0x48, 0x00, 0x00, 0x01, 0xAA]), 0x87);
+/// }
+/// ```
+///
+pub fn command_crc(command: &[u8; 5]) -> u8 {
+    let mut crc = crc7_mmc();
+    crc.calculate_from_byte_array(command);
+    crc.get_checksum_left_aligned() | 0x01
+}
+
+/// Builds a complete six-byte SD/MMC command frame from the command index and argument.
+///
+/// The start bit (0) and transmission bit (1) are set on the command index, which must be 0 to 63.
+pub fn command_frame(index: u8, argument: u32) -> [u8; 6] {
+    let arg = argument.to_be_bytes();
+    let command = [0x40 | (index & 0x3F), arg[0], arg[1], arg[2], arg[3]];
+    let mut frame = [0u8; 6];
+    frame[..5].copy_from_slice(&command);
+    frame[5] = command_crc(&command);
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_crc_matches_known_commands() {
+        assert_eq!(command_crc(&[0x40, 0x00, 0x00, 0x00, 0x00]), 0x95);  // CMD0
+        assert_eq!(command_crc(&[0x48, 0x00, 0x00, 0x01, 0xAA]), 0x87);  // CMD8
+        assert_eq!(command_crc(&[0x77, 0x00, 0x00, 0x00, 0x00]), 0x65);  // CMD55
+        assert_eq!(command_crc(&[0x69, 0x40, 0x00, 0x00, 0x00]), 0x77);  // ACMD41
+    }
+
+    #[test]
+    fn command_frame_sets_start_bits_and_crc() {
+        assert_eq!(command_frame(8, 0x1AA), [0x48, 0x00, 0x00, 0x01, 0xAA, 0x87]);
+        assert_eq!(command_frame(0, 0), [0x40, 0x00, 0x00, 0x00, 0x00, 0x95]);
+    }
+}
@@ -0,0 +1,218 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # Sensirion
+//!
+//! Helpers for the word CRC of Sensirion sensors (SHT3x, SHT4x, SCD4x, SGP40 and others).
+//! Data is exchanged as 16-bit words, most significant byte first, each followed by a CRC-8
+//! of its two bytes with polynomial 0x31 and initial value 0xFF, the parameters of CRC-8/NRSC-5.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8CrcParams;
+use crate::presets::{CRC8_NRSC_5, crc8_nrsc_5};
+
+/// The parameters of the Sensirion word CRC.
+pub const SENSIRION_CRC: Check8CrcParams = CRC8_NRSC_5.params;
+
+/// # Variants
+///
+/// - Length: The buffer is not a whole number of [MSB, LSB, CRC] groups, or the output is too short for them.
+/// - BadCrc: The CRC of the word at the given index (counting words, not bytes) does not match.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SensirionError {
+    Length,
+    BadCrc(usize),
+}
+
+impl fmt::Display for SensirionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SensirionError::Length => write!(f, "buffer is not a whole number of words with CRCs"),
+            SensirionError::BadCrc(index) => write!(f, "CRC mismatch for word {}", index),
+        }
+    }
+}
+
+impl core::error::Error for SensirionError {}
+
+/// Calculates the CRC of a 16-bit word.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::sensirion::word_crc;
+/// fn main() {
+///     // the example from the Sensirion datasheets
+///     assert_eq!(word_crc(0xBEEF), 0x92);
+/// }
+/// ```
+///
+pub fn word_crc(word: u16) -> u8 {
+    // the preset's table is generated at compile time
+    crc8_nrsc_5().calculate_from_byte_array(&word.to_be_bytes())
+}
+
+/// Verifies a buffer read from a sensor, laid out as [MSB, LSB, CRC] for each word, into a
+/// slice of words, so that it needs no allocator.
+///
+/// - **Parameter**: buffer - The bytes read.
+/// - **Parameter**: words - Where to store the words, with room for at least one per group.
+/// - **Returns**: The words stored, or a SensirionError for the first fault.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::sensirion::verify_words_into;
+/// fn main() {
+///     // SCD4x set_temperature_offset argument
+///     let mut words = [0u16; 2];
+///     assert_eq!(verify_words_into(&[0x07, 0xE6, 0x48], &mut words), Ok(&[0x07E6][..]));
+/// }
+/// ```
+///
+pub fn verify_words_into<'a>(buffer: &[u8], words: &'a mut [u16]) -> Result<&'a [u16], SensirionError> {
+    let (groups, rest) = buffer.as_chunks::<3>();
+    if !rest.is_empty() || groups.len() > words.len() {
+        return Err(SensirionError::Length);
+    }
+    let (words, _) = words.split_at_mut(groups.len());
+    for (index, (word, [msb, lsb, crc])) in words.iter_mut().zip(groups).enumerate() {
+        *word = u16::from_be_bytes([*msb, *lsb]);
+        if word_crc(*word) != *crc {
+            return Err(SensirionError::BadCrc(index));
+        }
+    }
+    Ok(words)
+}
+
+/// Verifies a buffer read from a sensor, laid out as [MSB, LSB, CRC] for each word.
+///
+/// - **Parameter**: buffer - The bytes read.
+/// - **Returns**: The words, or a SensirionError for the first fault.
+#[cfg(feature = "alloc")]
+pub fn verify_words(buffer: &[u8]) -> Result<Vec<u16>, SensirionError> {
+    let mut words = vec![0; buffer.len() / 3];
+    verify_words_into(buffer, &mut words)?;
+    Ok(words)
+}
+
+/// Lays out words to write to a sensor, each followed by its CRC, into a slice of bytes, so
+/// that it needs no allocator.
+///
+/// - **Parameter**: words - The words to write.
+/// - **Parameter**: buffer - Where to store the bytes, with room for at least three per word.
+/// - **Returns**: The bytes stored, [MSB, LSB, CRC] for each word, or SensirionError::Length.
+pub fn encode_words_into<'a>(words: &[u16], buffer: &'a mut [u8]) -> Result<&'a [u8], SensirionError> {
+    let (groups, _) = buffer.as_chunks_mut::<3>();
+    if words.len() > groups.len() {
+        return Err(SensirionError::Length);
+    }
+    for (group, word) in groups.iter_mut().zip(words) {
+        let [msb, lsb] = word.to_be_bytes();
+        *group = [msb, lsb, word_crc(*word)];
+    }
+    let (bytes, _) = buffer.split_at(words.len() * 3);
+    Ok(bytes)
+}
+
+/// Lays out words to write to a sensor, such as command arguments, each followed by its CRC.
+///
+/// - **Parameter**: words - The words to write.
+/// - **Returns**: The bytes, [MSB, LSB, CRC] for each word.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::sensirion::{encode_words, verify_words};
+/// fn main() {
+///     // SCD4x set_temperature_offset argument
+///     let bytes = encode_words(&[0x07E6]);
+///     assert_eq!(bytes, vec![0x07, 0xE6, 0x48]);
+///     assert_eq!(verify_words(&bytes), Ok(vec![0x07E6]));
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn encode_words(words: &[u16]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(words.len() * 3);
+    for word in words {
+        bytes.extend_from_slice(&word.to_be_bytes());
+        bytes.push(word_crc(*word));
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datasheet_example() {
+        assert_eq!(word_crc(0xBEEF), 0x92)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_reads() {
+        // SHT3x measurement: temperature word then humidity word
+        let buffer = encode_words(&[0x6666, 0x8000]);
+        assert_eq!(buffer.len(), 6);
+        assert_eq!(verify_words(&buffer), Ok(vec![0x6666, 0x8000]))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_reports_faults() {
+        let mut buffer = encode_words(&[0x1234, 0x5678, 0x9ABC]);
+        buffer[4] ^= 0x01;
+        assert_eq!(verify_words(&buffer), Err(SensirionError::BadCrc(1)));
+        assert_eq!(verify_words(&buffer[..5]), Err(SensirionError::Length));
+        assert_eq!(verify_words(&[]), Ok(vec![]))
+    }
+
+    #[test]
+    fn slices_in_and_out() {
+        let mut bytes = [0u8; 8];
+        let encoded = encode_words_into(&[0x6666, 0x8000], &mut bytes).unwrap();
+        assert_eq!(encoded, [0x66, 0x66, 0x93, 0x80, 0x00, 0xA2]);
+
+        let mut words = [0u16; 2];
+        assert_eq!(verify_words_into(&bytes[..6], &mut words), Ok(&[0x6666, 0x8000][..]));
+        assert_eq!(verify_words_into(&bytes[..5], &mut words), Err(SensirionError::Length));
+        assert_eq!(verify_words_into(&bytes[..6], &mut words[..1]), Err(SensirionError::Length));
+        assert_eq!(encode_words_into(&[0x6666, 0x8000], &mut bytes[..5]), Err(SensirionError::Length));
+
+        bytes[2] ^= 0x01;
+        assert_eq!(verify_words_into(&bytes[..6], &mut words), Err(SensirionError::BadCrc(0)))
+    }
+}
@@ -0,0 +1,169 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # SMBus
+//!
+//! Helpers for the Packet Error Code (PEC) of SMBus and PMBus transactions. The PEC is the
+//! CRC-8/SMBUS of every byte of the transaction in the order it appears on the bus, including
+//! the address bytes: the slave address with its R/W bit, the command code, the address again
+//! with the R bit after a repeated start when reading, and the data bytes.
+
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Crc;
+use crate::presets::crc8_smbus;
+
+/// Builds the PEC of an SMBus transaction from its parts, in bus order.
+///
+/// Addresses are the 7-bit slave address; the R/W bit is added by write_address and read_address.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::smbus::SmbusPec;
+/// fn main() {
+///     // MLX90614 read word: object temperature RAM address 0x07 from slave 0x5A,
+///     // which returns 0x3AD2 followed by PEC 0x30
+///     let pec = SmbusPec::new()
+///         .write_address(0x5A)
+///         .command(0x07)
+///         .read_address(0x5A)
+///         .data(&[0xD2, 0x3A])
+///         .pec();
+///     assert_eq!(pec, 0x30);
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct SmbusPec {
+    crc: Check8Crc,
+}
+
+impl SmbusPec {
+    /// Creates a new PEC calculation for a transaction.
+    pub fn new() -> Self {
+        SmbusPec { crc: crc8_smbus() }
+    }
+
+    /// Adds the slave address with the W bit, as sent after a start condition to write.
+    pub fn write_address(mut self, address: u8) -> Self {
+        self.crc.add((address & 0x7F) << 1);
+        self
+    }
+
+    /// Adds the slave address with the R bit, as sent after a start or repeated start to read.
+    pub fn read_address(mut self, address: u8) -> Self {
+        self.crc.add(((address & 0x7F) << 1) | 0x01);
+        self
+    }
+
+    /// Adds the command code.
+    pub fn command(mut self, command: u8) -> Self {
+        self.crc.add(command);
+        self
+    }
+
+    /// Adds data bytes, including the byte count of a block transfer.
+    pub fn data(mut self, data: &[u8]) -> Self {
+        for val in data {
+            self.crc.add(*val);
+        }
+        self
+    }
+
+    /// Retrieves the PEC of the transaction so far.
+    pub fn pec(&self) -> u8 {
+        self.crc.get_checksum()
+    }
+}
+
+impl Default for SmbusPec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Calculates the PEC of a write transaction: Write Byte, Write Word or Block Write.
+///
+/// - **Parameter**: address - The 7-bit slave address.
+/// - **Parameter**: command - The command code.
+/// - **Parameter**: data - The data bytes written, including the byte count of a block write.
+/// - **Returns**: The PEC byte the master sends after the data.
+pub fn write_pec(address: u8, command: u8, data: &[u8]) -> u8 {
+    SmbusPec::new().write_address(address).command(command).data(data).pec()
+}
+
+/// Calculates the PEC of a read transaction with a repeated start: Read Byte, Read Word or Block Read.
+///
+/// - **Parameter**: address - The 7-bit slave address.
+/// - **Parameter**: command - The command code.
+/// - **Parameter**: data - The data bytes read, including the byte count of a block read.
+/// - **Returns**: The PEC byte the slave should send after the data.
+pub fn read_pec(address: u8, command: u8, data: &[u8]) -> u8 {
+    SmbusPec::new().write_address(address).command(command).read_address(address).data(data).pec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mlx90614_read_word() {
+        assert_eq!(read_pec(0x5A, 0x07, &[0xD2, 0x3A]), 0x30)
+    }
+
+    #[test]
+    fn pec_covers_the_bytes_in_bus_order() {
+        // Block Write of two bytes: address+W, command, byte count, data
+        let mut crc = crc8_smbus();
+        let expected = crc.calculate_from_byte_array(&[0xB4, 0x22, 0x02, 0x12, 0x34]);
+        assert_eq!(write_pec(0x5A, 0x22, &[0x02, 0x12, 0x34]), expected);
+
+        // Read Byte: address+W, command, address+R, data
+        let mut crc = crc8_smbus();
+        let expected = crc.calculate_from_byte_array(&[0x16, 0x0D, 0x17, 0x55]);
+        assert_eq!(read_pec(0x0B, 0x0D, &[0x55]), expected)
+    }
+
+    #[test]
+    fn receive_byte_has_no_command() {
+        // Receive Byte: address+R, data
+        let mut crc = crc8_smbus();
+        let expected = crc.calculate_from_byte_array(&[0x91, 0xA5]);
+        assert_eq!(SmbusPec::new().read_address(0x48).data(&[0xA5]).pec(), expected)
+    }
+
+    #[test]
+    fn address_is_seven_bits() {
+        assert_eq!(write_pec(0xDA, 0x07, &[]), write_pec(0x5A, 0x07, &[]))
+    }
+
+    #[test]
+    fn appending_the_pec_leaves_zero() {
+        let data = [0xD2, 0x3A];
+        let pec = read_pec(0x5A, 0x07, &data);
+        assert_eq!(SmbusPec::new().write_address(0x5A).command(0x07).read_address(0x5A).data(&data).data(&[pec]).pec(), 0x00)
+    }
+}
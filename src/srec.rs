@@ -0,0 +1,441 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # S-record
+//!
+//! Helpers for the checksum of Motorola S-records. A record is a line of the form
+//! `STLLAAAA...DD...CC`: 'S', the record type digit, the byte count, the address (2, 3 or 4
+//! bytes depending on the type), the data and a checksum, as hexadecimal digit pairs. The
+//! byte count covers the address, data and checksum. The checksum is the ones' complement
+//! (bitwise inverse) of the low byte of the sum of the byte count, address and data, as
+//! computed by Check8Sum with a final XOR of 0xFF.
+//!
+//! SrecValidator checks the records of a file one line at a time, including the record
+//! count of an S5 or S6 record, so that large files can be validated while they are read.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Sum, Finalise};
+use crate::hex;
+
+/// # Variants
+///
+/// - MissingStart: The line does not start with 'S'.
+/// - InvalidType: The record type is not a digit from 0 to 9, or is the reserved type 4.
+/// - InvalidHex: The line contains a character that is not a hexadecimal digit, or an odd number of them.
+/// - TooLong: The address, data and checksum are longer than 255 bytes, the most the byte count can hold.
+/// - LengthMismatch: The byte count does not match the number of bytes in the line.
+/// - BadChecksum: The checksum does not match the other bytes of the record.
+/// - CountMismatch: The count in an S5 or S6 record does not match the number of data records before it.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SrecError {
+    MissingStart,
+    InvalidType,
+    InvalidHex,
+    TooLong,
+    LengthMismatch,
+    BadChecksum,
+    CountMismatch,
+}
+
+impl fmt::Display for SrecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SrecError::MissingStart => write!(f, "S-record does not start with 'S'"),
+            SrecError::InvalidType => write!(f, "invalid S-record type"),
+            SrecError::InvalidHex => write!(f, "S-record contains invalid hexadecimal digits"),
+            SrecError::TooLong => write!(f, "S-record data is too long"),
+            SrecError::LengthMismatch => write!(f, "S-record byte count does not match the record"),
+            SrecError::BadChecksum => write!(f, "S-record checksum does not match"),
+            SrecError::CountMismatch => write!(f, "S-record count does not match the number of data records"),
+        }
+    }
+}
+
+impl core::error::Error for SrecError {}
+
+/// An SrecError with the line number (from 1) at which it occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SrecLineError {
+    pub line: usize,
+    pub error: SrecError,
+}
+
+impl fmt::Display for SrecLineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl core::error::Error for SrecLineError {}
+
+/// # Variants
+///
+/// - Io: Reading the line with the given number (from 1) failed.
+/// - Record: A record was invalid.
+///
+/// The error of validate_reader. Only with the std feature.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SrecReadError {
+    Io {
+        line: usize,
+        #[cfg_attr(feature = "defmt", defmt(Debug2Format))]
+        kind: std::io::ErrorKind,
+    },
+    Record(SrecLineError),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for SrecReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SrecReadError::Io { line, kind } => write!(f, "line {}: error reading S-records: {}", line, kind),
+            SrecReadError::Record(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for SrecReadError {}
+
+#[cfg(feature = "std")]
+impl From<SrecLineError> for SrecReadError {
+    fn from(error: SrecLineError) -> Self {
+        SrecReadError::Record(error)
+    }
+}
+
+// the longest record: the byte count, then up to 255 bytes of address, data and checksum
+const MAX_RECORD_LEN: usize = 1 + 255;
+
+/// The fields of an S-record.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SrecRecord {
+    pub record_type: u8,
+    pub address: u32,
+    pub data: Vec<u8>,
+}
+
+/// The number of address bytes of a record type, or None for an invalid type.
+pub fn address_len(record_type: u8) -> Option<usize> {
+    match record_type {
+        0 | 1 | 5 | 9 => Some(2),
+        2 | 6 | 8 => Some(3),
+        3 | 7 => Some(4),
+        _ => None,
+    }
+}
+
+/// Calculates the checksum of a record from its fields.
+///
+/// - **Parameter**: record_type - The record type, 0 to 9 except 4.
+/// - **Parameter**: address - The address (or the record count of S5 and S6), which must fit the type's address field.
+/// - **Parameter**: data - The data bytes, at most 255 less the address length and the checksum.
+/// - **Returns**: The checksum byte, or SrecError::InvalidType or TooLong.
+pub fn record_checksum(record_type: u8, address: u32, data: &[u8]) -> Result<u8, SrecError> {
+    let address_len = address_len(record_type).ok_or(SrecError::InvalidType)?;
+    let count = u8::try_from(address_len + data.len() + 1).map_err(|_| SrecError::TooLong)?;
+    let mut sum = Check8Sum::with_finalise(0x00, Finalise::XorOut(0xFF));
+    sum.add(count);
+    sum.calculate_from_byte_array(&address.to_be_bytes()[4 - address_len..]);
+    Ok(sum.calculate_from_byte_array(data))
+}
+
+/// Formats a record as a line, without a line ending.
+///
+/// - **Parameter**: record_type - The record type, 0 to 9 except 4.
+/// - **Parameter**: address - The address (or the record count of S5 and S6).
+/// - **Parameter**: data - The data bytes, at most 255 less the address length and the checksum.
+/// - **Returns**: The record, e.g. "S9030000FC", or SrecError::InvalidType or TooLong.
+#[cfg(feature = "alloc")]
+pub fn format_record(record_type: u8, address: u32, data: &[u8]) -> Result<String, SrecError> {
+    let address_len = address_len(record_type).ok_or(SrecError::InvalidType)?;
+    let checksum = record_checksum(record_type, address, data)?;
+    let mut line = String::with_capacity(4 + 2 * (address_len + data.len() + 1));
+    line.push('S');
+    line.push((b'0' + record_type) as char);
+    hex::encode_into(&[(address_len + data.len() + 1) as u8], &mut line);
+    hex::encode_into(&address.to_be_bytes()[4 - address_len..], &mut line);
+    hex::encode_into(data, &mut line);
+    hex::encode_into(&[checksum], &mut line);
+    Ok(line)
+}
+
+/// Parses a record, validating its byte count and checksum.
+///
+/// - **Parameter**: line - The record, which may end with "\r\n".
+/// - **Returns**: The fields of the record, or an SrecError describing the fault.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::srec::{format_record, parse_record};
+/// fn main() {
+///     let record = parse_record("S111003848656C6C6F20776F726C642E0A0042").unwrap();
+///     assert_eq!(record.record_type, 1);
+///     assert_eq!(record.address, 0x0038);
+///     assert_eq!(record.data, b"Hello world.\n\0".to_vec());
+///     assert_eq!(format_record(9, 0x0000, &[]).unwrap(), "S9030000FC");
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub fn parse_record(line: &str) -> Result<SrecRecord, SrecError> {
+    let mut buf = [0u8; MAX_RECORD_LEN];
+    let (record_type, address_len, fields) = decode_record(line, &mut buf)?;
+    let (address_bytes, data) = fields.split_at(address_len);
+    let mut address = [0u8; 4];
+    address[4 - address_len..].copy_from_slice(address_bytes);
+    Ok(SrecRecord { record_type, address: u32::from_be_bytes(address), data: data.to_vec() })
+}
+
+/// Verifies the byte count and checksum of a record.
+///
+/// - **Parameter**: line - The record, which may end with "\r\n".
+/// - **Returns**: Ok if the record is intact, or an SrecError describing the fault.
+///
+/// The record is decoded into a buffer on the stack, so this needs no allocator.
+pub fn verify_record(line: &str) -> Result<(), SrecError> {
+    let mut buf = [0u8; MAX_RECORD_LEN];
+    decode_record(line, &mut buf).map(|_| ())
+}
+
+// Decodes a record into a buffer and checks its byte count and checksum, returning the
+// record type, the address length and the bytes of the address and data.
+fn decode_record<'a>(line: &str, buf: &'a mut [u8; MAX_RECORD_LEN]) -> Result<(u8, usize, &'a [u8]), SrecError> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let rest = line.strip_prefix('S').ok_or(SrecError::MissingStart)?;
+    let (record_type, digits) = match rest.as_bytes() {
+        [digit @ b'0'..=b'9', ..] => (digit - b'0', &rest[1..]),
+        _ => return Err(SrecError::InvalidType),
+    };
+    let address_len = address_len(record_type).ok_or(SrecError::InvalidType)?;
+    // a line too long for any byte count cannot match its own
+    if digits.len() > 2 * MAX_RECORD_LEN {
+        return Err(SrecError::LengthMismatch);
+    }
+    let bytes = hex::decode_into(digits, buf).ok_or(SrecError::InvalidHex)?;
+    let (count, fields, checksum) = match bytes {
+        [count, fields @ .., checksum] if fields.len() >= address_len => (*count, fields, *checksum),
+        _ => return Err(SrecError::LengthMismatch),
+    };
+    if count as usize != fields.len() + 1 {
+        return Err(SrecError::LengthMismatch);
+    }
+    // the checksum covers the byte count as well as the address and data
+    let mut sum = Check8Sum::with_finalise(count, Finalise::XorOut(0xFF));
+    if sum.calculate_from_byte_array(fields) != checksum {
+        return Err(SrecError::BadChecksum);
+    }
+    Ok((record_type, address_len, fields))
+}
+
+/// Validates the records of an S-record file one line at a time.
+///
+/// Blank lines are skipped. The number of S1, S2 and S3 data records is kept so that an S5
+/// or S6 count record can be checked against it.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::srec::SrecValidator;
+/// fn main() {
+///     let mut validator = SrecValidator::new();
+///     for line in ["S00F000068656C6C6F202020202000003C", "S111003848656C6C6F20776F726C642E0A0042",
+///                  "S5030001FB", "S9030000FC"] {
+///         validator.push_line(line).unwrap();
+///     }
+///     assert_eq!(validator.data_records(), 1);
+/// }
+/// ```
+///
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct SrecValidator {
+    lines: usize,
+    data_records: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl SrecValidator {
+    /// Creates a new validator, before the first line of a file.
+    pub fn new() -> Self {
+        SrecValidator { lines: 0, data_records: 0 }
+    }
+
+    /// Validates the next line of the file.
+    ///
+    /// - **Parameter**: line - The line, which may end with "\r\n".
+    /// - **Returns**: The record, None for a blank line, or an SrecLineError describing the fault.
+    pub fn push_line(&mut self, line: &str) -> Result<Option<SrecRecord>, SrecLineError> {
+        self.lines += 1;
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        let error = |error| SrecLineError { line: self.lines, error };
+        let record = parse_record(line).map_err(error)?;
+        match record.record_type {
+            1..=3 => self.data_records += 1,
+            5 | 6 if record.address != self.data_records => return Err(error(SrecError::CountMismatch)),
+            _ => {}
+        }
+        Ok(Some(record))
+    }
+
+    /// Retrieves the number of lines validated so far.
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    /// Retrieves the number of S1, S2 and S3 data records validated so far.
+    pub fn data_records(&self) -> u32 {
+        self.data_records
+    }
+}
+
+/// Validates every record read from a buffered reader, such as a file.
+///
+/// - **Parameter**: reader - The source of the lines.
+/// - **Returns**: The number of data records, or an SrecReadError for the first fault.
+#[cfg(feature = "std")]
+pub fn validate_reader<R: BufRead>(reader: R) -> Result<u32, SrecReadError> {
+    let mut validator = SrecValidator::new();
+    for line in reader.lines() {
+        let line = line.map_err(|e| SrecReadError::Io { line: validator.lines() + 1, kind: e.kind() })?;
+        validator.push_line(&line)?;
+    }
+    Ok(validator.data_records())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "S00F000068656C6C6F202020202000003C
+S11F00007C0802A6900100049421FFF07C6C1B787C8C23783C6000003863000026
+S11F001C4BFFFFE5398000007D83637880010014382100107C0803A64E800020E9
+S111003848656C6C6F20776F726C642E0A0042
+S5030003F9
+S9030000FC
+";
+
+    #[test]
+    fn known_records() {
+        for line in EXAMPLE.lines() {
+            assert_eq!(verify_record(line), Ok(()))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn all_address_sizes_round_trip() {
+        for (record_type, address) in [(0, 0x0000), (1, 0x1234), (2, 0x123456), (3, 0x12345678), (5, 3), (6, 0x010000),
+                                       (7, 0x08000000), (8, 0x080000), (9, 0x0100)] {
+            let line = format_record(record_type, address, &[0xDE, 0xAD]).unwrap();
+            let record = parse_record(&line).unwrap();
+            assert_eq!(record, SrecRecord { record_type, address, data: vec![0xDE, 0xAD] })
+        }
+        assert_eq!(format_record(3, 0x08000000, &[]).unwrap(), "S30508000000F2")
+    }
+
+    #[test]
+    fn checksum_is_inverted_sum() {
+        assert_eq!(record_checksum(9, 0x0000, &[]), Ok(0xFC));
+        assert_eq!(record_checksum(5, 0x0003, &[]), Ok(0xF9));
+        assert_eq!(record_checksum(4, 0x0000, &[]), Err(SrecError::InvalidType))
+    }
+
+    #[test]
+    fn verify_reports_faults() {
+        assert_eq!(verify_record("9030000FC"), Err(SrecError::MissingStart));
+        assert_eq!(verify_record("S4030000FC"), Err(SrecError::InvalidType));
+        assert_eq!(verify_record("SX030000FC"), Err(SrecError::InvalidType));
+        assert_eq!(verify_record("S9030000F"), Err(SrecError::InvalidHex));
+        assert_eq!(verify_record("S9040000FC"), Err(SrecError::LengthMismatch));
+        assert_eq!(verify_record("S9030000FD"), Err(SrecError::BadChecksum))
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_reader_counts_data_records() {
+        assert_eq!(validate_reader(EXAMPLE.as_bytes()), Ok(3))
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_reader_reports_line_of_fault() {
+        let corrupted = EXAMPLE.replace("S111003848656C6C6F20776F726C642E0A0042", "S111003848656C6C6F20776F726C642E0A0043");
+        assert_eq!(validate_reader(corrupted.as_bytes()), Err(SrecReadError::Record(SrecLineError { line: 4, error: SrecError::BadChecksum })));
+
+        let wrong_count = EXAMPLE.replace("S5030003F9", "S5030002FA");
+        assert_eq!(validate_reader(wrong_count.as_bytes()), Err(SrecReadError::Record(SrecLineError { line: 5, error: SrecError::CountMismatch })))
+    }
+
+    #[test]
+    fn data_too_long_for_the_byte_count_is_rejected() {
+        assert_eq!(record_checksum(1, 0, &[0; 252]), Ok(0x00));
+        assert_eq!(record_checksum(1, 0, &[0; 253]), Err(SrecError::TooLong));
+        assert_eq!(record_checksum(3, 0, &[0; 251]), Err(SrecError::TooLong))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn overlong_lines_are_a_length_mismatch() {
+        let mut line = String::from("S1FF0000");
+        line.extend(core::iter::repeat_n("00", 300));
+        assert_eq!(verify_record(&line), Err(SrecError::LengthMismatch))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn longest_record_round_trips() {
+        let line = format_record(1, 0x1234, &[0xA5; 252]).unwrap();
+        assert_eq!(verify_record(&line), Ok(()));
+        assert_eq!(parse_record(&line).unwrap().data, [0xA5; 252]);
+        assert_eq!(format_record(1, 0x1234, &[0xA5; 253]), Err(SrecError::TooLong))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn validator_skips_blank_lines() {
+        let mut validator = SrecValidator::new();
+        assert_eq!(validator.push_line("\r\n"), Ok(None));
+        assert!(validator.push_line("S9030000FC").unwrap().is_some());
+        assert_eq!(validator.lines(), 2)
+    }
+}
@@ -0,0 +1,245 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # TFmini
+//!
+//! Helpers for the frame checksum of Benewake TFmini and TFmini-Plus LiDAR sensors. In the
+//! standard output format each measurement is a 9-byte frame: the header 0x59 0x59, the
+//! distance and signal strength as little-endian 16-bit values, two further bytes (the
+//! temperature on the TFmini-Plus), and a checksum, the low 8 bits of the sum of the first
+//! 8 bytes, as computed by Check8Sum.
+//!
+//! TfminiScanner finds the frames in a serial byte stream, resynchronising after noise or
+//! dropped bytes by discarding bytes until a frame with a valid checksum lines up.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Sum;
+
+/// The header byte, which starts each frame twice.
+pub const TFMINI_HEADER: u8 = 0x59;
+
+/// # Variants
+///
+/// - BadHeader: The frame does not start with 0x59 0x59.
+/// - BadChecksum: The checksum does not match the first 8 bytes.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TfminiError {
+    BadHeader,
+    BadChecksum,
+}
+
+impl fmt::Display for TfminiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TfminiError::BadHeader => write!(f, "TFmini frame does not start with 0x59 0x59"),
+            TfminiError::BadChecksum => write!(f, "TFmini frame checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for TfminiError {}
+
+/// The measurement carried by a valid frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TfminiFrame {
+    pub distance: u16,
+    pub strength: u16,
+    pub extra: u16,
+}
+
+/// Calculates the checksum of the first 8 bytes of a frame.
+pub fn frame_checksum(frame: &[u8; 9]) -> u8 {
+    Check8Sum::new(0x00).calculate_from_byte_array(&frame[..8])
+}
+
+/// Validates a frame and extracts its measurement.
+///
+/// - **Parameter**: frame - The 9 bytes of the frame.
+/// - **Returns**: The measurement, or a TfminiError describing the fault.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::tfmini::parse_frame;
+/// fn main() {
+///     // 200 cm at strength 1000
+///     let frame = parse_frame(&[0x59, 0x59, 0xC8, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x65]).unwrap();
+///     assert_eq!(frame.distance, 200);
+///     assert_eq!(frame.strength, 1000);
+/// }
+/// ```
+///
+pub fn parse_frame(frame: &[u8; 9]) -> Result<TfminiFrame, TfminiError> {
+    if frame[0] != TFMINI_HEADER || frame[1] != TFMINI_HEADER {
+        return Err(TfminiError::BadHeader);
+    }
+    if frame_checksum(frame) != frame[8] {
+        return Err(TfminiError::BadChecksum);
+    }
+    Ok(TfminiFrame {
+        distance: u16::from_le_bytes([frame[2], frame[3]]),
+        strength: u16::from_le_bytes([frame[4], frame[5]]),
+        extra: u16::from_le_bytes([frame[6], frame[7]]),
+    })
+}
+
+/// Finds valid frames in a stream of bytes, one byte at a time.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::tfmini::TfminiScanner;
+/// fn main() {
+///     // a partial frame, then a whole one
+///     let stream = [0x03, 0x00, 0x25, 0x59, 0x59, 0xC8, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x65];
+///     let frames = TfminiScanner::new().scan(&stream);
+///     assert_eq!(frames.len(), 1);
+///     assert_eq!(frames[0].distance, 200);
+/// }
+/// ```
+///
+#[derive(Clone, Debug, Default)]
+pub struct TfminiScanner {
+    buffer: [u8; 9],
+    len: usize,
+}
+
+impl TfminiScanner {
+    /// Creates a new scanner with an empty buffer.
+    pub fn new() -> Self {
+        TfminiScanner { buffer: [0u8; 9], len: 0 }
+    }
+
+    // drops the oldest byte from the buffer
+    fn discard(&mut self) {
+        self.buffer.copy_within(1..self.len, 0);
+        self.len -= 1;
+    }
+
+    /// Adds the next byte of the stream.
+    ///
+    /// - **Parameter**: val - The byte received.
+    /// - **Returns**: The measurement, if the byte completes a valid frame.
+    pub fn push(&mut self, val: u8) -> Option<TfminiFrame> {
+        self.buffer[self.len] = val;
+        self.len += 1;
+        loop {
+            // the buffer must start with as much of the header as it holds
+            if self.buffer[0] != TFMINI_HEADER || (self.len >= 2 && self.buffer[1] != TFMINI_HEADER) {
+                self.discard();
+                if self.len == 0 {
+                    return None;
+                }
+                continue;
+            }
+            if self.len < 9 {
+                return None;
+            }
+            match parse_frame(&self.buffer) {
+                Ok(frame) => {
+                    self.len = 0;
+                    return Some(frame);
+                }
+                // the header was a coincidence, so look for one later in the buffer
+                Err(_) => self.discard(),
+            }
+        }
+    }
+
+    /// Adds bytes of the stream, collecting the measurements of the frames they complete.
+    #[cfg(feature = "alloc")]
+    pub fn scan(&mut self, data: &[u8]) -> Vec<TfminiFrame> {
+        data.iter().filter_map(|val| self.push(*val)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(distance: u16, strength: u16) -> [u8; 9] {
+        let mut frame = [TFMINI_HEADER, TFMINI_HEADER, 0, 0, 0, 0, 0x00, 0x09, 0];
+        frame[2..4].copy_from_slice(&distance.to_le_bytes());
+        frame[4..6].copy_from_slice(&strength.to_le_bytes());
+        frame[8] = frame_checksum(&frame);
+        frame
+    }
+
+    #[test]
+    fn parse_reports_faults() {
+        let mut bytes = frame(150, 2000);
+        assert_eq!(parse_frame(&bytes), Ok(TfminiFrame { distance: 150, strength: 2000, extra: 0x0900 }));
+        bytes[3] ^= 0x01;
+        assert_eq!(parse_frame(&bytes), Err(TfminiError::BadChecksum));
+        bytes[0] = 0x00;
+        assert_eq!(parse_frame(&bytes), Err(TfminiError::BadHeader))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scanner_finds_consecutive_frames() {
+        let mut stream = Vec::new();
+        for distance in [100, 101, 102] {
+            stream.extend_from_slice(&frame(distance, 500));
+        }
+        let distances: Vec<u16> = TfminiScanner::new().scan(&stream).iter().map(|f| f.distance).collect();
+        assert_eq!(distances, vec![100, 101, 102])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scanner_resynchronises_after_noise() {
+        let mut stream = vec![0x59, 0x00, 0x59];
+        // a corrupted frame, then good ones
+        let mut corrupted = frame(300, 500);
+        corrupted[4] ^= 0x40;
+        stream.extend_from_slice(&corrupted);
+        stream.extend_from_slice(&frame(301, 500));
+        // a dropped byte
+        stream.extend_from_slice(&frame(302, 500)[1..]);
+        stream.extend_from_slice(&frame(303, 500));
+        let distances: Vec<u16> = TfminiScanner::new().scan(&stream).iter().map(|f| f.distance).collect();
+        assert_eq!(distances, vec![301, 303])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scanner_handles_header_bytes_in_data() {
+        // distance 0x5959 puts header bytes inside the frame
+        let mut stream = vec![0x59];
+        stream.extend_from_slice(&frame(0x5959, 0x5959));
+        let frames = TfminiScanner::new().scan(&stream);
+        assert_eq!(frames, vec![TfminiFrame { distance: 0x5959, strength: 0x5959, extra: 0x0900 }])
+    }
+}
@@ -0,0 +1,165 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # UART
+//!
+//! Helpers for the parity bit of UART framing: the bit for a single byte, the bits for each
+//! byte of a slice as a bitmap, and 9-bit words with the parity bit above the data bits.
+//! Mark and space parity fix the ninth bit at 1 or 0, as used to flag address bytes in
+//! 9-bit multiprocessor communication.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// The parity scheme for the bit sent after the data bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UartParity {
+    Even,
+    Odd,
+    Mark,
+    Space,
+}
+
+/// Calculates the parity bit for a byte.
+///
+/// - **Parameter**: val - The data byte.
+/// - **Parameter**: parity - The parity scheme.
+/// - **Returns**: The parity bit, 0 or 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::uart::{parity_bit, UartParity};
+/// fn main() {
+///     // 0x07 has three bits set
+///     assert_eq!(parity_bit(0x07, UartParity::Even), 1);
+///     assert_eq!(parity_bit(0x07, UartParity::Odd), 0);
+///     assert_eq!(parity_bit(0x07, UartParity::Mark), 1);
+///     assert_eq!(parity_bit(0x07, UartParity::Space), 0);
+/// }
+/// ```
+///
+pub fn parity_bit(val: u8, parity: UartParity) -> u8 {
+    let odd_count = (val.count_ones() & 0x01) as u8;
+    match parity {
+        UartParity::Even => odd_count,
+        UartParity::Odd => odd_count ^ 0x01,
+        UartParity::Mark => 1,
+        UartParity::Space => 0,
+    }
+}
+
+/// Calculates the parity bit for each byte of a slice, packed into a bitmap.
+///
+/// The bit for byte i is bit (i % 8) of bitmap byte (i / 8).
+#[cfg(feature = "alloc")]
+pub fn parity_bitmap(array: &[u8], parity: UartParity) -> Vec<u8> {
+    let mut bitmap = vec![0u8; array.len().div_ceil(8)];
+    for (i, val) in array.iter().enumerate() {
+        bitmap[i / 8] |= parity_bit(*val, parity) << (i % 8);
+    }
+    bitmap
+}
+
+/// Encodes a byte as a 9-bit word, with the parity bit as bit 8.
+pub fn encode_9bit(val: u8, parity: UartParity) -> u16 {
+    val as u16 | (parity_bit(val, parity) as u16) << 8
+}
+
+/// Encodes each byte of a slice as a 9-bit word, with the parity bit as bit 8.
+#[cfg(feature = "alloc")]
+pub fn encode_9bit_words(array: &[u8], parity: UartParity) -> Vec<u16> {
+    array.iter().map(|val| encode_9bit(*val, parity)).collect()
+}
+
+/// Decodes a 9-bit word, returning the data byte if its parity bit is correct.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::uart::{decode_9bit, encode_9bit, UartParity};
+/// fn main() {
+///     let word = encode_9bit(0x41, UartParity::Odd);
+///     assert_eq!(decode_9bit(word, UartParity::Odd), Some(0x41));
+///     assert_eq!(decode_9bit(word ^ 0x0001, UartParity::Odd), None);
+/// }
+/// ```
+///
+pub fn decode_9bit(word: u16, parity: UartParity) -> Option<u8> {
+    let val = word as u8;
+    if word > 0x1FF || (word >> 8) as u8 != parity_bit(val, parity) {
+        None
+    } else {
+        Some(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parity_bit_makes_total_even_or_odd() {
+        for val in 0..=255u8 {
+            assert_eq!((val.count_ones() + parity_bit(val, UartParity::Even) as u32) % 2, 0);
+            assert_eq!((val.count_ones() + parity_bit(val, UartParity::Odd) as u32) % 2, 1);
+        }
+    }
+
+    #[test]
+    fn mark_and_space_are_fixed() {
+        for val in [0x00, 0x01, 0xFF] {
+            assert_eq!(parity_bit(val, UartParity::Mark), 1);
+            assert_eq!(parity_bit(val, UartParity::Space), 0);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn parity_bitmap_packs_bits_in_order() {
+        // bytes with an odd count of set bits get an even parity bit of 1
+        let data = [0x01, 0x03, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80];
+        assert_eq!(parity_bitmap(&data, UartParity::Even), vec![0b0000_0101, 0b0000_0001]);
+        assert!(parity_bitmap(&[], UartParity::Even).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_9bit_words_sets_bit_8() {
+        assert_eq!(encode_9bit_words(&[0x01, 0x03], UartParity::Even), vec![0x101, 0x003]);
+        assert_eq!(encode_9bit_words(&[0x01, 0x03], UartParity::Mark), vec![0x101, 0x103]);
+    }
+
+    #[test]
+    fn decode_9bit_rejects_bad_parity_and_out_of_range_words() {
+        assert_eq!(decode_9bit(0x101, UartParity::Even), Some(0x01));
+        assert_eq!(decode_9bit(0x001, UartParity::Even), None);
+        assert_eq!(decode_9bit(0x301, UartParity::Even), None);
+        assert_eq!(decode_9bit(0x0AA, UartParity::Space), Some(0xAA));
+    }
+}
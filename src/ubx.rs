@@ -0,0 +1,220 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # UBX
+//!
+//! Helpers for the checksum of u-blox UBX protocol frames. A frame is the sync characters
+//! 0xB5 0x62, the message class and id, a little-endian 16-bit payload length, the payload,
+//! and the checksum bytes CK_A and CK_B: an 8-bit Fletcher checksum over the class, id,
+//! length and payload. CK_A is the wrapping sum of the bytes and CK_B the wrapping sum of
+//! the successive values of CK_A, so the checksum is two coupled Check8Sum accumulators.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Sum;
+
+/// The two sync characters which start every frame.
+pub const UBX_SYNC: [u8; 2] = [0xB5, 0x62];
+
+/// # Variants
+///
+/// - TooShort: The frame is shorter than its header and checksum.
+/// - TooLong: The payload is longer than 65535 bytes, the most the length field can hold.
+/// - BadSync: The frame does not start with the sync characters.
+/// - LengthMismatch: The length field does not match the number of bytes in the frame.
+/// - BadChecksum: The checksum bytes do not match the checksum of the frame.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UbxError {
+    TooShort,
+    TooLong,
+    BadSync,
+    LengthMismatch,
+    BadChecksum,
+}
+
+impl fmt::Display for UbxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UbxError::TooShort => write!(f, "UBX frame is too short"),
+            UbxError::TooLong => write!(f, "UBX payload is too long"),
+            UbxError::BadSync => write!(f, "UBX frame does not start with the sync characters"),
+            UbxError::LengthMismatch => write!(f, "UBX length field does not match the frame"),
+            UbxError::BadChecksum => write!(f, "UBX frame checksum does not match"),
+        }
+    }
+}
+
+impl core::error::Error for UbxError {}
+
+/// Calculates the UBX checksum of a sequence of bytes.
+///
+/// - **Parameter**: bytes - The bytes covered by the checksum, from the class to the end of the payload.
+/// - **Returns**: The checksum bytes (CK_A, CK_B).
+pub fn checksum_of(bytes: &[u8]) -> (u8, u8) {
+    let mut ck_a = Check8Sum::new(0x00);
+    let mut ck_b = Check8Sum::new(0x00);
+    for val in bytes {
+        ck_b.add(ck_a.add(*val));
+    }
+    (ck_a.get_checksum(), ck_b.get_checksum())
+}
+
+/// Calculates the UBX checksum of a message from its class, id and payload.
+///
+/// - **Parameter**: class - The message class.
+/// - **Parameter**: id - The message id.
+/// - **Parameter**: payload - The payload bytes, at most 65535.
+/// - **Returns**: The checksum bytes (CK_A, CK_B), or UbxError::TooLong.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::ubx::checksum;
+/// fn main() {
+///     // UBX-MON-VER poll, B5 62 0A 04 00 00 0E 34
+///     assert_eq!(checksum(0x0A, 0x04, &[]), Ok((0x0E, 0x34)));
+/// }
+/// ```
+///
+pub fn checksum(class: u8, id: u8, payload: &[u8]) -> Result<(u8, u8), UbxError> {
+    let length = u16::try_from(payload.len()).map_err(|_| UbxError::TooLong)?.to_le_bytes();
+    let (header_a, header_b) = checksum_of(&[class, id, length[0], length[1]]);
+    let (payload_a, payload_b) = checksum_of(payload);
+    // carrying on from the header adds its CK_A into CK_B once for every payload byte; the
+    // length is only needed modulo 256, so truncating it is exact
+    let carried = header_a.wrapping_mul(payload.len() as u8);
+    Ok((header_a.wrapping_add(payload_a), header_b.wrapping_add(carried).wrapping_add(payload_b)))
+}
+
+/// Builds a complete UBX frame, from the sync characters to the checksum.
+///
+/// - **Parameter**: class - The message class.
+/// - **Parameter**: id - The message id.
+/// - **Parameter**: payload - The payload bytes, at most 65535.
+/// - **Returns**: The frame, or UbxError::TooLong.
+#[cfg(feature = "alloc")]
+pub fn build_frame(class: u8, id: u8, payload: &[u8]) -> Result<Vec<u8>, UbxError> {
+    let length = u16::try_from(payload.len()).map_err(|_| UbxError::TooLong)?;
+    let mut frame = Vec::with_capacity(payload.len() + 8);
+    frame.extend_from_slice(&UBX_SYNC);
+    frame.extend_from_slice(&[class, id]);
+    frame.extend_from_slice(&length.to_le_bytes());
+    frame.extend_from_slice(payload);
+    let (ck_a, ck_b) = checksum_of(&frame[2..]);
+    frame.extend_from_slice(&[ck_a, ck_b]);
+    Ok(frame)
+}
+
+/// Checks the sync characters, length field and checksum of a complete UBX frame.
+///
+/// - **Parameter**: frame - The frame, from the sync characters to CK_B.
+/// - **Returns**: Ok if the frame is intact, or a UbxError describing the fault.
+pub fn verify_frame(frame: &[u8]) -> Result<(), UbxError> {
+    if frame.len() < 8 {
+        return Err(UbxError::TooShort);
+    }
+    if frame[..2] != UBX_SYNC {
+        return Err(UbxError::BadSync);
+    }
+    let length = u16::from_le_bytes([frame[4], frame[5]]) as usize;
+    if length != frame.len() - 8 {
+        return Err(UbxError::LengthMismatch);
+    }
+    let body = &frame[2..frame.len() - 2];
+    if checksum_of(body) != (frame[frame.len() - 2], frame[frame.len() - 1]) {
+        return Err(UbxError::BadChecksum);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn poll_messages() {
+        assert_eq!(build_frame(0x06, 0x00, &[]).unwrap(), vec![0xB5, 0x62, 0x06, 0x00, 0x00, 0x00, 0x06, 0x18]);  // CFG-PRT
+        assert_eq!(build_frame(0x0A, 0x04, &[]).unwrap(), vec![0xB5, 0x62, 0x0A, 0x04, 0x00, 0x00, 0x0E, 0x34]);  // MON-VER
+        assert_eq!(build_frame(0x01, 0x07, &[]).unwrap(), vec![0xB5, 0x62, 0x01, 0x07, 0x00, 0x00, 0x08, 0x19])   // NAV-PVT
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn checksum_covers_length_and_payload() {
+        let payload = [0x01, 0x00, 0x00, 0x00, 0xD0, 0x08, 0x00, 0x00];
+        let frame = build_frame(0x06, 0x00, &payload).unwrap();
+        assert_eq!(frame[4..6], [0x08, 0x00]);
+        assert_eq!(checksum(0x06, 0x00, &payload), Ok((frame[14], frame[15])));
+        assert_eq!(verify_frame(&frame), Ok(()))
+    }
+
+    #[test]
+    fn ck_b_is_order_sensitive() {
+        let (a1, b1) = checksum_of(&[0x01, 0x02]);
+        let (a2, b2) = checksum_of(&[0x02, 0x01]);
+        assert_eq!(a1, a2);
+        assert_ne!(b1, b2)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_reports_faults() {
+        let frame = build_frame(0x01, 0x07, &[0xAA, 0x55]).unwrap();
+        let mut corrupted = frame.clone();
+        corrupted[6] ^= 0x01;
+        assert_eq!(verify_frame(&corrupted), Err(UbxError::BadChecksum));
+        let mut corrupted = frame.clone();
+        corrupted[0] = 0x00;
+        assert_eq!(verify_frame(&corrupted), Err(UbxError::BadSync));
+        assert_eq!(verify_frame(&frame[..frame.len() - 1]), Err(UbxError::LengthMismatch));
+        assert_eq!(verify_frame(&frame[..7]), Err(UbxError::TooShort))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn checksum_of_long_payloads_matches_the_frame() {
+        let payload: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+        let frame = build_frame(0x02, 0x15, &payload).unwrap();
+        assert_eq!(checksum(0x02, 0x15, &payload), Ok((frame[frame.len() - 2], frame[frame.len() - 1])))
+    }
+
+    #[test]
+    fn payloads_too_long_for_the_length_field_are_rejected() {
+        let payload = [0u8; 65536];
+        assert_eq!(checksum(0x02, 0x15, &payload), Err(UbxError::TooLong));
+        #[cfg(feature = "alloc")]
+        assert_eq!(build_frame(0x02, 0x15, &payload), Err(UbxError::TooLong));
+        assert!(checksum(0x02, 0x15, &payload[..65535]).is_ok())
+    }
+}
@@ -0,0 +1,112 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # VE.Direct
+//!
+//! Helpers for the checksum of Victron VE.Direct text protocol blocks. A block is a series of
+//! fields, each "\r\n", a label, a tab and a value, ending with a "Checksum" field whose value
+//! is a single byte chosen so that all of the bytes of the block sum to zero modulo 256. The
+//! checksum byte can be any value, including unprintable ones.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::{Check8Lrc, Check8Sum};
+
+/// The label and separator which precede the checksum byte.
+pub const CHECKSUM_FIELD: &[u8] = b"\r\nChecksum\t";
+
+/// Calculates the checksum byte for a block.
+///
+/// - **Parameter**: block - The block up to the checksum byte, normally ending with "\r\nChecksum\t".
+/// - **Returns**: The byte which makes the block sum to zero.
+pub fn checksum_value_for(block: &[u8]) -> u8 {
+    Check8Lrc::new(0x00).calculate_from_byte_array(block)
+}
+
+/// Verifies a complete block, including its checksum byte.
+///
+/// - **Parameter**: block - The block, from the "\r\n" of its first field to the checksum byte.
+/// - **Returns**: true if the block ends with a checksum field and all of its bytes sum to zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::vedirect::{append_checksum, verify_block};
+/// fn main() {
+///     let mut block = b"\r\nPID\t0xA053\r\nV\t12800\r\nI\t-1500".to_vec();
+///     append_checksum(&mut block);
+///     assert!(verify_block(&block));
+/// }
+/// ```
+///
+pub fn verify_block(block: &[u8]) -> bool {
+    block.len() > CHECKSUM_FIELD.len()
+        && block[..block.len() - 1].ends_with(CHECKSUM_FIELD)
+        && Check8Sum::new(0x00).calculate_from_byte_array(block) == 0x00
+}
+
+/// Appends the checksum field, and its checksum byte, to a block.
+#[cfg(feature = "alloc")]
+pub fn append_checksum(block: &mut Vec<u8>) {
+    block.extend_from_slice(CHECKSUM_FIELD);
+    block.push(checksum_value_for(block));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_makes_block_sum_to_zero() {
+        let block = b"\r\nV\t12800\r\nChecksum\t";
+        let value = checksum_value_for(block);
+        let total = block.iter().fold(value, |acc, val| acc.wrapping_add(*val));
+        assert_eq!(total, 0x00)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn verify_complete_blocks() {
+        let mut block = b"\r\nPID\t0xA053\r\nFW\t159\r\nSER#\tHQ1328A1B2C\r\nV\t13790\r\nI\t-10\r\nVPV\t15950\r\nPPV\t0\r\nCS\t5".to_vec();
+        append_checksum(&mut block);
+        assert!(verify_block(&block));
+
+        block[5] ^= 0x01;
+        assert!(!verify_block(&block))
+    }
+
+    #[test]
+    fn verify_requires_checksum_field() {
+        // sums to zero, but has no checksum field
+        let mut block = b"\r\nV\t12800".to_vec();
+        block.push(checksum_value_for(&block));
+        assert!(!verify_block(&block));
+        assert!(!verify_block(b""))
+    }
+}
@@ -0,0 +1,134 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+//! # WebAssembly bindings
+//!
+//! wasm-bindgen wrappers for JavaScript, so that code in a browser, such as a log viewer
+//! checking device frames, runs the same implementations as the Rust side. Available with the
+//! wasm feature, which implies alloc and presets.
+//!
+//! Checksum is constructed from the name of an algorithm: sum, xor, xor-rotate, lrc, bsd,
+//! parity, ones-complement, fletcher, pearson or roland for the checksum type of that name with
+//! its Default parameters, or the name of any CRC in the presets module, e.g. "CRC-8/SMBUS".
+//! Names are not case-sensitive.
+//!
+//! update takes a Uint8Array and finalize returns the checksum as a number. An unknown name
+//! throws an Error. algorithms lists the names.
+//!
+//! The module is built for the browser with
+//! `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`,
+//! followed by wasm-bindgen to generate the JavaScript glue.
+//!
+//! # Examples
+//!
+//! ```js
+//! import { Checksum, checksum } from "./pkg/check8.js";
+//!
+//! const crc = new Checksum("CRC-8/SMBUS");
+//! crc.update(new TextEncoder().encode("1234"));
+//! crc.update(new TextEncoder().encode("56789"));
+//! console.log(crc.finalize().toString(16));  // f4
+//!
+//! console.log(checksum("xor", frame.subarray(0, -1)) === frame.at(-1));
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::check8registry::{by_name, names, AnyChecksum};
+
+/// A running checksum for JavaScript, exported as Checksum.
+#[wasm_bindgen(js_name = Checksum)]
+pub struct WasmChecksum {
+    checksum: AnyChecksum,
+    initial: u8,
+}
+
+#[wasm_bindgen(js_class = Checksum)]
+impl WasmChecksum {
+    /// Creates a checksum for an algorithm name, throwing an Error for an unknown name.
+    #[wasm_bindgen(constructor)]
+    pub fn new(algorithm: &str) -> Result<WasmChecksum, JsError> {
+        Self::from_name(algorithm).ok_or_else(|| JsError::new(&format!("unknown checksum algorithm {}", algorithm)))
+    }
+
+    /// Adds the bytes of a Uint8Array and returns the checksum so far.
+    pub fn update(&mut self, data: &[u8]) -> u8 {
+        self.checksum.calculate_from_byte_array(data)
+    }
+
+    /// Retrieves the checksum, after any finalisation. The checksum can still be updated.
+    pub fn finalize(&self) -> u8 {
+        self.checksum.get_checksum()
+    }
+
+    /// Returns the checksum to the state it was created in.
+    pub fn reset(&mut self) -> u8 {
+        self.checksum.init(self.initial)
+    }
+}
+
+impl WasmChecksum {
+    fn from_name(algorithm: &str) -> Option<WasmChecksum> {
+        let checksum = by_name(algorithm)?;
+        let initial = checksum.get_accum();
+        Some(WasmChecksum { checksum, initial })
+    }
+}
+
+/// Calculates the checksum of a Uint8Array in one call, throwing an Error for an unknown
+/// algorithm name.
+#[wasm_bindgen]
+pub fn checksum(algorithm: &str, data: &[u8]) -> Result<u8, JsError> {
+    let mut checksum = WasmChecksum::new(algorithm)?;
+    Ok(checksum.update(data))
+}
+
+/// Lists the algorithm names Checksum accepts: the simple checksums, then the CRC presets.
+#[wasm_bindgen]
+pub fn algorithms() -> Vec<String> {
+    names().map(String::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_finalize_and_reset() {
+        let mut crc = WasmChecksum::new("CRC-8/MAXIM-DOW").ok().unwrap();
+        crc.update(b"1234");
+        assert_eq!(crc.update(b"56789"), 0xA1);
+        assert_eq!(crc.finalize(), 0xA1);
+        crc.reset();
+        crc.update(b"123456789");
+        assert_eq!(crc.finalize(), 0xA1);
+        assert_eq!(checksum("xor", &[0x01, 0x02]).ok(), Some(0x03));
+        assert!(algorithms().iter().any(|name| name == "CRC-8/SMBUS"))
+    }
+}
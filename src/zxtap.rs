@@ -0,0 +1,261 @@
+/*
+
+MIT License
+
+Copyright (c) 2025 Tony Hedge, Benthic Sciences LLP
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+
+*/
+
+//! # ZX TAP
+//!
+//! Helpers for the checksum of ZX Spectrum tape blocks. Each block in a .tap image is a
+//! little-endian 16-bit length followed by that many bytes: a flag byte (0x00 for a header,
+//! 0xFF for data), the data, and a parity byte, the XOR of the flag and the data, as
+//! computed by Check8Xor. TZX standard speed data blocks (ID 0x10) carry the same bytes, so
+//! verify_block applies to them too.
+//!
+//! TapBlocks walks a whole .tap image, so an archive can be checked for damaged blocks.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Check8;          // for the Check8 trait
+use crate::Checksum;        // for the Checksum trait
+use crate::Check8Xor;
+
+/// # Variants
+///
+/// - Truncated: The block starting at the given offset runs past the end of the image.
+/// - TooLarge: The data is too long for the 16-bit block length.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TapError {
+    Truncated { offset: usize },
+    TooLarge,
+}
+
+impl fmt::Display for TapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TapError::Truncated { offset } => write!(f, "TAP block at offset {} is truncated", offset),
+            TapError::TooLarge => write!(f, "data too long for a TAP block"),
+        }
+    }
+}
+
+impl core::error::Error for TapError {}
+
+/// Calculates the parity byte of a block.
+pub fn block_checksum(flag: u8, data: &[u8]) -> u8 {
+    let mut xor = Check8Xor::new(flag);
+    xor.calculate_from_byte_array(data)
+}
+
+/// Checks a block from its flag byte to its parity byte, without the length prefix.
+///
+/// - **Parameter**: block - The flag byte, the data and the parity byte.
+/// - **Returns**: true if the XOR of all the bytes is zero.
+///
+pub fn verify_block(block: &[u8]) -> bool {
+    block.len() >= 2 && Check8Xor::new(0x00).calculate_from_byte_array(block) == 0x00
+}
+
+/// Builds a block, with its length prefix, ready to append to a .tap image.
+///
+/// - **Parameter**: flag - The flag byte.
+/// - **Parameter**: data - The data.
+/// - **Returns**: The block, or TapError::TooLarge.
+///
+#[cfg(feature = "alloc")]
+pub fn encode_block(flag: u8, data: &[u8]) -> Result<Vec<u8>, TapError> {
+    let len = u16::try_from(data.len() + 2).map_err(|_| TapError::TooLarge)?;
+    let mut block = Vec::with_capacity(data.len() + 4);
+    block.extend_from_slice(&len.to_le_bytes());
+    block.push(flag);
+    block.extend_from_slice(data);
+    block.push(block_checksum(flag, data));
+    Ok(block)
+}
+
+/// A block of a .tap image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TapBlock<'a> {
+    /// The offset of the block's length prefix in the image.
+    pub offset: usize,
+    /// The flag byte, the data and the parity byte.
+    pub bytes: &'a [u8],
+}
+
+impl<'a> TapBlock<'a> {
+    /// The flag byte, or None for an empty block.
+    pub fn flag(&self) -> Option<u8> {
+        self.bytes.first().copied()
+    }
+
+    /// The data between the flag and parity bytes.
+    pub fn data(&self) -> &'a [u8] {
+        if self.bytes.len() < 2 { &[] } else { &self.bytes[1..self.bytes.len() - 1] }
+    }
+
+    /// Whether the parity byte matches.
+    pub fn is_valid(&self) -> bool {
+        verify_block(self.bytes)
+    }
+}
+
+/// An iterator over the blocks of a .tap image.
+///
+/// After a truncated block it yields the error and then stops.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::check8::zxtap::{encode_block, TapBlocks};
+/// fn main() {
+///     let mut image = encode_block(0x00, &[0x03; 17]).unwrap();
+///     image.extend(encode_block(0xFF, b"HELLO").unwrap());
+///     let last = image.len() - 1;
+///     image[last] ^= 0x01;
+///     let failing: Vec<usize> = TapBlocks::new(&image)
+///         .enumerate()
+///         .filter(|(_, block)| !block.map(|b| b.is_valid()).unwrap_or(false))
+///         .map(|(index, _)| index)
+///         .collect();
+///     assert_eq!(failing, vec![1]);
+/// }
+/// ```
+///
+pub struct TapBlocks<'a> {
+    image: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> TapBlocks<'a> {
+    /// Creates an iterator over the blocks of a .tap image.
+    pub fn new(image: &'a [u8]) -> Self {
+        TapBlocks { image, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for TapBlocks<'a> {
+    type Item = Result<TapBlock<'a>, TapError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        let rest = &self.image[offset..];
+        if rest.is_empty() {
+            return None;
+        }
+        let end = match rest {
+            [lo, hi, ..] => 2 + u16::from_le_bytes([*lo, *hi]) as usize,
+            _ => usize::MAX,
+        };
+        if end > rest.len() {
+            self.offset = self.image.len();
+            return Some(Err(TapError::Truncated { offset }));
+        }
+        self.offset += end;
+        Some(Ok(TapBlock { offset, bytes: &rest[2..end] }))
+    }
+}
+
+/// Lists the blocks of a .tap image whose parity byte does not match.
+///
+/// - **Parameter**: image - The .tap image.
+/// - **Returns**: The indices of the failing blocks, or TapError::Truncated.
+///
+#[cfg(feature = "alloc")]
+pub fn failing_blocks(image: &[u8]) -> Result<Vec<usize>, TapError> {
+    let mut failing = Vec::new();
+    for (index, block) in TapBlocks::new(image).enumerate() {
+        if !block?.is_valid() {
+            failing.push(index);
+        }
+    }
+    Ok(failing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a program header for "test", 10 bytes long, autostart line 10
+    const HEADER: [u8; 21] = [
+        0x13, 0x00, 0x00, 0x00, b't', b'e', b's', b't', b' ', b' ', b' ', b' ', b' ', b' ',
+        0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn header_parity() {
+        let parity = block_checksum(0x00, &HEADER[3..20]);
+        let mut block = HEADER;
+        block[20] = parity;
+        assert!(verify_block(&block[2..]));
+        block[4] = b'T';
+        assert!(!verify_block(&block[2..]))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_round_trip() {
+        let block = encode_block(0xFF, &[0x01, 0x02, 0x04]).unwrap();
+        assert_eq!(block, vec![0x05, 0x00, 0xFF, 0x01, 0x02, 0x04, 0xF8]);
+        let parsed = TapBlocks::new(&block).next().unwrap().unwrap();
+        assert_eq!(parsed.offset, 0);
+        assert_eq!(parsed.flag(), Some(0xFF));
+        assert_eq!(parsed.data(), &[0x01, 0x02, 0x04]);
+        assert!(parsed.is_valid())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reports_failing_blocks() {
+        let mut image = Vec::new();
+        for data in [&b"one"[..], b"two", b"three"] {
+            image.extend(encode_block(0xFF, data).unwrap());
+        }
+        assert_eq!(failing_blocks(&image), Ok(vec![]));
+        image[10] ^= 0x20;
+        assert_eq!(failing_blocks(&image), Ok(vec![1]))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn truncated_image() {
+        let mut image = encode_block(0xFF, b"one").unwrap();
+        image.extend(encode_block(0xFF, b"two").unwrap());
+        image.truncate(10);
+        let mut blocks = TapBlocks::new(&image);
+        assert!(blocks.next().unwrap().is_ok());
+        assert_eq!(blocks.next(), Some(Err(TapError::Truncated { offset: 7 })));
+        assert_eq!(blocks.next(), None);
+        assert_eq!(failing_blocks(&[0x05]), Err(TapError::Truncated { offset: 0 }))
+    }
+
+    #[test]
+    fn empty_block_is_invalid() {
+        assert!(!verify_block(&[]));
+        assert!(!verify_block(&[0x00]))
+    }
+}